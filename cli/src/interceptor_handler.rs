@@ -1,15 +1,14 @@
-use std::{
-    num::NonZeroU32,
-    sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
-};
+use std::{num::NonZeroU32, sync::Arc};
 
 use anyhow::anyhow;
 use bincode::deserialize;
 use jito_bytemuck::AccountDeserialize;
+use solana_clock::Clock;
 use solana_commitment_config::CommitmentConfig;
 use solana_keypair::Keypair;
-use solana_program::{borsh1::try_from_slice_unchecked, sysvar::SysvarId};
+use solana_program::{
+    borsh1::try_from_slice_unchecked, instruction::AccountMeta, sysvar::SysvarId,
+};
 use solana_pubkey::{pubkey, Pubkey};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_rpc_client_api::{
@@ -19,18 +18,23 @@ use solana_rpc_client_api::{
 use solana_signer::Signer;
 use solana_system_interface::instruction::transfer;
 use solana_transaction::{Instruction, Signers, Transaction};
-use spl_associated_token_account_interface::address::get_associated_token_address;
+use spl_associated_token_account_interface::address::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
 use spl_stake_pool::{
     find_stake_program_address, find_withdraw_authority_program_address,
     state::{StakePool, ValidatorList},
 };
-use stake_deposit_interceptor_client::instructions::{
-    ClaimPoolTokensBuilder, DepositStakeBuilder, DepositStakeWhitelistedBuilder,
-    InitStakePoolDepositStakeAuthorityBuilder, UpdateStakePoolDepositStakeAuthorityBuilder,
-    WithdrawFromHopperBuilder, WithdrawStakeWhitelistedBuilder,
+use stake_deposit_interceptor_client::{
+    instructions::{
+        ClaimPoolTokensBuilder, DepositStakeBuilder, DepositStakeWhitelistedBuilder,
+        InitStakePoolDepositStakeAuthorityBuilder, UpdateStakePoolDepositStakeAuthorityBuilder,
+        WithdrawFromHopperBuilder, WithdrawStakeWhitelistedBuilder,
+    },
+    types::{CoolDownUnit, FeeCurve},
 };
 use stake_deposit_interceptor_program::state::{
-    hopper::Hopper, StakeDepositInterceptorDiscriminators,
+    fee_split::FeeSplit, hopper::Hopper, StakeDepositInterceptorDiscriminators,
 };
 
 use crate::{
@@ -137,26 +141,30 @@ pub struct ReceiptInfo {
     pub owner_ata_exists: bool,
 }
 
+/// Fetch the cluster's Clock sysvar
+pub async fn get_clock(rpc_client: &RpcClient) -> anyhow::Result<Clock> {
+    let account_data = rpc_client.get_account_data(&Clock::id()).await?;
+    let clock = deserialize(account_data.as_slice())?;
+    Ok(clock)
+}
+
 /// Calculate receipt status and timing information
 pub async fn calculate_receipt_info(
     rpc_client: &RpcClient,
+    clock: &Clock,
     receipt_address: Pubkey,
     receipt: &stake_deposit_interceptor_program::state::DepositReceipt,
 ) -> ReceiptInfo {
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-
     let deposit_time = u64::from(receipt.deposit_time);
     let cool_down_seconds = u64::from(receipt.cool_down_seconds);
-    let expiry_time = deposit_time.saturating_add(cool_down_seconds);
-    let is_expired = now > expiry_time;
+    // Expressed in the receipt's cool down unit
+    let expiry_time = receipt.cool_down_start().saturating_add(cool_down_seconds);
+    let is_expired = !receipt.is_cooling_down(clock);
 
     let current_fee_amount = if is_expired {
         0
     } else {
-        receipt.calculate_fee_amount(now as i64)
+        receipt.calculate_fee_amount_at(clock)
     };
 
     // Check if owner has an ATA for the J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn token
@@ -435,20 +443,23 @@ impl StakeDepositInterceptorCliHandler {
         ix_builder
             .payer(self.cli_config.signer.pubkey())
             .deposit_stake_authority(deposit_stake_authority_pda)
-            .vault_ata(get_associated_token_address(
+            .vault_ata(get_associated_token_address_with_program_id(
                 &deposit_stake_authority_pda,
                 &stake_pool.pool_mint,
+                &stake_pool.token_program_id,
             ))
             .authority(*authority)
             .base(base.pubkey())
             .stake_pool(*stake_pool_address)
             .stake_pool_mint(stake_pool.pool_mint)
             .stake_pool_program(spl_stake_pool_program_id)
-            .token_program(spl_token_interface::id())
+            .token_program(stake_pool.token_program_id)
             .associated_token_program(spl_associated_token_account_interface::program::id())
             .fee_wallet(*fee_wallet)
             .cool_down_seconds(cool_down_seconds)
-            .initial_fee_bps(initial_fee_bps);
+            .initial_fee_bps(initial_fee_bps)
+            .fee_curve(FeeCurve::Linear)
+            .cool_down_unit(CoolDownUnit::Seconds);
         let mut ix = ix_builder.instruction();
         ix.program_id = self.stake_deposit_interceptor_program_id;
 
@@ -584,6 +595,7 @@ impl StakeDepositInterceptorCliHandler {
             .manager_fee_account(stake_pool.manager_fee_account)
             .referrer_pool_tokens_account(referrer_token_account)
             .pool_mint(stake_pool.pool_mint)
+            .token_program(stake_pool.token_program_id)
             .clock(solana_clock::Clock::id())
             .stake_history(solana_stake_interface::stake_history::StakeHistory::id())
             .stake_program(solana_stake_interface::program::id())
@@ -638,6 +650,10 @@ impl StakeDepositInterceptorCliHandler {
         );
         let cool_down_seconds: u64 = stake_deposit_authority.cool_down_seconds.into();
         println!("Cool Down Seconds:       {cool_down_seconds}");
+        println!(
+            "Cool Down Unit:          {:?}",
+            stake_deposit_authority.cool_down_unit()
+        );
         let initial_fee_bps: u32 = stake_deposit_authority.inital_fee_bps.into();
         println!("Initial Fee (bps):       {initial_fee_bps}",);
         println!(
@@ -670,6 +686,7 @@ impl StakeDepositInterceptorCliHandler {
         let program_id = program_id.unwrap_or(default_program_id);
 
         let receipts = get_all_deposit_receipts(&rpc_client, &program_id, stake_pool).await?;
+        let clock = get_clock(&rpc_client).await?;
 
         if receipts.is_empty() {
             println!("No deposit receipts found.");
@@ -678,7 +695,7 @@ impl StakeDepositInterceptorCliHandler {
 
         let futs: Vec<_> = receipts
             .iter()
-            .map(|(addr, receipt)| calculate_receipt_info(&rpc_client, *addr, receipt))
+            .map(|(addr, receipt)| calculate_receipt_info(&rpc_client, &clock, *addr, receipt))
             .collect();
         let mut receipt_infos: Vec<ReceiptInfo> = futures::future::join_all(futs).await;
 
@@ -768,19 +785,27 @@ impl StakeDepositInterceptorCliHandler {
             )
             ?;
 
+        // SPL Token or Token-2022, whichever owns the pool mint
+        let token_program_id = rpc_client
+            .get_account(&stake_pool_deposit_authority.pool_mint)
+            .await?
+            .owner;
+
         // Determine the destination token account
         let destination_token_account = match destination {
             Some(dest) => dest,
-            None => get_associated_token_address(
+            None => get_associated_token_address_with_program_id(
                 &receipt.owner,
                 &stake_pool_deposit_authority.pool_mint,
+                &token_program_id,
             ),
         };
 
         // Get fee wallet token account
-        let fee_wallet_token_account = get_associated_token_address(
+        let fee_wallet_token_account = get_associated_token_address_with_program_id(
             &stake_pool_deposit_authority.fee_wallet,
             &stake_pool_deposit_authority.pool_mint,
+            &token_program_id,
         );
 
         // Collect all instructions
@@ -800,7 +825,7 @@ impl StakeDepositInterceptorCliHandler {
                         &self.cli_config.signer.pubkey(),
                         &receipt.owner,
                         &stake_pool_deposit_authority.pool_mint,
-                        &spl_token::id(),
+                        &token_program_id,
                     );
                 instructions.push(create_ata_ix);
             } else {
@@ -823,11 +848,48 @@ impl StakeDepositInterceptorCliHandler {
                     &self.cli_config.signer.pubkey(),
                     &stake_pool_deposit_authority.fee_wallet,
                     &stake_pool_deposit_authority.pool_mint,
-                    &spl_token::id(),
+                    &token_program_id,
                 );
             instructions.push(create_fee_ata_ix);
         }
 
+        // Fees go to the FeeSplit recipients' token accounts when a FeeSplit is set
+        let mut fee_split_accounts = Vec::new();
+        if stake_pool_deposit_authority.has_fee_split() {
+            let (fee_split_address, _bump_seed, _seeds) = FeeSplit::find_program_address(
+                &self.stake_deposit_interceptor_program_id,
+                &receipt.stake_pool_deposit_stake_authority,
+            );
+            let fee_split_account = rpc_client.get_account(&fee_split_address).await?;
+            let fee_split = FeeSplit::try_from_slice_unchecked(fee_split_account.data.as_slice())?;
+
+            fee_split_accounts.push(AccountMeta::new_readonly(fee_split_address, false));
+            for recipient in fee_split.recipients() {
+                let recipient_token_account = get_associated_token_address_with_program_id(
+                    &recipient.wallet,
+                    &stake_pool_deposit_authority.pool_mint,
+                    &token_program_id,
+                );
+                if rpc_client
+                    .get_account(&recipient_token_account)
+                    .await
+                    .is_err()
+                {
+                    println!("Will create fee split token account: {recipient_token_account}");
+
+                    instructions.push(
+                        spl_associated_token_account_interface::instruction::create_associated_token_account(
+                            &self.cli_config.signer.pubkey(),
+                            &recipient.wallet,
+                            &stake_pool_deposit_authority.pool_mint,
+                            &token_program_id,
+                        ),
+                    );
+                }
+                fee_split_accounts.push(AccountMeta::new(recipient_token_account, false));
+            }
+        }
+
         let mut ix_builder = ClaimPoolTokensBuilder::new();
         ix_builder
             .deposit_receipt(
@@ -839,7 +901,11 @@ impl StakeDepositInterceptorCliHandler {
             .destination(destination_token_account)
             .fee_wallet(fee_wallet_token_account)
             .deposit_authority(receipt.stake_pool_deposit_stake_authority)
-            .pool_mint(stake_pool_deposit_authority.pool_mint);
+            .pool_mint(stake_pool_deposit_authority.pool_mint)
+            .token_program(token_program_id)
+            .receipt_owner(receipt.owner)
+            .referrer((receipt.referrer != Pubkey::default()).then_some(receipt.referrer))
+            .add_remaining_accounts(&fee_split_accounts);
         let mut ix = ix_builder.instruction();
         ix.program_id = self.stake_deposit_interceptor_program_id;
 
@@ -911,6 +977,7 @@ impl StakeDepositInterceptorCliHandler {
             .manager_fee_account(stake_pool.manager_fee_account)
             .referral_fee_account(pool_tokens_to)
             .pool_mint(stake_pool.pool_mint)
+            .token_program(stake_pool.token_program_id)
             .clock(solana_clock::Clock::id())
             .stake_history(solana_stake_interface::stake_history::StakeHistory::id())
             .spl_stake_pool_program(spl_stake_pool_program_id)
@@ -76,6 +76,87 @@ pub enum StakeDepositInterceptorError {
     /// 21 - UnsupportedFeeAccountExtension
     #[error("UnsupportedFeeAccountExtension")]
     UnsupportedFeeAccountExtension = 0x15,
+    /// 22 - InvalidClaimAmount
+    #[error("InvalidClaimAmount")]
+    InvalidClaimAmount = 0x16,
+    /// 23 - InvalidSplitAmount
+    #[error("InvalidSplitAmount")]
+    InvalidSplitAmount = 0x17,
+    /// 24 - DepositReceiptMismatch
+    #[error("DepositReceiptMismatch")]
+    DepositReceiptMismatch = 0x18,
+    /// 25 - InvalidFeeCurve
+    #[error("InvalidFeeCurve")]
+    InvalidFeeCurve = 0x19,
+    /// 26 - Paused
+    #[error("Paused")]
+    Paused = 0x1A,
+    /// 27 - InvalidPendingAuthority
+    #[error("InvalidPendingAuthority")]
+    InvalidPendingAuthority = 0x1B,
+    /// 28 - VaultNotEmpty
+    #[error("VaultNotEmpty")]
+    VaultNotEmpty = 0x1C,
+    /// 29 - DepositStakeAuthorityInUse
+    #[error("DepositStakeAuthorityInUse")]
+    DepositStakeAuthorityInUse = 0x1D,
+    /// 30 - WhitelistFeeDiscountMaxExceeded
+    #[error("WhitelistFeeDiscountMaxExceeded")]
+    WhitelistFeeDiscountMaxExceeded = 0x1E,
+    /// 31 - OwnerNotWhitelisted
+    #[error("OwnerNotWhitelisted")]
+    OwnerNotWhitelisted = 0x1F,
+    /// 32 - MaxDepositExceeded
+    #[error("MaxDepositExceeded")]
+    MaxDepositExceeded = 0x20,
+    /// 33 - EpochDepositCapExceeded
+    #[error("EpochDepositCapExceeded")]
+    EpochDepositCapExceeded = 0x21,
+    /// 34 - VaultUndercollateralized
+    #[error("VaultUndercollateralized")]
+    VaultUndercollateralized = 0x22,
+    /// 35 - ReceiptAccountingIncomplete
+    #[error("ReceiptAccountingIncomplete")]
+    ReceiptAccountingIncomplete = 0x23,
+    /// 36 - ReferrerFeeShareMaxExceeded
+    #[error("ReferrerFeeShareMaxExceeded")]
+    ReferrerFeeShareMaxExceeded = 0x24,
+    /// 37 - InvalidReferrerTokenAccount
+    #[error("InvalidReferrerTokenAccount")]
+    InvalidReferrerTokenAccount = 0x25,
+    /// 38 - InvalidFeeSplit
+    #[error("InvalidFeeSplit")]
+    InvalidFeeSplit = 0x26,
+    /// 39 - InvalidFeeSplitRecipient
+    #[error("InvalidFeeSplitRecipient")]
+    InvalidFeeSplitRecipient = 0x27,
+    /// 40 - UnsupportedPoolMintExtension
+    #[error("UnsupportedPoolMintExtension")]
+    UnsupportedPoolMintExtension = 0x28,
+    /// 41 - UnsupportedAccountVersion
+    #[error("UnsupportedAccountVersion")]
+    UnsupportedAccountVersion = 0x29,
+    /// 42 - AccountMigrationRequired
+    #[error("AccountMigrationRequired")]
+    AccountMigrationRequired = 0x2A,
+    /// 43 - InvalidCoolDownUnit
+    #[error("InvalidCoolDownUnit")]
+    InvalidCoolDownUnit = 0x2B,
+    /// 44 - HopperRebateDiscountMaxExceeded
+    #[error("HopperRebateDiscountMaxExceeded")]
+    HopperRebateDiscountMaxExceeded = 0x2C,
+    /// 45 - InvalidRebateBudget
+    #[error("InvalidRebateBudget")]
+    InvalidRebateBudget = 0x2D,
+    /// 46 - InvalidRebateMode
+    #[error("InvalidRebateMode")]
+    InvalidRebateMode = 0x2E,
+    /// 47 - InvalidWhitelist
+    #[error("InvalidWhitelist")]
+    InvalidWhitelist = 0x2F,
+    /// 48 - RebateVaultNotEmpty
+    #[error("RebateVaultNotEmpty")]
+    RebateVaultNotEmpty = 0x30,
 }
 
 impl From<StakeDepositInterceptorError> for solana_program_error::ProgramError {
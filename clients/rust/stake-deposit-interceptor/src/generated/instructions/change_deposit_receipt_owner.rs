@@ -19,6 +19,10 @@ pub struct ChangeDepositReceiptOwner {
     pub current_owner: solana_pubkey::Pubkey,
     /// New owner for the receipt
     pub new_owner: solana_pubkey::Pubkey,
+    /// Optional deposit authority PDA, required for a whitelist discounted receipt
+    pub deposit_authority: Option<solana_pubkey::Pubkey>,
+    /// Optional Jito Whitelist the new owner is on
+    pub whitelist: Option<solana_pubkey::Pubkey>,
 }
 
 impl ChangeDepositReceiptOwner {
@@ -31,7 +35,7 @@ impl ChangeDepositReceiptOwner {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             self.deposit_receipt,
             false,
@@ -44,6 +48,27 @@ impl ChangeDepositReceiptOwner {
             self.new_owner,
             false,
         ));
+        if let Some(deposit_authority) = self.deposit_authority {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                deposit_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
+        if let Some(whitelist) = self.whitelist {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                whitelist, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = ChangeDepositReceiptOwnerInstructionData::new()
             .try_to_vec()
@@ -86,11 +111,15 @@ impl Default for ChangeDepositReceiptOwnerInstructionData {
 ///   0. `[writable]` deposit_receipt
 ///   1. `[signer]` current_owner
 ///   2. `[]` new_owner
+///   3. `[optional]` deposit_authority
+///   4. `[optional]` whitelist
 #[derive(Clone, Debug, Default)]
 pub struct ChangeDepositReceiptOwnerBuilder {
     deposit_receipt: Option<solana_pubkey::Pubkey>,
     current_owner: Option<solana_pubkey::Pubkey>,
     new_owner: Option<solana_pubkey::Pubkey>,
+    deposit_authority: Option<solana_pubkey::Pubkey>,
+    whitelist: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -116,6 +145,23 @@ impl ChangeDepositReceiptOwnerBuilder {
         self.new_owner = Some(new_owner);
         self
     }
+    /// `[optional account]`
+    /// Optional deposit authority PDA, required for a whitelist discounted receipt
+    #[inline(always)]
+    pub fn deposit_authority(
+        &mut self,
+        deposit_authority: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.deposit_authority = deposit_authority;
+        self
+    }
+    /// `[optional account]`
+    /// Optional Jito Whitelist the new owner is on
+    #[inline(always)]
+    pub fn whitelist(&mut self, whitelist: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.whitelist = whitelist;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -137,6 +183,8 @@ impl ChangeDepositReceiptOwnerBuilder {
             deposit_receipt: self.deposit_receipt.expect("deposit_receipt is not set"),
             current_owner: self.current_owner.expect("current_owner is not set"),
             new_owner: self.new_owner.expect("new_owner is not set"),
+            deposit_authority: self.deposit_authority,
+            whitelist: self.whitelist,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -151,6 +199,10 @@ pub struct ChangeDepositReceiptOwnerCpiAccounts<'a, 'b> {
     pub current_owner: &'b solana_account_info::AccountInfo<'a>,
     /// New owner for the receipt
     pub new_owner: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional deposit authority PDA, required for a whitelist discounted receipt
+    pub deposit_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional Jito Whitelist the new owner is on
+    pub whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `change_deposit_receipt_owner` CPI instruction.
@@ -163,6 +215,10 @@ pub struct ChangeDepositReceiptOwnerCpi<'a, 'b> {
     pub current_owner: &'b solana_account_info::AccountInfo<'a>,
     /// New owner for the receipt
     pub new_owner: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional deposit authority PDA, required for a whitelist discounted receipt
+    pub deposit_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional Jito Whitelist the new owner is on
+    pub whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> ChangeDepositReceiptOwnerCpi<'a, 'b> {
@@ -175,6 +231,8 @@ impl<'a, 'b> ChangeDepositReceiptOwnerCpi<'a, 'b> {
             deposit_receipt: accounts.deposit_receipt,
             current_owner: accounts.current_owner,
             new_owner: accounts.new_owner,
+            deposit_authority: accounts.deposit_authority,
+            whitelist: accounts.whitelist,
         }
     }
     #[inline(always)]
@@ -200,7 +258,7 @@ impl<'a, 'b> ChangeDepositReceiptOwnerCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.deposit_receipt.key,
             false,
@@ -213,6 +271,28 @@ impl<'a, 'b> ChangeDepositReceiptOwnerCpi<'a, 'b> {
             *self.new_owner.key,
             false,
         ));
+        if let Some(deposit_authority) = self.deposit_authority {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *deposit_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
+        if let Some(whitelist) = self.whitelist {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *whitelist.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -229,11 +309,17 @@ impl<'a, 'b> ChangeDepositReceiptOwnerCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.deposit_receipt.clone());
         account_infos.push(self.current_owner.clone());
         account_infos.push(self.new_owner.clone());
+        if let Some(deposit_authority) = self.deposit_authority {
+            account_infos.push(deposit_authority.clone());
+        }
+        if let Some(whitelist) = self.whitelist {
+            account_infos.push(whitelist.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -253,6 +339,8 @@ impl<'a, 'b> ChangeDepositReceiptOwnerCpi<'a, 'b> {
 ///   0. `[writable]` deposit_receipt
 ///   1. `[signer]` current_owner
 ///   2. `[]` new_owner
+///   3. `[optional]` deposit_authority
+///   4. `[optional]` whitelist
 #[derive(Clone, Debug)]
 pub struct ChangeDepositReceiptOwnerCpiBuilder<'a, 'b> {
     instruction: Box<ChangeDepositReceiptOwnerCpiBuilderInstruction<'a, 'b>>,
@@ -265,6 +353,8 @@ impl<'a, 'b> ChangeDepositReceiptOwnerCpiBuilder<'a, 'b> {
             deposit_receipt: None,
             current_owner: None,
             new_owner: None,
+            deposit_authority: None,
+            whitelist: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -293,6 +383,26 @@ impl<'a, 'b> ChangeDepositReceiptOwnerCpiBuilder<'a, 'b> {
         self.instruction.new_owner = Some(new_owner);
         self
     }
+    /// `[optional account]`
+    /// Optional deposit authority PDA, required for a whitelist discounted receipt
+    #[inline(always)]
+    pub fn deposit_authority(
+        &mut self,
+        deposit_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.deposit_authority = deposit_authority;
+        self
+    }
+    /// `[optional account]`
+    /// Optional Jito Whitelist the new owner is on
+    #[inline(always)]
+    pub fn whitelist(
+        &mut self,
+        whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.whitelist = whitelist;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -341,6 +451,10 @@ impl<'a, 'b> ChangeDepositReceiptOwnerCpiBuilder<'a, 'b> {
                 .expect("current_owner is not set"),
 
             new_owner: self.instruction.new_owner.expect("new_owner is not set"),
+
+            deposit_authority: self.instruction.deposit_authority,
+
+            whitelist: self.instruction.whitelist,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -355,6 +469,8 @@ struct ChangeDepositReceiptOwnerCpiBuilderInstruction<'a, 'b> {
     deposit_receipt: Option<&'b solana_account_info::AccountInfo<'a>>,
     current_owner: Option<&'b solana_account_info::AccountInfo<'a>>,
     new_owner: Option<&'b solana_account_info::AccountInfo<'a>>,
+    deposit_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -15,7 +15,7 @@ pub const CLAIM_POOL_TOKENS_DISCRIMINATOR: u8 = 5;
 pub struct ClaimPoolTokens {
     /// PDA storing deposit receipt
     pub deposit_receipt: solana_pubkey::Pubkey,
-    /// Owner of the receipt
+    /// Owner or delegate of the receipt
     pub owner: solana_pubkey::Pubkey,
     /// Vault token account
     pub vault: solana_pubkey::Pubkey,
@@ -31,6 +31,10 @@ pub struct ClaimPoolTokens {
     pub token_program: solana_pubkey::Pubkey,
     /// System program
     pub system_program: solana_pubkey::Pubkey,
+    /// Owner of the receipt, receiving its rent once closed. Only required when the delegate claims
+    pub receipt_owner: solana_pubkey::Pubkey,
+    /// Referrer token account
+    pub referrer: Option<solana_pubkey::Pubkey>,
 }
 
 impl ClaimPoolTokens {
@@ -43,7 +47,7 @@ impl ClaimPoolTokens {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             self.deposit_receipt,
             false,
@@ -55,7 +59,7 @@ impl ClaimPoolTokens {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(self.fee_wallet, false));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.deposit_authority,
             false,
         ));
@@ -71,6 +75,18 @@ impl ClaimPoolTokens {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.receipt_owner,
+            false,
+        ));
+        if let Some(referrer) = self.referrer {
+            accounts.push(solana_instruction::AccountMeta::new(referrer, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = ClaimPoolTokensInstructionData::new().try_to_vec().unwrap();
 
@@ -113,10 +129,12 @@ impl Default for ClaimPoolTokensInstructionData {
 ///   2. `[writable]` vault
 ///   3. `[writable]` destination
 ///   4. `[writable]` fee_wallet
-///   5. `[]` deposit_authority
+///   5. `[writable]` deposit_authority
 ///   6. `[]` pool_mint
 ///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   9. `[writable]` receipt_owner
+///   10. `[writable, optional]` referrer
 #[derive(Clone, Debug, Default)]
 pub struct ClaimPoolTokensBuilder {
     deposit_receipt: Option<solana_pubkey::Pubkey>,
@@ -128,6 +146,8 @@ pub struct ClaimPoolTokensBuilder {
     pool_mint: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    receipt_owner: Option<solana_pubkey::Pubkey>,
+    referrer: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -141,7 +161,7 @@ impl ClaimPoolTokensBuilder {
         self.deposit_receipt = Some(deposit_receipt);
         self
     }
-    /// Owner of the receipt
+    /// Owner or delegate of the receipt
     #[inline(always)]
     pub fn owner(&mut self, owner: solana_pubkey::Pubkey) -> &mut Self {
         self.owner = Some(owner);
@@ -191,6 +211,19 @@ impl ClaimPoolTokensBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// Owner of the receipt, receiving its rent once closed. Only required when the delegate claims
+    #[inline(always)]
+    pub fn receipt_owner(&mut self, receipt_owner: solana_pubkey::Pubkey) -> &mut Self {
+        self.receipt_owner = Some(receipt_owner);
+        self
+    }
+    /// `[optional account]`
+    /// Referrer token account
+    #[inline(always)]
+    pub fn referrer(&mut self, referrer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.referrer = referrer;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -224,6 +257,8 @@ impl ClaimPoolTokensBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            receipt_owner: self.receipt_owner.expect("receipt_owner is not set"),
+            referrer: self.referrer,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -234,7 +269,7 @@ impl ClaimPoolTokensBuilder {
 pub struct ClaimPoolTokensCpiAccounts<'a, 'b> {
     /// PDA storing deposit receipt
     pub deposit_receipt: &'b solana_account_info::AccountInfo<'a>,
-    /// Owner of the receipt
+    /// Owner or delegate of the receipt
     pub owner: &'b solana_account_info::AccountInfo<'a>,
     /// Vault token account
     pub vault: &'b solana_account_info::AccountInfo<'a>,
@@ -250,6 +285,10 @@ pub struct ClaimPoolTokensCpiAccounts<'a, 'b> {
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
     /// System program
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Owner of the receipt, receiving its rent once closed. Only required when the delegate claims
+    pub receipt_owner: &'b solana_account_info::AccountInfo<'a>,
+    /// Referrer token account
+    pub referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `claim_pool_tokens` CPI instruction.
@@ -258,7 +297,7 @@ pub struct ClaimPoolTokensCpi<'a, 'b> {
     pub __program: &'b solana_account_info::AccountInfo<'a>,
     /// PDA storing deposit receipt
     pub deposit_receipt: &'b solana_account_info::AccountInfo<'a>,
-    /// Owner of the receipt
+    /// Owner or delegate of the receipt
     pub owner: &'b solana_account_info::AccountInfo<'a>,
     /// Vault token account
     pub vault: &'b solana_account_info::AccountInfo<'a>,
@@ -274,6 +313,10 @@ pub struct ClaimPoolTokensCpi<'a, 'b> {
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
     /// System program
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Owner of the receipt, receiving its rent once closed. Only required when the delegate claims
+    pub receipt_owner: &'b solana_account_info::AccountInfo<'a>,
+    /// Referrer token account
+    pub referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> ClaimPoolTokensCpi<'a, 'b> {
@@ -292,6 +335,8 @@ impl<'a, 'b> ClaimPoolTokensCpi<'a, 'b> {
             pool_mint: accounts.pool_mint,
             token_program: accounts.token_program,
             system_program: accounts.system_program,
+            receipt_owner: accounts.receipt_owner,
+            referrer: accounts.referrer,
         }
     }
     #[inline(always)]
@@ -317,7 +362,7 @@ impl<'a, 'b> ClaimPoolTokensCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.deposit_receipt.key,
             false,
@@ -332,7 +377,7 @@ impl<'a, 'b> ClaimPoolTokensCpi<'a, 'b> {
             *self.fee_wallet.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.deposit_authority.key,
             false,
         ));
@@ -348,6 +393,18 @@ impl<'a, 'b> ClaimPoolTokensCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.receipt_owner.key,
+            false,
+        ));
+        if let Some(referrer) = self.referrer {
+            accounts.push(solana_instruction::AccountMeta::new(*referrer.key, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -362,7 +419,7 @@ impl<'a, 'b> ClaimPoolTokensCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.deposit_receipt.clone());
         account_infos.push(self.owner.clone());
@@ -373,6 +430,10 @@ impl<'a, 'b> ClaimPoolTokensCpi<'a, 'b> {
         account_infos.push(self.pool_mint.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.receipt_owner.clone());
+        if let Some(referrer) = self.referrer {
+            account_infos.push(referrer.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -394,10 +455,12 @@ impl<'a, 'b> ClaimPoolTokensCpi<'a, 'b> {
 ///   2. `[writable]` vault
 ///   3. `[writable]` destination
 ///   4. `[writable]` fee_wallet
-///   5. `[]` deposit_authority
+///   5. `[writable]` deposit_authority
 ///   6. `[]` pool_mint
 ///   7. `[]` token_program
 ///   8. `[]` system_program
+///   9. `[writable]` receipt_owner
+///   10. `[writable, optional]` referrer
 #[derive(Clone, Debug)]
 pub struct ClaimPoolTokensCpiBuilder<'a, 'b> {
     instruction: Box<ClaimPoolTokensCpiBuilderInstruction<'a, 'b>>,
@@ -416,6 +479,8 @@ impl<'a, 'b> ClaimPoolTokensCpiBuilder<'a, 'b> {
             pool_mint: None,
             token_program: None,
             system_program: None,
+            receipt_owner: None,
+            referrer: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -429,7 +494,7 @@ impl<'a, 'b> ClaimPoolTokensCpiBuilder<'a, 'b> {
         self.instruction.deposit_receipt = Some(deposit_receipt);
         self
     }
-    /// Owner of the receipt
+    /// Owner or delegate of the receipt
     #[inline(always)]
     pub fn owner(&mut self, owner: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.owner = Some(owner);
@@ -492,6 +557,25 @@ impl<'a, 'b> ClaimPoolTokensCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// Owner of the receipt, receiving its rent once closed. Only required when the delegate claims
+    #[inline(always)]
+    pub fn receipt_owner(
+        &mut self,
+        receipt_owner: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_owner = Some(receipt_owner);
+        self
+    }
+    /// `[optional account]`
+    /// Referrer token account
+    #[inline(always)]
+    pub fn referrer(
+        &mut self,
+        referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referrer = referrer;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -561,6 +645,13 @@ impl<'a, 'b> ClaimPoolTokensCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            receipt_owner: self
+                .instruction
+                .receipt_owner
+                .expect("receipt_owner is not set"),
+
+            referrer: self.instruction.referrer,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -581,6 +672,8 @@ struct ClaimPoolTokensCpiBuilderInstruction<'a, 'b> {
     pool_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    receipt_owner: Option<&'b solana_account_info::AccountInfo<'a>>,
+    referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
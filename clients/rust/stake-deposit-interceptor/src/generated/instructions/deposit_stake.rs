@@ -54,6 +54,8 @@ pub struct DepositStake {
     pub stake_program: solana_pubkey::Pubkey,
     /// System program id
     pub system_program: solana_pubkey::Pubkey,
+    /// Optional Jito Whitelist the DepositReceipt owner is on
+    pub whitelist: Option<solana_pubkey::Pubkey>,
 }
 
 impl DepositStake {
@@ -70,7 +72,7 @@ impl DepositStake {
         args: DepositStakeInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.stake_pool_program,
@@ -85,7 +87,7 @@ impl DepositStake {
             self.validator_stake_list,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.deposit_stake_authority,
             false,
         ));
@@ -134,6 +136,16 @@ impl DepositStake {
             self.system_program,
             false,
         ));
+        if let Some(whitelist) = self.whitelist {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                whitelist, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = DepositStakeInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -190,7 +202,7 @@ impl DepositStakeInstructionArgs {
 ///   2. `[writable]` deposit_receipt
 ///   3. `[writable]` stake_pool
 ///   4. `[writable]` validator_stake_list
-///   5. `[]` deposit_stake_authority
+///   5. `[writable]` deposit_stake_authority
 ///   6. `[signer]` base
 ///   7. `[]` stake_pool_withdraw_authority
 ///   8. `[writable]` stake
@@ -205,6 +217,7 @@ impl DepositStakeInstructionArgs {
 ///   17. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   18. `[]` stake_program
 ///   19. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   20. `[optional]` whitelist
 #[derive(Clone, Debug, Default)]
 pub struct DepositStakeBuilder {
     payer: Option<solana_pubkey::Pubkey>,
@@ -227,6 +240,7 @@ pub struct DepositStakeBuilder {
     token_program: Option<solana_pubkey::Pubkey>,
     stake_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    whitelist: Option<solana_pubkey::Pubkey>,
     owner: Option<Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -375,6 +389,13 @@ impl DepositStakeBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Optional Jito Whitelist the DepositReceipt owner is on
+    #[inline(always)]
+    pub fn whitelist(&mut self, whitelist: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.whitelist = whitelist;
+        self
+    }
     #[inline(always)]
     pub fn owner(&mut self, owner: Pubkey) -> &mut Self {
         self.owner = Some(owner);
@@ -438,6 +459,7 @@ impl DepositStakeBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            whitelist: self.whitelist,
         };
         let args = DepositStakeInstructionArgs {
             owner: self.owner.clone().expect("owner is not set"),
@@ -489,6 +511,8 @@ pub struct DepositStakeCpiAccounts<'a, 'b> {
     pub stake_program: &'b solana_account_info::AccountInfo<'a>,
     /// System program id
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional Jito Whitelist the DepositReceipt owner is on
+    pub whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `deposit_stake` CPI instruction.
@@ -535,6 +559,8 @@ pub struct DepositStakeCpi<'a, 'b> {
     pub stake_program: &'b solana_account_info::AccountInfo<'a>,
     /// System program id
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional Jito Whitelist the DepositReceipt owner is on
+    pub whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DepositStakeInstructionArgs,
 }
@@ -567,6 +593,7 @@ impl<'a, 'b> DepositStakeCpi<'a, 'b> {
             token_program: accounts.token_program,
             stake_program: accounts.stake_program,
             system_program: accounts.system_program,
+            whitelist: accounts.whitelist,
             __args: args,
         }
     }
@@ -593,7 +620,7 @@ impl<'a, 'b> DepositStakeCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.stake_pool_program.key,
@@ -611,7 +638,7 @@ impl<'a, 'b> DepositStakeCpi<'a, 'b> {
             *self.validator_stake_list.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.deposit_stake_authority.key,
             false,
         ));
@@ -665,6 +692,17 @@ impl<'a, 'b> DepositStakeCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(whitelist) = self.whitelist {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *whitelist.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -681,7 +719,7 @@ impl<'a, 'b> DepositStakeCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(21 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(22 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.stake_pool_program.clone());
@@ -703,6 +741,9 @@ impl<'a, 'b> DepositStakeCpi<'a, 'b> {
         account_infos.push(self.token_program.clone());
         account_infos.push(self.stake_program.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(whitelist) = self.whitelist {
+            account_infos.push(whitelist.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -724,7 +765,7 @@ impl<'a, 'b> DepositStakeCpi<'a, 'b> {
 ///   2. `[writable]` deposit_receipt
 ///   3. `[writable]` stake_pool
 ///   4. `[writable]` validator_stake_list
-///   5. `[]` deposit_stake_authority
+///   5. `[writable]` deposit_stake_authority
 ///   6. `[signer]` base
 ///   7. `[]` stake_pool_withdraw_authority
 ///   8. `[writable]` stake
@@ -739,6 +780,7 @@ impl<'a, 'b> DepositStakeCpi<'a, 'b> {
 ///   17. `[]` token_program
 ///   18. `[]` stake_program
 ///   19. `[]` system_program
+///   20. `[optional]` whitelist
 #[derive(Clone, Debug)]
 pub struct DepositStakeCpiBuilder<'a, 'b> {
     instruction: Box<DepositStakeCpiBuilderInstruction<'a, 'b>>,
@@ -768,6 +810,7 @@ impl<'a, 'b> DepositStakeCpiBuilder<'a, 'b> {
             token_program: None,
             stake_program: None,
             system_program: None,
+            whitelist: None,
             owner: None,
             __remaining_accounts: Vec::new(),
         });
@@ -935,6 +978,16 @@ impl<'a, 'b> DepositStakeCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Optional Jito Whitelist the DepositReceipt owner is on
+    #[inline(always)]
+    pub fn whitelist(
+        &mut self,
+        whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.whitelist = whitelist;
+        self
+    }
     #[inline(always)]
     pub fn owner(&mut self, owner: Pubkey) -> &mut Self {
         self.instruction.owner = Some(owner);
@@ -1058,6 +1111,8 @@ impl<'a, 'b> DepositStakeCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            whitelist: self.instruction.whitelist,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -1090,6 +1145,7 @@ struct DepositStakeCpiBuilderInstruction<'a, 'b> {
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     stake_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
     owner: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -81,7 +81,7 @@ impl DepositStakeWhitelisted {
             self.validator_list,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.stake_deposit_authority,
             false,
         ));
@@ -194,7 +194,7 @@ impl DepositStakeWhitelistedInstructionArgs {
 ///   1. `[]` whitelist
 ///   2. `[writable]` stake_pool
 ///   3. `[writable]` validator_list
-///   4. `[]` stake_deposit_authority
+///   4. `[writable]` stake_deposit_authority
 ///   5. `[]` withdraw_authority
 ///   6. `[writable]` deposit_stake
 ///   7. `[writable]` validator_stake
@@ -589,7 +589,7 @@ impl<'a, 'b> DepositStakeWhitelistedCpi<'a, 'b> {
             *self.validator_list.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.stake_deposit_authority.key,
             false,
         ));
@@ -708,7 +708,7 @@ impl<'a, 'b> DepositStakeWhitelistedCpi<'a, 'b> {
 ///   1. `[]` whitelist
 ///   2. `[writable]` stake_pool
 ///   3. `[writable]` validator_list
-///   4. `[]` stake_deposit_authority
+///   4. `[writable]` stake_deposit_authority
 ///   5. `[]` withdraw_authority
 ///   6. `[writable]` deposit_stake
 ///   7. `[writable]` validator_stake
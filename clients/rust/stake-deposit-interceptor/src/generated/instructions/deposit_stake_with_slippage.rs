@@ -54,6 +54,8 @@ pub struct DepositStakeWithSlippage {
     pub stake_program: solana_pubkey::Pubkey,
     /// System program id
     pub system_program: solana_pubkey::Pubkey,
+    /// Optional Jito Whitelist the DepositReceipt owner is on
+    pub whitelist: Option<solana_pubkey::Pubkey>,
 }
 
 impl DepositStakeWithSlippage {
@@ -70,7 +72,7 @@ impl DepositStakeWithSlippage {
         args: DepositStakeWithSlippageInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.stake_pool_program,
@@ -85,7 +87,7 @@ impl DepositStakeWithSlippage {
             self.validator_stake_list,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.deposit_stake_authority,
             false,
         ));
@@ -134,6 +136,16 @@ impl DepositStakeWithSlippage {
             self.system_program,
             false,
         ));
+        if let Some(whitelist) = self.whitelist {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                whitelist, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = DepositStakeWithSlippageInstructionData::new()
             .try_to_vec()
@@ -193,7 +205,7 @@ impl DepositStakeWithSlippageInstructionArgs {
 ///   2. `[writable]` deposit_receipt
 ///   3. `[writable]` stake_pool
 ///   4. `[writable]` validator_stake_list
-///   5. `[]` deposit_stake_authority
+///   5. `[writable]` deposit_stake_authority
 ///   6. `[signer]` base
 ///   7. `[]` stake_pool_withdraw_authority
 ///   8. `[writable]` stake
@@ -208,6 +220,7 @@ impl DepositStakeWithSlippageInstructionArgs {
 ///   17. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   18. `[]` stake_program
 ///   19. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   20. `[optional]` whitelist
 #[derive(Clone, Debug, Default)]
 pub struct DepositStakeWithSlippageBuilder {
     payer: Option<solana_pubkey::Pubkey>,
@@ -230,6 +243,7 @@ pub struct DepositStakeWithSlippageBuilder {
     token_program: Option<solana_pubkey::Pubkey>,
     stake_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    whitelist: Option<solana_pubkey::Pubkey>,
     owner: Option<Pubkey>,
     minimum_pool_tokens_out: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -379,6 +393,13 @@ impl DepositStakeWithSlippageBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Optional Jito Whitelist the DepositReceipt owner is on
+    #[inline(always)]
+    pub fn whitelist(&mut self, whitelist: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.whitelist = whitelist;
+        self
+    }
     #[inline(always)]
     pub fn owner(&mut self, owner: Pubkey) -> &mut Self {
         self.owner = Some(owner);
@@ -447,6 +468,7 @@ impl DepositStakeWithSlippageBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            whitelist: self.whitelist,
         };
         let args = DepositStakeWithSlippageInstructionArgs {
             owner: self.owner.clone().expect("owner is not set"),
@@ -502,6 +524,8 @@ pub struct DepositStakeWithSlippageCpiAccounts<'a, 'b> {
     pub stake_program: &'b solana_account_info::AccountInfo<'a>,
     /// System program id
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional Jito Whitelist the DepositReceipt owner is on
+    pub whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `deposit_stake_with_slippage` CPI instruction.
@@ -548,6 +572,8 @@ pub struct DepositStakeWithSlippageCpi<'a, 'b> {
     pub stake_program: &'b solana_account_info::AccountInfo<'a>,
     /// System program id
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional Jito Whitelist the DepositReceipt owner is on
+    pub whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DepositStakeWithSlippageInstructionArgs,
 }
@@ -580,6 +606,7 @@ impl<'a, 'b> DepositStakeWithSlippageCpi<'a, 'b> {
             token_program: accounts.token_program,
             stake_program: accounts.stake_program,
             system_program: accounts.system_program,
+            whitelist: accounts.whitelist,
             __args: args,
         }
     }
@@ -606,7 +633,7 @@ impl<'a, 'b> DepositStakeWithSlippageCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.stake_pool_program.key,
@@ -624,7 +651,7 @@ impl<'a, 'b> DepositStakeWithSlippageCpi<'a, 'b> {
             *self.validator_stake_list.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.deposit_stake_authority.key,
             false,
         ));
@@ -678,6 +705,17 @@ impl<'a, 'b> DepositStakeWithSlippageCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(whitelist) = self.whitelist {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *whitelist.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -696,7 +734,7 @@ impl<'a, 'b> DepositStakeWithSlippageCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(21 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(22 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.stake_pool_program.clone());
@@ -718,6 +756,9 @@ impl<'a, 'b> DepositStakeWithSlippageCpi<'a, 'b> {
         account_infos.push(self.token_program.clone());
         account_infos.push(self.stake_program.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(whitelist) = self.whitelist {
+            account_infos.push(whitelist.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -739,7 +780,7 @@ impl<'a, 'b> DepositStakeWithSlippageCpi<'a, 'b> {
 ///   2. `[writable]` deposit_receipt
 ///   3. `[writable]` stake_pool
 ///   4. `[writable]` validator_stake_list
-///   5. `[]` deposit_stake_authority
+///   5. `[writable]` deposit_stake_authority
 ///   6. `[signer]` base
 ///   7. `[]` stake_pool_withdraw_authority
 ///   8. `[writable]` stake
@@ -754,6 +795,7 @@ impl<'a, 'b> DepositStakeWithSlippageCpi<'a, 'b> {
 ///   17. `[]` token_program
 ///   18. `[]` stake_program
 ///   19. `[]` system_program
+///   20. `[optional]` whitelist
 #[derive(Clone, Debug)]
 pub struct DepositStakeWithSlippageCpiBuilder<'a, 'b> {
     instruction: Box<DepositStakeWithSlippageCpiBuilderInstruction<'a, 'b>>,
@@ -783,6 +825,7 @@ impl<'a, 'b> DepositStakeWithSlippageCpiBuilder<'a, 'b> {
             token_program: None,
             stake_program: None,
             system_program: None,
+            whitelist: None,
             owner: None,
             minimum_pool_tokens_out: None,
             __remaining_accounts: Vec::new(),
@@ -951,6 +994,16 @@ impl<'a, 'b> DepositStakeWithSlippageCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Optional Jito Whitelist the DepositReceipt owner is on
+    #[inline(always)]
+    pub fn whitelist(
+        &mut self,
+        whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.whitelist = whitelist;
+        self
+    }
     #[inline(always)]
     pub fn owner(&mut self, owner: Pubkey) -> &mut Self {
         self.instruction.owner = Some(owner);
@@ -1084,6 +1137,8 @@ impl<'a, 'b> DepositStakeWithSlippageCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            whitelist: self.instruction.whitelist,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -1116,6 +1171,7 @@ struct DepositStakeWithSlippageCpiBuilderInstruction<'a, 'b> {
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     stake_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    whitelist: Option<&'b solana_account_info::AccountInfo<'a>>,
     owner: Option<Pubkey>,
     minimum_pool_tokens_out: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -5,6 +5,8 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::CoolDownUnit;
+use crate::generated::types::FeeCurve;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -133,6 +135,8 @@ pub struct InitStakePoolDepositStakeAuthorityInstructionArgs {
     pub fee_wallet: Pubkey,
     pub cool_down_seconds: u64,
     pub initial_fee_bps: u32,
+    pub fee_curve: FeeCurve,
+    pub cool_down_unit: CoolDownUnit,
 }
 
 impl InitStakePoolDepositStakeAuthorityInstructionArgs {
@@ -172,6 +176,8 @@ pub struct InitStakePoolDepositStakeAuthorityBuilder {
     fee_wallet: Option<Pubkey>,
     cool_down_seconds: Option<u64>,
     initial_fee_bps: Option<u32>,
+    fee_curve: Option<FeeCurve>,
+    cool_down_unit: Option<CoolDownUnit>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -267,6 +273,16 @@ impl InitStakePoolDepositStakeAuthorityBuilder {
         self.initial_fee_bps = Some(initial_fee_bps);
         self
     }
+    #[inline(always)]
+    pub fn fee_curve(&mut self, fee_curve: FeeCurve) -> &mut Self {
+        self.fee_curve = Some(fee_curve);
+        self
+    }
+    #[inline(always)]
+    pub fn cool_down_unit(&mut self, cool_down_unit: CoolDownUnit) -> &mut Self {
+        self.cool_down_unit = Some(cool_down_unit);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -317,6 +333,11 @@ impl InitStakePoolDepositStakeAuthorityBuilder {
                 .initial_fee_bps
                 .clone()
                 .expect("initial_fee_bps is not set"),
+            fee_curve: self.fee_curve.clone().expect("fee_curve is not set"),
+            cool_down_unit: self
+                .cool_down_unit
+                .clone()
+                .expect("cool_down_unit is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -547,6 +568,8 @@ impl<'a, 'b> InitStakePoolDepositStakeAuthorityCpiBuilder<'a, 'b> {
             fee_wallet: None,
             cool_down_seconds: None,
             initial_fee_bps: None,
+            fee_curve: None,
+            cool_down_unit: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -652,6 +675,16 @@ impl<'a, 'b> InitStakePoolDepositStakeAuthorityCpiBuilder<'a, 'b> {
         self.instruction.initial_fee_bps = Some(initial_fee_bps);
         self
     }
+    #[inline(always)]
+    pub fn fee_curve(&mut self, fee_curve: FeeCurve) -> &mut Self {
+        self.instruction.fee_curve = Some(fee_curve);
+        self
+    }
+    #[inline(always)]
+    pub fn cool_down_unit(&mut self, cool_down_unit: CoolDownUnit) -> &mut Self {
+        self.instruction.cool_down_unit = Some(cool_down_unit);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -702,6 +735,16 @@ impl<'a, 'b> InitStakePoolDepositStakeAuthorityCpiBuilder<'a, 'b> {
                 .initial_fee_bps
                 .clone()
                 .expect("initial_fee_bps is not set"),
+            fee_curve: self
+                .instruction
+                .fee_curve
+                .clone()
+                .expect("fee_curve is not set"),
+            cool_down_unit: self
+                .instruction
+                .cool_down_unit
+                .clone()
+                .expect("cool_down_unit is not set"),
         };
         let instruction = InitStakePoolDepositStakeAuthorityCpi {
             __program: self.instruction.__program,
@@ -771,6 +814,8 @@ struct InitStakePoolDepositStakeAuthorityCpiBuilderInstruction<'a, 'b> {
     fee_wallet: Option<Pubkey>,
     cool_down_seconds: Option<u64>,
     initial_fee_bps: Option<u32>,
+    fee_curve: Option<FeeCurve>,
+    cool_down_unit: Option<CoolDownUnit>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -5,22 +5,64 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_authority;
+pub(crate) mod r#approve_claim_delegate;
+pub(crate) mod r#cancel_authority_proposal;
 pub(crate) mod r#change_deposit_receipt_owner;
 pub(crate) mod r#claim_pool_tokens;
+pub(crate) mod r#claim_pool_tokens_partial;
+pub(crate) mod r#close_deposit_stake_authority;
+pub(crate) mod r#deposit_sol;
+pub(crate) mod r#deposit_sol_whitelisted;
+pub(crate) mod r#deposit_sol_with_slippage;
 pub(crate) mod r#deposit_stake;
 pub(crate) mod r#deposit_stake_whitelisted;
 pub(crate) mod r#deposit_stake_with_slippage;
+pub(crate) mod r#fund_rebate_vault;
 pub(crate) mod r#init_stake_pool_deposit_stake_authority;
+pub(crate) mod r#merge_deposit_receipts;
+pub(crate) mod r#migrate_account;
+pub(crate) mod r#pause;
+pub(crate) mod r#propose_authority;
+pub(crate) mod r#redeem_receipt_to_stake;
+pub(crate) mod r#revoke_claim_delegate;
+pub(crate) mod r#set_fee_split;
+pub(crate) mod r#split_deposit_receipt;
+pub(crate) mod r#sweep_vault_surplus;
+pub(crate) mod r#unpause;
 pub(crate) mod r#update_stake_pool_deposit_stake_authority;
 pub(crate) mod r#withdraw_from_hopper;
+pub(crate) mod r#withdraw_from_rebate_vault;
+pub(crate) mod r#withdraw_sol_whitelisted;
 pub(crate) mod r#withdraw_stake_whitelisted;
 
+pub use self::r#accept_authority::*;
+pub use self::r#approve_claim_delegate::*;
+pub use self::r#cancel_authority_proposal::*;
 pub use self::r#change_deposit_receipt_owner::*;
 pub use self::r#claim_pool_tokens::*;
+pub use self::r#claim_pool_tokens_partial::*;
+pub use self::r#close_deposit_stake_authority::*;
+pub use self::r#deposit_sol::*;
+pub use self::r#deposit_sol_whitelisted::*;
+pub use self::r#deposit_sol_with_slippage::*;
 pub use self::r#deposit_stake::*;
 pub use self::r#deposit_stake_whitelisted::*;
 pub use self::r#deposit_stake_with_slippage::*;
+pub use self::r#fund_rebate_vault::*;
 pub use self::r#init_stake_pool_deposit_stake_authority::*;
+pub use self::r#merge_deposit_receipts::*;
+pub use self::r#migrate_account::*;
+pub use self::r#pause::*;
+pub use self::r#propose_authority::*;
+pub use self::r#redeem_receipt_to_stake::*;
+pub use self::r#revoke_claim_delegate::*;
+pub use self::r#set_fee_split::*;
+pub use self::r#split_deposit_receipt::*;
+pub use self::r#sweep_vault_surplus::*;
+pub use self::r#unpause::*;
 pub use self::r#update_stake_pool_deposit_stake_authority::*;
 pub use self::r#withdraw_from_hopper::*;
+pub use self::r#withdraw_from_rebate_vault::*;
+pub use self::r#withdraw_sol_whitelisted::*;
 pub use self::r#withdraw_stake_whitelisted::*;
@@ -5,6 +5,9 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::CoolDownUnit;
+use crate::generated::types::FeeCurve;
+use crate::generated::types::RebateMode;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -18,7 +21,7 @@ pub struct UpdateStakePoolDepositStakeAuthority {
     pub deposit_stake_authority: solana_pubkey::Pubkey,
     /// Authority that can update the deposit authority
     pub authority: solana_pubkey::Pubkey,
-    /// Optional new authority
+    /// Optional new authority, which must sign to accept the transfer
     pub new_authority: Option<solana_pubkey::Pubkey>,
 }
 
@@ -48,7 +51,7 @@ impl UpdateStakePoolDepositStakeAuthority {
         if let Some(new_authority) = self.new_authority {
             accounts.push(solana_instruction::AccountMeta::new_readonly(
                 new_authority,
-                false,
+                true,
             ));
         } else {
             accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -100,6 +103,18 @@ pub struct UpdateStakePoolDepositStakeAuthorityInstructionArgs {
     pub cool_down_seconds: Option<u64>,
     pub initial_fee_bps: Option<u32>,
     pub jito_whitelist_management_program_id: Option<Pubkey>,
+    pub fee_curve: Option<FeeCurve>,
+    pub whitelist_fee_discount_bps: Option<u32>,
+    pub max_deposit_lamports: Option<u64>,
+    pub epoch_deposit_cap: Option<u64>,
+    pub referrer_fee_share_bps: Option<u32>,
+    pub cool_down_unit: Option<CoolDownUnit>,
+    pub hopper_rebate_discount_bps: Option<u32>,
+    pub max_hopper_rebate_lamports: Option<u64>,
+    pub epoch_hopper_rebate_budget: Option<u64>,
+    pub rebate_mode: Option<RebateMode>,
+    pub deposit_whitelist: Option<Pubkey>,
+    pub withdraw_whitelist: Option<Pubkey>,
 }
 
 impl UpdateStakePoolDepositStakeAuthorityInstructionArgs {
@@ -114,7 +129,7 @@ impl UpdateStakePoolDepositStakeAuthorityInstructionArgs {
 ///
 ///   0. `[writable]` deposit_stake_authority
 ///   1. `[signer]` authority
-///   2. `[optional]` new_authority
+///   2. `[signer, optional]` new_authority
 #[derive(Clone, Debug, Default)]
 pub struct UpdateStakePoolDepositStakeAuthorityBuilder {
     deposit_stake_authority: Option<solana_pubkey::Pubkey>,
@@ -124,6 +139,18 @@ pub struct UpdateStakePoolDepositStakeAuthorityBuilder {
     cool_down_seconds: Option<u64>,
     initial_fee_bps: Option<u32>,
     jito_whitelist_management_program_id: Option<Pubkey>,
+    fee_curve: Option<FeeCurve>,
+    whitelist_fee_discount_bps: Option<u32>,
+    max_deposit_lamports: Option<u64>,
+    epoch_deposit_cap: Option<u64>,
+    referrer_fee_share_bps: Option<u32>,
+    cool_down_unit: Option<CoolDownUnit>,
+    hopper_rebate_discount_bps: Option<u32>,
+    max_hopper_rebate_lamports: Option<u64>,
+    epoch_hopper_rebate_budget: Option<u64>,
+    rebate_mode: Option<RebateMode>,
+    deposit_whitelist: Option<Pubkey>,
+    withdraw_whitelist: Option<Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -147,7 +174,7 @@ impl UpdateStakePoolDepositStakeAuthorityBuilder {
         self
     }
     /// `[optional account]`
-    /// Optional new authority
+    /// Optional new authority, which must sign to accept the transfer
     #[inline(always)]
     pub fn new_authority(&mut self, new_authority: Option<solana_pubkey::Pubkey>) -> &mut Self {
         self.new_authority = new_authority;
@@ -180,6 +207,78 @@ impl UpdateStakePoolDepositStakeAuthorityBuilder {
         self.jito_whitelist_management_program_id = Some(jito_whitelist_management_program_id);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn fee_curve(&mut self, fee_curve: FeeCurve) -> &mut Self {
+        self.fee_curve = Some(fee_curve);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn whitelist_fee_discount_bps(&mut self, whitelist_fee_discount_bps: u32) -> &mut Self {
+        self.whitelist_fee_discount_bps = Some(whitelist_fee_discount_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_deposit_lamports(&mut self, max_deposit_lamports: u64) -> &mut Self {
+        self.max_deposit_lamports = Some(max_deposit_lamports);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn epoch_deposit_cap(&mut self, epoch_deposit_cap: u64) -> &mut Self {
+        self.epoch_deposit_cap = Some(epoch_deposit_cap);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn referrer_fee_share_bps(&mut self, referrer_fee_share_bps: u32) -> &mut Self {
+        self.referrer_fee_share_bps = Some(referrer_fee_share_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn cool_down_unit(&mut self, cool_down_unit: CoolDownUnit) -> &mut Self {
+        self.cool_down_unit = Some(cool_down_unit);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn hopper_rebate_discount_bps(&mut self, hopper_rebate_discount_bps: u32) -> &mut Self {
+        self.hopper_rebate_discount_bps = Some(hopper_rebate_discount_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_hopper_rebate_lamports(&mut self, max_hopper_rebate_lamports: u64) -> &mut Self {
+        self.max_hopper_rebate_lamports = Some(max_hopper_rebate_lamports);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn epoch_hopper_rebate_budget(&mut self, epoch_hopper_rebate_budget: u64) -> &mut Self {
+        self.epoch_hopper_rebate_budget = Some(epoch_hopper_rebate_budget);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn rebate_mode(&mut self, rebate_mode: RebateMode) -> &mut Self {
+        self.rebate_mode = Some(rebate_mode);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn deposit_whitelist(&mut self, deposit_whitelist: Pubkey) -> &mut Self {
+        self.deposit_whitelist = Some(deposit_whitelist);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn withdraw_whitelist(&mut self, withdraw_whitelist: Pubkey) -> &mut Self {
+        self.withdraw_whitelist = Some(withdraw_whitelist);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -209,6 +308,18 @@ impl UpdateStakePoolDepositStakeAuthorityBuilder {
             cool_down_seconds: self.cool_down_seconds.clone(),
             initial_fee_bps: self.initial_fee_bps.clone(),
             jito_whitelist_management_program_id: self.jito_whitelist_management_program_id.clone(),
+            fee_curve: self.fee_curve.clone(),
+            whitelist_fee_discount_bps: self.whitelist_fee_discount_bps.clone(),
+            max_deposit_lamports: self.max_deposit_lamports.clone(),
+            epoch_deposit_cap: self.epoch_deposit_cap.clone(),
+            referrer_fee_share_bps: self.referrer_fee_share_bps.clone(),
+            cool_down_unit: self.cool_down_unit.clone(),
+            hopper_rebate_discount_bps: self.hopper_rebate_discount_bps.clone(),
+            max_hopper_rebate_lamports: self.max_hopper_rebate_lamports.clone(),
+            epoch_hopper_rebate_budget: self.epoch_hopper_rebate_budget.clone(),
+            rebate_mode: self.rebate_mode.clone(),
+            deposit_whitelist: self.deposit_whitelist.clone(),
+            withdraw_whitelist: self.withdraw_whitelist.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -221,7 +332,7 @@ pub struct UpdateStakePoolDepositStakeAuthorityCpiAccounts<'a, 'b> {
     pub deposit_stake_authority: &'b solana_account_info::AccountInfo<'a>,
     /// Authority that can update the deposit authority
     pub authority: &'b solana_account_info::AccountInfo<'a>,
-    /// Optional new authority
+    /// Optional new authority, which must sign to accept the transfer
     pub new_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
@@ -233,7 +344,7 @@ pub struct UpdateStakePoolDepositStakeAuthorityCpi<'a, 'b> {
     pub deposit_stake_authority: &'b solana_account_info::AccountInfo<'a>,
     /// Authority that can update the deposit authority
     pub authority: &'b solana_account_info::AccountInfo<'a>,
-    /// Optional new authority
+    /// Optional new authority, which must sign to accept the transfer
     pub new_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: UpdateStakePoolDepositStakeAuthorityInstructionArgs,
@@ -288,7 +399,7 @@ impl<'a, 'b> UpdateStakePoolDepositStakeAuthorityCpi<'a, 'b> {
         if let Some(new_authority) = self.new_authority {
             accounts.push(solana_instruction::AccountMeta::new_readonly(
                 *new_authority.key,
-                false,
+                true,
             ));
         } else {
             accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -339,7 +450,7 @@ impl<'a, 'b> UpdateStakePoolDepositStakeAuthorityCpi<'a, 'b> {
 ///
 ///   0. `[writable]` deposit_stake_authority
 ///   1. `[signer]` authority
-///   2. `[optional]` new_authority
+///   2. `[signer, optional]` new_authority
 #[derive(Clone, Debug)]
 pub struct UpdateStakePoolDepositStakeAuthorityCpiBuilder<'a, 'b> {
     instruction: Box<UpdateStakePoolDepositStakeAuthorityCpiBuilderInstruction<'a, 'b>>,
@@ -356,6 +467,18 @@ impl<'a, 'b> UpdateStakePoolDepositStakeAuthorityCpiBuilder<'a, 'b> {
             cool_down_seconds: None,
             initial_fee_bps: None,
             jito_whitelist_management_program_id: None,
+            fee_curve: None,
+            whitelist_fee_discount_bps: None,
+            max_deposit_lamports: None,
+            epoch_deposit_cap: None,
+            referrer_fee_share_bps: None,
+            cool_down_unit: None,
+            hopper_rebate_discount_bps: None,
+            max_hopper_rebate_lamports: None,
+            epoch_hopper_rebate_budget: None,
+            rebate_mode: None,
+            deposit_whitelist: None,
+            withdraw_whitelist: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -376,7 +499,7 @@ impl<'a, 'b> UpdateStakePoolDepositStakeAuthorityCpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// Optional new authority
+    /// Optional new authority, which must sign to accept the transfer
     #[inline(always)]
     pub fn new_authority(
         &mut self,
@@ -413,6 +536,78 @@ impl<'a, 'b> UpdateStakePoolDepositStakeAuthorityCpiBuilder<'a, 'b> {
             Some(jito_whitelist_management_program_id);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn fee_curve(&mut self, fee_curve: FeeCurve) -> &mut Self {
+        self.instruction.fee_curve = Some(fee_curve);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn whitelist_fee_discount_bps(&mut self, whitelist_fee_discount_bps: u32) -> &mut Self {
+        self.instruction.whitelist_fee_discount_bps = Some(whitelist_fee_discount_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_deposit_lamports(&mut self, max_deposit_lamports: u64) -> &mut Self {
+        self.instruction.max_deposit_lamports = Some(max_deposit_lamports);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn epoch_deposit_cap(&mut self, epoch_deposit_cap: u64) -> &mut Self {
+        self.instruction.epoch_deposit_cap = Some(epoch_deposit_cap);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn referrer_fee_share_bps(&mut self, referrer_fee_share_bps: u32) -> &mut Self {
+        self.instruction.referrer_fee_share_bps = Some(referrer_fee_share_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn cool_down_unit(&mut self, cool_down_unit: CoolDownUnit) -> &mut Self {
+        self.instruction.cool_down_unit = Some(cool_down_unit);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn hopper_rebate_discount_bps(&mut self, hopper_rebate_discount_bps: u32) -> &mut Self {
+        self.instruction.hopper_rebate_discount_bps = Some(hopper_rebate_discount_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_hopper_rebate_lamports(&mut self, max_hopper_rebate_lamports: u64) -> &mut Self {
+        self.instruction.max_hopper_rebate_lamports = Some(max_hopper_rebate_lamports);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn epoch_hopper_rebate_budget(&mut self, epoch_hopper_rebate_budget: u64) -> &mut Self {
+        self.instruction.epoch_hopper_rebate_budget = Some(epoch_hopper_rebate_budget);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn rebate_mode(&mut self, rebate_mode: RebateMode) -> &mut Self {
+        self.instruction.rebate_mode = Some(rebate_mode);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn deposit_whitelist(&mut self, deposit_whitelist: Pubkey) -> &mut Self {
+        self.instruction.deposit_whitelist = Some(deposit_whitelist);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn withdraw_whitelist(&mut self, withdraw_whitelist: Pubkey) -> &mut Self {
+        self.instruction.withdraw_whitelist = Some(withdraw_whitelist);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -455,6 +650,18 @@ impl<'a, 'b> UpdateStakePoolDepositStakeAuthorityCpiBuilder<'a, 'b> {
                 .instruction
                 .jito_whitelist_management_program_id
                 .clone(),
+            fee_curve: self.instruction.fee_curve.clone(),
+            whitelist_fee_discount_bps: self.instruction.whitelist_fee_discount_bps.clone(),
+            max_deposit_lamports: self.instruction.max_deposit_lamports.clone(),
+            epoch_deposit_cap: self.instruction.epoch_deposit_cap.clone(),
+            referrer_fee_share_bps: self.instruction.referrer_fee_share_bps.clone(),
+            cool_down_unit: self.instruction.cool_down_unit.clone(),
+            hopper_rebate_discount_bps: self.instruction.hopper_rebate_discount_bps.clone(),
+            max_hopper_rebate_lamports: self.instruction.max_hopper_rebate_lamports.clone(),
+            epoch_hopper_rebate_budget: self.instruction.epoch_hopper_rebate_budget.clone(),
+            rebate_mode: self.instruction.rebate_mode.clone(),
+            deposit_whitelist: self.instruction.deposit_whitelist.clone(),
+            withdraw_whitelist: self.instruction.withdraw_whitelist.clone(),
         };
         let instruction = UpdateStakePoolDepositStakeAuthorityCpi {
             __program: self.instruction.__program,
@@ -486,6 +693,18 @@ struct UpdateStakePoolDepositStakeAuthorityCpiBuilderInstruction<'a, 'b> {
     cool_down_seconds: Option<u64>,
     initial_fee_bps: Option<u32>,
     jito_whitelist_management_program_id: Option<Pubkey>,
+    fee_curve: Option<FeeCurve>,
+    whitelist_fee_discount_bps: Option<u32>,
+    max_deposit_lamports: Option<u64>,
+    epoch_deposit_cap: Option<u64>,
+    referrer_fee_share_bps: Option<u32>,
+    cool_down_unit: Option<CoolDownUnit>,
+    hopper_rebate_discount_bps: Option<u32>,
+    max_hopper_rebate_lamports: Option<u64>,
+    epoch_hopper_rebate_budget: Option<u64>,
+    rebate_mode: Option<RebateMode>,
+    deposit_whitelist: Option<Pubkey>,
+    withdraw_whitelist: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
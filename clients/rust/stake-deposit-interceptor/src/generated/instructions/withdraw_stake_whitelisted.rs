@@ -53,6 +53,10 @@ pub struct WithdrawStakeWhitelisted {
     pub spl_stake_pool_program: solana_pubkey::Pubkey,
     /// System program
     pub system_program: solana_pubkey::Pubkey,
+    /// Optional RebateBudget PDA tracking the whitelisted signer's rebates per epoch
+    pub rebate_budget: Option<solana_pubkey::Pubkey>,
+    /// Optional RebateVault PDA paying the fee rebate in pool tokens
+    pub rebate_vault: Option<solana_pubkey::Pubkey>,
 }
 
 impl WithdrawStakeWhitelisted {
@@ -69,7 +73,7 @@ impl WithdrawStakeWhitelisted {
         args: WithdrawStakeWhitelistedInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(22 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             self.whitelisted_signer,
             true,
@@ -83,7 +87,7 @@ impl WithdrawStakeWhitelisted {
             self.validator_list,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.stake_deposit_authority,
             false,
         ));
@@ -143,6 +147,22 @@ impl WithdrawStakeWhitelisted {
             self.system_program,
             false,
         ));
+        if let Some(rebate_budget) = self.rebate_budget {
+            accounts.push(solana_instruction::AccountMeta::new(rebate_budget, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
+        if let Some(rebate_vault) = self.rebate_vault {
+            accounts.push(solana_instruction::AccountMeta::new(rebate_vault, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = WithdrawStakeWhitelistedInstructionData::new()
             .try_to_vec()
@@ -201,7 +221,7 @@ impl WithdrawStakeWhitelistedInstructionArgs {
 ///   1. `[]` whitelist
 ///   2. `[writable]` stake_pool
 ///   3. `[writable]` validator_list
-///   4. `[]` stake_deposit_authority
+///   4. `[writable]` stake_deposit_authority
 ///   5. `[]` withdraw_authority
 ///   6. `[writable]` stake_split_from
 ///   7. `[writable]` stake_split_to
@@ -217,6 +237,8 @@ impl WithdrawStakeWhitelistedInstructionArgs {
 ///   17. `[]` stake_program
 ///   18. `[]` spl_stake_pool_program
 ///   19. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   20. `[writable, optional]` rebate_budget
+///   21. `[writable, optional]` rebate_vault
 #[derive(Clone, Debug, Default)]
 pub struct WithdrawStakeWhitelistedBuilder {
     whitelisted_signer: Option<solana_pubkey::Pubkey>,
@@ -239,6 +261,8 @@ pub struct WithdrawStakeWhitelistedBuilder {
     stake_program: Option<solana_pubkey::Pubkey>,
     spl_stake_pool_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    rebate_budget: Option<solana_pubkey::Pubkey>,
+    rebate_vault: Option<solana_pubkey::Pubkey>,
     pool_tokens_in: Option<u64>,
     minimum_lamports_out: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -388,6 +412,20 @@ impl WithdrawStakeWhitelistedBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Optional RebateBudget PDA tracking the whitelisted signer's rebates per epoch
+    #[inline(always)]
+    pub fn rebate_budget(&mut self, rebate_budget: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.rebate_budget = rebate_budget;
+        self
+    }
+    /// `[optional account]`
+    /// Optional RebateVault PDA paying the fee rebate in pool tokens
+    #[inline(always)]
+    pub fn rebate_vault(&mut self, rebate_vault: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.rebate_vault = rebate_vault;
+        self
+    }
     #[inline(always)]
     pub fn pool_tokens_in(&mut self, pool_tokens_in: u64) -> &mut Self {
         self.pool_tokens_in = Some(pool_tokens_in);
@@ -460,6 +498,8 @@ impl WithdrawStakeWhitelistedBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            rebate_budget: self.rebate_budget,
+            rebate_vault: self.rebate_vault,
         };
         let args = WithdrawStakeWhitelistedInstructionArgs {
             pool_tokens_in: self
@@ -518,6 +558,10 @@ pub struct WithdrawStakeWhitelistedCpiAccounts<'a, 'b> {
     pub spl_stake_pool_program: &'b solana_account_info::AccountInfo<'a>,
     /// System program
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional RebateBudget PDA tracking the whitelisted signer's rebates per epoch
+    pub rebate_budget: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional RebateVault PDA paying the fee rebate in pool tokens
+    pub rebate_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `withdraw_stake_whitelisted` CPI instruction.
@@ -564,6 +608,10 @@ pub struct WithdrawStakeWhitelistedCpi<'a, 'b> {
     pub spl_stake_pool_program: &'b solana_account_info::AccountInfo<'a>,
     /// System program
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional RebateBudget PDA tracking the whitelisted signer's rebates per epoch
+    pub rebate_budget: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional RebateVault PDA paying the fee rebate in pool tokens
+    pub rebate_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: WithdrawStakeWhitelistedInstructionArgs,
 }
@@ -596,6 +644,8 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpi<'a, 'b> {
             stake_program: accounts.stake_program,
             spl_stake_pool_program: accounts.spl_stake_pool_program,
             system_program: accounts.system_program,
+            rebate_budget: accounts.rebate_budget,
+            rebate_vault: accounts.rebate_vault,
             __args: args,
         }
     }
@@ -622,7 +672,7 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(22 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.whitelisted_signer.key,
             true,
@@ -639,7 +689,7 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpi<'a, 'b> {
             *self.validator_list.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.stake_deposit_authority.key,
             false,
         ));
@@ -703,6 +753,28 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(rebate_budget) = self.rebate_budget {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *rebate_budget.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
+        if let Some(rebate_vault) = self.rebate_vault {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *rebate_vault.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::STAKE_DEPOSIT_INTERCEPTOR_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -721,7 +793,7 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(21 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(23 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.whitelisted_signer.clone());
         account_infos.push(self.whitelist.clone());
@@ -743,6 +815,12 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpi<'a, 'b> {
         account_infos.push(self.stake_program.clone());
         account_infos.push(self.spl_stake_pool_program.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(rebate_budget) = self.rebate_budget {
+            account_infos.push(rebate_budget.clone());
+        }
+        if let Some(rebate_vault) = self.rebate_vault {
+            account_infos.push(rebate_vault.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -763,7 +841,7 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpi<'a, 'b> {
 ///   1. `[]` whitelist
 ///   2. `[writable]` stake_pool
 ///   3. `[writable]` validator_list
-///   4. `[]` stake_deposit_authority
+///   4. `[writable]` stake_deposit_authority
 ///   5. `[]` withdraw_authority
 ///   6. `[writable]` stake_split_from
 ///   7. `[writable]` stake_split_to
@@ -779,6 +857,8 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpi<'a, 'b> {
 ///   17. `[]` stake_program
 ///   18. `[]` spl_stake_pool_program
 ///   19. `[]` system_program
+///   20. `[writable, optional]` rebate_budget
+///   21. `[writable, optional]` rebate_vault
 #[derive(Clone, Debug)]
 pub struct WithdrawStakeWhitelistedCpiBuilder<'a, 'b> {
     instruction: Box<WithdrawStakeWhitelistedCpiBuilderInstruction<'a, 'b>>,
@@ -808,6 +888,8 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpiBuilder<'a, 'b> {
             stake_program: None,
             spl_stake_pool_program: None,
             system_program: None,
+            rebate_budget: None,
+            rebate_vault: None,
             pool_tokens_in: None,
             minimum_lamports_out: None,
             __remaining_accounts: Vec::new(),
@@ -985,6 +1067,26 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Optional RebateBudget PDA tracking the whitelisted signer's rebates per epoch
+    #[inline(always)]
+    pub fn rebate_budget(
+        &mut self,
+        rebate_budget: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rebate_budget = rebate_budget;
+        self
+    }
+    /// `[optional account]`
+    /// Optional RebateVault PDA paying the fee rebate in pool tokens
+    #[inline(always)]
+    pub fn rebate_vault(
+        &mut self,
+        rebate_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rebate_vault = rebate_vault;
+        self
+    }
     #[inline(always)]
     pub fn pool_tokens_in(&mut self, pool_tokens_in: u64) -> &mut Self {
         self.instruction.pool_tokens_in = Some(pool_tokens_in);
@@ -1131,6 +1233,10 @@ impl<'a, 'b> WithdrawStakeWhitelistedCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            rebate_budget: self.instruction.rebate_budget,
+
+            rebate_vault: self.instruction.rebate_vault,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -1163,6 +1269,8 @@ struct WithdrawStakeWhitelistedCpiBuilderInstruction<'a, 'b> {
     stake_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     spl_stake_pool_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    rebate_budget: Option<&'b solana_account_info::AccountInfo<'a>>,
+    rebate_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
     pool_tokens_in: Option<u64>,
     minimum_lamports_out: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -1,13 +1,22 @@
 use ::{
+    base64::{prelude::BASE64_STANDARD, Engine},
     jito_bytemuck::AccountDeserialize,
     solana_client::{
         nonblocking::rpc_client::RpcClient,
-        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig}, // Added explicit imports
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionConfig}, // Added explicit imports
         rpc_filter::{Memcmp, RpcFilterType},
     },
-    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction},
+    solana_program::clock::Clock,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signature},
+        signer::Signer,
+        transaction::Transaction,
+    },
+    solana_transaction_status_client_types::UiTransactionEncoding,
     spl_associated_token_account_interface::instruction::create_associated_token_account,
     stake_deposit_interceptor_program::{
+        events::StakeDepositInterceptorEvent,
         instruction::{create_claim_pool_tokens_instruction, derive_stake_deposit_receipt},
         state::{
             DepositReceipt, StakeDepositInterceptorDiscriminators, StakePoolDepositStakeAuthority,
@@ -25,7 +34,7 @@ pub mod metrics;
 use metrics::{emit_crank, emit_deposit_receipt, emit_error, emit_heartbeat};
 use solana_client::rpc_config::UiAccountEncoding;
 use solana_commitment_config::CommitmentConfig;
-use spl_associated_token_account_interface::address::get_associated_token_address;
+use spl_associated_token_account_interface::address::get_associated_token_address_with_program_id;
 
 #[derive(Clone)]
 pub struct CrankerConfig {
@@ -97,6 +106,14 @@ impl InterceptorCranker {
             .duration_since(UNIX_EPOCH)
             .map_err(|e| CrankerError::TimeError(e.to_string()))?
             .as_secs();
+        // Receipts may measure their cool down in slots or epochs instead of seconds
+        let epoch_info = self.rpc_client.get_epoch_info().await?;
+        let clock = Clock {
+            slot: epoch_info.absolute_slot,
+            epoch: epoch_info.epoch,
+            unix_timestamp: now as i64,
+            ..Clock::default()
+        };
 
         let deposit_receipts: u64 = receipts.len() as u64;
         let mut future_deposits: u64 = 0;
@@ -104,16 +121,22 @@ impl InterceptorCranker {
         let mut claimed_receipts: u64 = 0;
         for receipt in receipts {
             // Get raw bytes using bytemuck and interpret as little-endian
-            let deposit_time = u64::from(receipt.deposit_time);
+            let deposit_time = receipt.cool_down_start();
             let cool_down = u64::from(receipt.cool_down_seconds);
+            let now = receipt.cool_down_unit().clock_value(&clock);
 
             info!(
                 "Receipt {} raw bytes:\n\
                   Interpreted values:\n\
+                  cool_down_unit: {:?}\n\
                   deposit_time: {}\n\
                   cool_down: {}\n\
                   current_time: {}",
-                receipt.base, deposit_time, cool_down, now
+                receipt.base,
+                receipt.cool_down_unit(),
+                deposit_time,
+                cool_down,
+                now
             );
             emit_deposit_receipt(&receipt, &self.cluster_name);
 
@@ -255,8 +278,18 @@ impl InterceptorCranker {
             .get_stake_pool_deposit_authority(&receipt.stake_pool_deposit_stake_authority)
             .await?;
 
-        let owner_ata =
-            get_associated_token_address(&receipt.owner, &stake_pool_deposit_authority.pool_mint);
+        // The pool mint may be owned by SPL Token or Token-2022
+        let token_program = self
+            .rpc_client
+            .get_account(&stake_pool_deposit_authority.pool_mint)
+            .await?
+            .owner;
+
+        let owner_ata = get_associated_token_address_with_program_id(
+            &receipt.owner,
+            &stake_pool_deposit_authority.pool_mint,
+            &token_program,
+        );
 
         // Check if account exists
         match self.rpc_client.get_account(&owner_ata).await {
@@ -269,7 +302,7 @@ impl InterceptorCranker {
                     &self.payer.pubkey(),
                     &receipt.owner,
                     &stake_pool_deposit_authority.pool_mint,
-                    &spl_token_interface::id(),
+                    &token_program,
                 );
 
                 let recent_blockhash = self.rpc_client.get_latest_blockhash().await?;
@@ -287,9 +320,10 @@ impl InterceptorCranker {
             }
         }
 
-        let fee_wallet_token_account = get_associated_token_address(
+        let fee_wallet_token_account = get_associated_token_address_with_program_id(
             &stake_pool_deposit_authority.fee_wallet,
             &stake_pool_deposit_authority.pool_mint,
+            &token_program,
         );
 
         // Check if account exists
@@ -309,7 +343,7 @@ impl InterceptorCranker {
                     &self.payer.pubkey(),
                     &stake_pool_deposit_authority.fee_wallet,
                     &stake_pool_deposit_authority.pool_mint,
-                    &spl_token_interface::id(),
+                    &token_program,
                 );
 
                 let recent_blockhash = self.rpc_client.get_latest_blockhash().await?;
@@ -340,8 +374,9 @@ impl InterceptorCranker {
             &fee_wallet_token_account,
             &receipt.stake_pool_deposit_stake_authority,
             &stake_pool_deposit_authority.pool_mint,
-            &spl_token_interface::id(),
+            &token_program,
             true,
+            (receipt.referrer != Pubkey::default()).then_some(&receipt.referrer),
         );
 
         let recent_blockhash = self.rpc_client.get_latest_blockhash().await?;
@@ -362,6 +397,9 @@ impl InterceptorCranker {
                     "Successfully claimed pool tokens for receipt {}. Transaction signature: {}",
                     receipt.base, sig
                 );
+                if let Err(e) = self.log_claim_events(&sig).await {
+                    error!("Failed to fetch the claim events of transaction {sig}: {e}");
+                }
                 Ok(())
             }
             Err(e) => {
@@ -377,6 +415,54 @@ impl InterceptorCranker {
         }
     }
 
+    /// Log the PoolTokensClaimed events of a confirmed claim transaction
+    async fn log_claim_events(&self, signature: &Signature) -> Result<(), CrankerError> {
+        let transaction = self
+            .rpc_client
+            .get_transaction_with_config(
+                signature,
+                RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    max_supported_transaction_version: Some(0),
+                },
+            )
+            .await?;
+        let log_messages: Option<Vec<String>> = transaction
+            .transaction
+            .meta
+            .and_then(|meta| meta.log_messages.into());
+
+        for log_message in log_messages.iter().flatten() {
+            // Events are logged as `Program data: <base64> <base64>`
+            let Some(data) = log_message.strip_prefix("Program data: ") else {
+                continue;
+            };
+            let Ok(fields) = data
+                .split(' ')
+                .map(|field| BASE64_STANDARD.decode(field))
+                .collect::<Result<Vec<_>, _>>()
+            else {
+                continue;
+            };
+            let fields: Vec<&[u8]> = fields.iter().map(Vec::as_slice).collect();
+            if let Some(StakeDepositInterceptorEvent::PoolTokensClaimed {
+                deposit_receipt,
+                destination,
+                fee_amount,
+                net_amount,
+                ..
+            }) = StakeDepositInterceptorEvent::decode(&fields)
+            {
+                info!(
+                    "Claimed DepositReceipt {deposit_receipt}: {net_amount} pool tokens to {destination}, fee {fee_amount}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     async fn get_stake_pool_deposit_authority(
         &self,
         pubkey: &Pubkey,
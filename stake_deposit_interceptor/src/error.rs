@@ -74,6 +74,114 @@ pub enum StakeDepositInterceptorError {
     /// 21 : The fee account has an unsupported extension
     #[error("UnsupportedFeeAccountExtension")]
     UnsupportedFeeAccountExtension,
+
+    /// 22 : Claim amount is zero or exceeds the DepositReceipt balance
+    #[error("InvalidClaimAmount")]
+    InvalidClaimAmount,
+
+    /// 23 : Split amount must be non-zero and less than the DepositReceipt balance
+    #[error("InvalidSplitAmount")]
+    InvalidSplitAmount,
+
+    /// 24 : DepositReceipts cannot be merged
+    #[error("DepositReceiptMismatch")]
+    DepositReceiptMismatch,
+
+    /// 25 : Fee curve parameters are invalid for the cool down period
+    #[error("InvalidFeeCurve")]
+    InvalidFeeCurve,
+
+    /// 26 : Operation is paused on the StakePoolDepositStakeAuthority
+    #[error("Paused")]
+    Paused,
+
+    /// 27 : Signer is not the pending authority of the StakePoolDepositStakeAuthority
+    #[error("InvalidPendingAuthority")]
+    InvalidPendingAuthority,
+
+    /// 28 : Vault still holds pool tokens
+    #[error("VaultNotEmpty")]
+    VaultNotEmpty,
+
+    /// 29 : StakePool still uses the StakePoolDepositStakeAuthority as a deposit authority
+    #[error("DepositStakeAuthorityInUse")]
+    DepositStakeAuthorityInUse,
+
+    /// 30 : Whitelist fee discount cannot exceed 100%
+    #[error("WhitelistFeeDiscountMaxExceeded")]
+    WhitelistFeeDiscountMaxExceeded,
+
+    /// 31 : DepositReceipt owner is not on the whitelist
+    #[error("OwnerNotWhitelisted")]
+    OwnerNotWhitelisted,
+
+    /// 32 : Deposit exceeds the maximum single deposit
+    #[error("MaxDepositExceeded")]
+    MaxDepositExceeded,
+
+    /// 33 : Deposit exceeds the remaining deposit cap for the current epoch
+    #[error("EpochDepositCapExceeded")]
+    EpochDepositCapExceeded,
+
+    /// 34 : Vault holds fewer pool tokens than open DepositReceipts are owed
+    #[error("VaultUndercollateralized")]
+    VaultUndercollateralized,
+
+    /// 35 : StakePoolDepositStakeAuthority predates the receipt accounting counters
+    #[error("ReceiptAccountingIncomplete")]
+    ReceiptAccountingIncomplete,
+
+    /// 36 : Referrer fee share exceeds the maximum
+    #[error("ReferrerFeeShareMaxExceeded")]
+    ReferrerFeeShareMaxExceeded,
+
+    /// 37 : Referrer token account does not match the DepositReceipt
+    #[error("InvalidReferrerTokenAccount")]
+    InvalidReferrerTokenAccount,
+
+    /// 38 : FeeSplit recipients are invalid or their shares do not sum to 100%
+    #[error("InvalidFeeSplit")]
+    InvalidFeeSplit,
+
+    /// 39 : FeeSplit account or recipient token account does not match the FeeSplit
+    #[error("InvalidFeeSplitRecipient")]
+    InvalidFeeSplitRecipient,
+
+    /// 40 : Pool mint carries a Token-2022 extension that is not supported
+    #[error("UnsupportedPoolMintExtension")]
+    UnsupportedPoolMintExtension,
+
+    /// 41 : Account layout version is newer than this program supports
+    #[error("UnsupportedAccountVersion")]
+    UnsupportedAccountVersion,
+
+    /// 42 : Account uses an older layout and must be upgraded with MigrateAccount
+    #[error("AccountMigrationRequired")]
+    AccountMigrationRequired,
+
+    /// 43 : Invalid cool down unit
+    #[error("InvalidCoolDownUnit")]
+    InvalidCoolDownUnit,
+
+    /// 44 : Hopper rebate discount cannot exceed 100%
+    #[error("HopperRebateDiscountMaxExceeded")]
+    HopperRebateDiscountMaxExceeded,
+
+    /// 45 : RebateBudget account does not match the whitelisted signer
+    #[error("InvalidRebateBudget")]
+    InvalidRebateBudget,
+
+    /// 46 : Invalid rebate mode
+    #[error("InvalidRebateMode")]
+    InvalidRebateMode,
+
+    /// 47 : Whitelist is not the one set for the operation
+    #[error("InvalidWhitelist")]
+    InvalidWhitelist,
+
+    /// 48 : RebateVault still holds pool tokens
+    #[error("RebateVaultNotEmpty")]
+    RebateVaultNotEmpty,
 }
 
 impl From<StakeDepositInterceptorError> for ProgramError {
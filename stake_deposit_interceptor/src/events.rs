@@ -0,0 +1,224 @@
+//! Events logged by the program with `sol_log_data`, so indexers can follow deposits, claims
+//! and rebates without diffing token balances.
+//!
+//! Each event is logged as two fields: `EVENT_DISCRIMINATOR` followed by the Borsh-encoded
+//! `StakeDepositInterceptorEvent`. RPC nodes surface these as `Program data: <base64> <base64>`
+//! log lines; base64-decode the fields and pass them to `StakeDepositInterceptorEvent::decode`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+
+use crate::instruction::FeeSplitRecipientArgs;
+
+/// First field of every event logged by the program
+pub const EVENT_DISCRIMINATOR: [u8; 8] = *b"sdi_evt0";
+
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum StakeDepositInterceptorEvent {
+    /// A DepositReceipt was created by DepositStake, DepositSol or SplitDepositReceipt
+    DepositReceiptCreated {
+        deposit_receipt: Pubkey,
+        owner: Pubkey,
+        stake_pool: Pubkey,
+        deposit_stake_authority: Pubkey,
+        lst_amount: u64,
+        initial_fee_bps: u32,
+        deposit_time: u64,
+    },
+    /// "Pool" tokens were claimed from a DepositReceipt
+    PoolTokensClaimed {
+        deposit_receipt: Pubkey,
+        owner: Pubkey,
+        destination: Pubkey,
+        fee_amount: u64,
+        referrer_fee_amount: u64,
+        net_amount: u64,
+        remaining_amount: u64,
+    },
+    /// The owner of a DepositReceipt changed
+    DepositReceiptOwnerChanged {
+        deposit_receipt: Pubkey,
+        previous_owner: Pubkey,
+        new_owner: Pubkey,
+    },
+    /// The parameters or authority of a StakePoolDepositStakeAuthority changed
+    DepositStakeAuthorityUpdated {
+        deposit_stake_authority: Pubkey,
+        authority: Pubkey,
+        fee_wallet: Pubkey,
+        cool_down_seconds: u64,
+        initial_fee_bps: u32,
+    },
+    /// A whitelisted signer deposited stake directly into the StakePool
+    StakeDepositedWhitelisted {
+        deposit_stake_authority: Pubkey,
+        whitelisted_signer: Pubkey,
+        deposit_stake: Pubkey,
+        lamports: u64,
+        pool_tokens_minted: u64,
+    },
+    /// A whitelisted signer withdrew stake from the StakePool
+    StakeWithdrawnWhitelisted {
+        deposit_stake_authority: Pubkey,
+        whitelisted_signer: Pubkey,
+        pool_tokens_in: u64,
+        fee_lamports: u64,
+    },
+    /// The Hopper rebated a whitelisted withdrawal's fee
+    HopperRebatePaid {
+        hopper: Pubkey,
+        recipient: Pubkey,
+        lamports: u64,
+    },
+    /// The authority withdrew lamports from the Hopper
+    HopperWithdrawn {
+        hopper: Pubkey,
+        recipient: Pubkey,
+        lamports: u64,
+    },
+    /// Vault "pool" tokens not owed to any DepositReceipt were swept to the fee wallet
+    VaultSurplusSwept {
+        deposit_stake_authority: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    /// The claim delegate of a DepositReceipt changed. `delegate` is default when revoked.
+    ClaimDelegateUpdated {
+        deposit_receipt: Pubkey,
+        owner: Pubkey,
+        delegate: Pubkey,
+    },
+    /// An account was upgraded to the current layout by MigrateAccount
+    AccountMigrated {
+        account: Pubkey,
+        from_version: u8,
+        to_version: u8,
+    },
+    /// A whitelisted signer deposited SOL directly into the StakePool
+    SolDepositedWhitelisted {
+        deposit_stake_authority: Pubkey,
+        whitelisted_signer: Pubkey,
+        lamports: u64,
+        pool_tokens_minted: u64,
+    },
+    /// A whitelisted signer withdrew SOL from the StakePool
+    SolWithdrawnWhitelisted {
+        deposit_stake_authority: Pubkey,
+        whitelisted_signer: Pubkey,
+        pool_tokens_in: u64,
+        fee_lamports: u64,
+    },
+    /// "Pool" tokens were transferred into the RebateVault
+    RebateVaultFunded {
+        deposit_stake_authority: Pubkey,
+        funder: Pubkey,
+        amount: u64,
+    },
+    /// The RebateVault rebated a whitelisted withdrawal's fee in "pool" tokens
+    RebateVaultRebatePaid {
+        deposit_stake_authority: Pubkey,
+        recipient: Pubkey,
+        amount: u64,
+    },
+    /// The authority withdrew "pool" tokens from the RebateVault
+    RebateVaultWithdrawn {
+        deposit_stake_authority: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    /// A DepositReceipt was redeemed for stake withdrawn from the StakePool
+    DepositReceiptRedeemed {
+        deposit_receipt: Pubkey,
+        owner: Pubkey,
+        stake_account: Pubkey,
+        fee_amount: u64,
+        referrer_fee_amount: u64,
+        pool_tokens_redeemed: u64,
+    },
+    /// A source DepositReceipt was merged into a destination DepositReceipt and closed
+    DepositReceiptsMerged {
+        destination_deposit_receipt: Pubkey,
+        source_deposit_receipt: Pubkey,
+        owner: Pubkey,
+        lst_amount: u64,
+    },
+    /// The paused operations of a StakePoolDepositStakeAuthority changed, by Pause or Unpause
+    PausedOperationsUpdated {
+        deposit_stake_authority: Pubkey,
+        authority: Pubkey,
+        paused_operations: u8,
+    },
+    /// The pending authority of a StakePoolDepositStakeAuthority changed. `pending_authority`
+    /// is default when the proposal was cancelled.
+    PendingAuthorityUpdated {
+        deposit_stake_authority: Pubkey,
+        authority: Pubkey,
+        pending_authority: Pubkey,
+    },
+    /// A StakePoolDepositStakeAuthority and its accounts were closed
+    DepositStakeAuthorityClosed {
+        deposit_stake_authority: Pubkey,
+        authority: Pubkey,
+        destination: Pubkey,
+    },
+    /// The FeeSplit of a StakePoolDepositStakeAuthority was set. `recipients` is empty when
+    /// it was removed.
+    FeeSplitUpdated {
+        deposit_stake_authority: Pubkey,
+        fee_split: Pubkey,
+        recipients: Vec<FeeSplitRecipientArgs>,
+    },
+}
+
+impl StakeDepositInterceptorEvent {
+    /// Log the event with `sol_log_data`
+    pub fn emit(&self) {
+        let data = borsh::to_vec(self).unwrap();
+        sol_log_data(&[&EVENT_DISCRIMINATOR, &data]);
+    }
+
+    /// Decode an event from the (base64-decoded) fields of a `Program data:` log line.
+    /// Returns `None` when the fields are not an event logged by this program.
+    pub fn decode(fields: &[&[u8]]) -> Option<Self> {
+        match fields {
+            [discriminator, data] if *discriminator == EVENT_DISCRIMINATOR.as_slice() => {
+                Self::try_from_slice(data).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let event = StakeDepositInterceptorEvent::PoolTokensClaimed {
+            deposit_receipt: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            destination: Pubkey::new_unique(),
+            fee_amount: 10,
+            referrer_fee_amount: 2,
+            net_amount: 990,
+            remaining_amount: 0,
+        };
+        let data = borsh::to_vec(&event).unwrap();
+
+        assert_eq!(
+            StakeDepositInterceptorEvent::decode(&[&EVENT_DISCRIMINATOR, &data]),
+            Some(event)
+        );
+        // Other `sol_log_data` output is ignored
+        assert_eq!(StakeDepositInterceptorEvent::decode(&[&data]), None);
+        assert_eq!(
+            StakeDepositInterceptorEvent::decode(&[b"sdi_evt1", &data]),
+            None
+        );
+        assert_eq!(
+            StakeDepositInterceptorEvent::decode(&[&EVENT_DISCRIMINATOR, &data[..data.len() - 1]]),
+            None
+        );
+    }
+}
@@ -4,7 +4,11 @@ use solana_program::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
 };
-use spl_associated_token_account_interface::address::get_associated_token_address;
+use spl_associated_token_account_interface::address::get_associated_token_address_with_program_id;
+
+use crate::state::{
+    fee_split::FeeSplit, rebate_vault::RebateVault, CoolDownUnit, FeeCurve, RebateMode,
+};
 
 /// Initialize arguments for StakePoolDepositStakeAuthority
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
@@ -12,6 +16,8 @@ pub struct InitStakePoolDepositStakeAuthorityArgs {
     pub fee_wallet: Pubkey,
     pub cool_down_seconds: u64,
     pub initial_fee_bps: u32,
+    pub fee_curve: FeeCurve,
+    pub cool_down_unit: CoolDownUnit,
 }
 
 /// Update arguments for StakePoolDepositStakeAuthority
@@ -21,6 +27,27 @@ pub struct UpdateStakePoolDepositStakeAuthorityArgs {
     pub cool_down_seconds: Option<u64>,
     pub initial_fee_bps: Option<u32>,
     pub jito_whitelist_management_program_id: Option<Pubkey>,
+    pub fee_curve: Option<FeeCurve>,
+    pub whitelist_fee_discount_bps: Option<u32>,
+    pub max_deposit_lamports: Option<u64>,
+    pub epoch_deposit_cap: Option<u64>,
+    pub referrer_fee_share_bps: Option<u32>,
+    pub cool_down_unit: Option<CoolDownUnit>,
+    pub hopper_rebate_discount_bps: Option<u32>,
+    pub max_hopper_rebate_lamports: Option<u64>,
+    pub epoch_hopper_rebate_budget: Option<u64>,
+    pub rebate_mode: Option<RebateMode>,
+    pub deposit_whitelist: Option<Pubkey>,
+    pub withdraw_whitelist: Option<Pubkey>,
+}
+
+/// A recipient of SetFeeSplit
+#[derive(Clone, Copy, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct FeeSplitRecipientArgs {
+    /// Owner of the "pool" token account the share is paid to
+    pub wallet: Pubkey,
+    /// Share (in bps) of the claim-time fees
+    pub fee_bps: u32,
 }
 
 /// Arguments for DepositStake.
@@ -46,6 +73,27 @@ pub struct DepositStakeWithSlippageArgs {
     pub minimum_pool_tokens_out: u64,
 }
 
+/// Arguments for DepositSol.
+///
+/// NOTE: the owner of the DepositReceipt is passed separately so the
+/// account funding the deposit does not have to be the one claiming.
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct DepositSolArgs {
+    pub owner: Pubkey,
+    pub lamports_in: u64,
+}
+
+/// Arguments for DepositSolWithSlippage.
+///
+/// NOTE: the owner of the DepositReceipt is passed separately so the
+/// account funding the deposit does not have to be the one claiming.
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct DepositSolWithSlippageArgs {
+    pub owner: Pubkey,
+    pub lamports_in: u64,
+    pub minimum_pool_tokens_out: u64,
+}
+
 /// Instructions supported by the StakeDepositInterceptor program.
 #[derive(
     ShankInstruction, ShankInstruction, Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize,
@@ -90,7 +138,7 @@ pub enum StakeDepositInterceptorInstruction {
     ///
     ///   0. `[w]` StakePoolDepositStakeAuthority PDA to be updated
     ///   1. `[s]` Authority
-    ///   2. `[]` (Optional) New authority
+    ///   2. `[s]` (Optional) New authority, which must sign to accept the transfer
     #[account(
         0,
         writable,
@@ -103,7 +151,13 @@ pub enum StakeDepositInterceptorInstruction {
         name = "authority",
         desc = "Authority that can update the deposit authority"
     )]
-    #[account(2, optional, name = "new_authority", desc = "Optional new authority")]
+    #[account(
+        2,
+        optional,
+        signer,
+        name = "new_authority",
+        desc = "Optional new authority, which must sign to accept the transfer"
+    )]
     UpdateStakePoolDepositStakeAuthority(UpdateStakePoolDepositStakeAuthorityArgs),
 
     ///   Deposit some stake into the pool. The "pool" token minted is held by the DepositReceipt's
@@ -115,7 +169,7 @@ pub enum StakeDepositInterceptorInstruction {
     ///   2. `[w]` DepositReceipt to be created
     ///   3. `[w]` Stake pool
     ///   4. `[w]` Validator stake list storage account
-    ///   5. `[]` Stake pool deposit authority (aka the StakePoolDepositStakeAuthority PDA)
+    ///   5. `[w]` Stake pool deposit authority (aka the StakePoolDepositStakeAuthority PDA)
     ///   6. `[s]` Base for PDA seed
     ///   7. `[]` Stake pool withdraw authority
     ///   8. `[w]` Stake account to join the pool
@@ -130,6 +184,8 @@ pub enum StakeDepositInterceptorInstruction {
     ///   17. `[]` Pool token program id
     ///   18. `[]` Stake program id
     ///   19. `[]` System program id
+    ///   20. `[]` (Optional) Jito Whitelist. When the DepositReceipt owner is on it, the
+    ///       StakePoolDepositStakeAuthority's `whitelist_fee_discount_bps` is applied.
     #[account(0, writable, signer, name = "payer", desc = "Funding account")]
     #[account(1, name = "stake_pool_program", desc = "Stake pool program id")]
     #[account(
@@ -147,6 +203,7 @@ pub enum StakeDepositInterceptorInstruction {
     )]
     #[account(
         5,
+        writable,
         name = "deposit_stake_authority",
         desc = "StakePool stake_deposit_authority"
     )]
@@ -193,6 +250,12 @@ pub enum StakeDepositInterceptorInstruction {
     #[account(17, name = "token_program", desc = "Pool token program id")]
     #[account(18, name = "stake_program", desc = "Stake program id")]
     #[account(19, name = "system_program", desc = "System program id")]
+    #[account(
+        20,
+        optional,
+        name = "whitelist",
+        desc = "Optional Jito Whitelist the DepositReceipt owner is on"
+    )]
     DepositStake(DepositStakeArgs),
 
     ///   Deposit stake with slippage protection. The "pool" token minted is held by the DepositReceipt's
@@ -204,7 +267,7 @@ pub enum StakeDepositInterceptorInstruction {
     ///   2. `[w]` DepositReceipt to be created
     ///   3. `[w]` Stake pool
     ///   4. `[w]` Validator stake list storage account
-    ///   5. `[]` Stake pool deposit authority (aka the StakePoolDepositStakeAuthority PDA)
+    ///   5. `[w]` Stake pool deposit authority (aka the StakePoolDepositStakeAuthority PDA)
     ///   6. `[s]` Base for PDA seed
     ///   7. `[]` Stake pool withdraw authority
     ///   8. `[w]` Stake account to join the pool
@@ -219,6 +282,8 @@ pub enum StakeDepositInterceptorInstruction {
     ///   17. `[]` Pool token program id
     ///   18. `[]` Stake program id
     ///   19. `[]` System program id
+    ///   20. `[]` (Optional) Jito Whitelist. When the DepositReceipt owner is on it, the
+    ///       StakePoolDepositStakeAuthority's `whitelist_fee_discount_bps` is applied.
     #[account(0, writable, signer, name = "payer", desc = "Funding account")]
     #[account(1, name = "stake_pool_program", desc = "Stake pool program id")]
     #[account(
@@ -236,6 +301,7 @@ pub enum StakeDepositInterceptorInstruction {
     )]
     #[account(
         5,
+        writable,
         name = "deposit_stake_authority",
         desc = "StakePool stake_deposit_authority"
     )]
@@ -282,14 +348,26 @@ pub enum StakeDepositInterceptorInstruction {
     #[account(17, name = "token_program", desc = "Pool token program id")]
     #[account(18, name = "stake_program", desc = "Stake program id")]
     #[account(19, name = "system_program", desc = "System program id")]
+    #[account(
+        20,
+        optional,
+        name = "whitelist",
+        desc = "Optional Jito Whitelist the DepositReceipt owner is on"
+    )]
     DepositStakeWithSlippage(DepositStakeWithSlippageArgs),
 
     ///   Update the `owner` of the DepositReceipt so the new owner
-    ///   has the authority to claim the "pool" tokens.
+    ///   has the authority to claim the "pool" tokens. Any claim delegate
+    ///   is revoked. A whitelist discounted fee rate is reset to the
+    ///   StakePoolDepositStakeAuthority's `inital_fee_bps` unless the new owner
+    ///   is on the deposit Whitelist.
     ///
     ///   0. `[w]` DepositReceipt PDA
     ///   1. `[s]` current owner of the DepositReceipt
     ///   2. `[]` new owner for the DepositReceipt
+    ///   3. `[]` (Optional) StakePoolDepositStakeAuthority PDA of the DepositReceipt, required
+    ///      when the DepositReceipt is whitelist discounted
+    ///   4. `[]` (Optional) Jito Whitelist. When the new owner is on it, the discount is kept.
     #[account(
         0,
         writable,
@@ -303,6 +381,18 @@ pub enum StakeDepositInterceptorInstruction {
         desc = "Current owner of the receipt"
     )]
     #[account(2, name = "new_owner", desc = "New owner for the receipt")]
+    #[account(
+        3,
+        optional,
+        name = "deposit_authority",
+        desc = "Optional deposit authority PDA, required for a whitelist discounted receipt"
+    )]
+    #[account(
+        4,
+        optional,
+        name = "whitelist",
+        desc = "Optional Jito Whitelist the new owner is on"
+    )]
     ChangeDepositReceiptOwner,
 
     ///   Claim the "pool" tokens held by the program from a former DepositStake
@@ -310,28 +400,63 @@ pub enum StakeDepositInterceptorInstruction {
     ///   if this instruction is invoked during the cool down period.
     ///
     ///   0. `[w]` DepositReceipt PDA
-    ///   1. `[w,s]` owner of the DepositReceipt
+    ///   1. `[w,s]` owner or delegate of the DepositReceipt
     ///   2. `[w]` vault token account to send tokens from
     ///   3. `[w]` destination token account
     ///   4. `[w]` fee wallet token account
-    ///   5. `[]` StakePoolDepositStakeAuthority PDA
+    ///   5. `[w]` StakePoolDepositStakeAuthority PDA
     ///   6. `[]` Pool token mint
     ///   7. `[]` Token program id
     ///   8. `[]` System program id
+    ///   9. `[w]` owner of the DepositReceipt, receiving its rent once closed. Required ONLY
+    ///      when account 1 is the delegate; a claim by the owner may omit it, and the
+    ///      accounts below then move up by one
+    ///   10. `[w]` (Optional) Referrer token account recorded on the DepositReceipt
+    ///   11. `[]` (Optional) FeeSplit of the StakePoolDepositStakeAuthority
+    ///   12..12+N. `[w]` (Optional) FeeSplit recipient token accounts, in FeeSplit order
+    ///
+    ///   When the StakePoolDepositStakeAuthority has a FeeSplit and a fee is owed, the fee is
+    ///   paid to the FeeSplit recipients instead of the fee wallet token account. The referrer
+    ///   account must then be present, using the program id as a placeholder when the
+    ///   DepositReceipt has no referrer.
     #[account(
         0,
         writable,
         name = "deposit_receipt",
         desc = "PDA storing deposit receipt"
     )]
-    #[account(1, writable, signer, name = "owner", desc = "Owner of the receipt")]
+    #[account(
+        1,
+        writable,
+        signer,
+        name = "owner",
+        desc = "Owner or delegate of the receipt"
+    )]
     #[account(2, writable, name = "vault", desc = "Vault token account")]
     #[account(3, writable, name = "destination", desc = "Destination token account")]
     #[account(4, writable, name = "fee_wallet", desc = "Fee wallet token account")]
-    #[account(5, name = "deposit_authority", desc = "Deposit authority PDA")]
+    #[account(
+        5,
+        writable,
+        name = "deposit_authority",
+        desc = "Deposit authority PDA"
+    )]
     #[account(6, name = "pool_mint", desc = "Pool token mint")]
     #[account(7, name = "token_program", desc = "Token program")]
     #[account(8, name = "system_program", desc = "System program")]
+    #[account(
+        9,
+        writable,
+        name = "receipt_owner",
+        desc = "Owner of the receipt, receiving its rent once closed. Only required when the delegate claims"
+    )]
+    #[account(
+        10,
+        optional,
+        writable,
+        name = "referrer",
+        desc = "Referrer token account"
+    )]
     ClaimPoolTokens,
 
     /// Deposits stake directly into the spl-stake-pool — bypassing the Ticket/cooldown mechanism.
@@ -340,7 +465,7 @@ pub enum StakeDepositInterceptorInstruction {
     ///   1. `[]` Whitelist PDA
     ///   2. `[w]` Stake pool account
     ///   3. `[w]` Validator list account
-    ///   4. `[]` StakePoolDepositStakeAuthority PDA
+    ///   4. `[w]` StakePoolDepositStakeAuthority PDA
     ///   5. `[]` Pool withdraw authority
     ///   6. `[w]` Deposit stake account
     ///   7. `[w]` Validator stake account
@@ -370,6 +495,7 @@ pub enum StakeDepositInterceptorInstruction {
     #[account(3, writable, name = "validator_list", desc = "Validator List")]
     #[account(
         4,
+        writable,
         name = "stake_deposit_authority",
         desc = "Interceptor PDA - the stake deposit authority on the pool"
     )]
@@ -422,7 +548,7 @@ pub enum StakeDepositInterceptorInstruction {
     ///   1. `[]` Whitelist PDA
     ///   2. `[w]` Stake pool account
     ///   3. `[w]` Validator list account
-    ///   4. `[]` StakePoolDepositStakeAuthority PDA
+    ///   4. `[w]` StakePoolDepositStakeAuthority PDA
     ///   5. `[]` Pool withdraw authority
     ///   6. `[w]` Validator stake account to split from
     ///   7. `[w]` The new stake account
@@ -438,6 +564,10 @@ pub enum StakeDepositInterceptorInstruction {
     ///   17. `[]` Stake program id
     ///   18. `[]` SPL stake pool program id
     ///   19. `[]` System program id
+    ///   20. `[w]` (Optional) RebateBudget PDA of the whitelisted signer, required when the
+    ///       StakePoolDepositStakeAuthority has an `epoch_hopper_rebate_budget`
+    ///   21. `[w]` (Optional) RebateVault PDA, required when the StakePoolDepositStakeAuthority
+    ///       rebates with `RebateMode::RebateVault`
     #[account(
         0,
         signer,
@@ -454,6 +584,7 @@ pub enum StakeDepositInterceptorInstruction {
     #[account(3, writable, name = "validator_list", desc = "Validator List")]
     #[account(
         4,
+        writable,
         name = "stake_deposit_authority",
         desc = "Interceptor PDA - the stake deposit authority on the pool"
     )]
@@ -503,6 +634,20 @@ pub enum StakeDepositInterceptorInstruction {
     #[account(17, name = "stake_program", desc = "Stake program id")]
     #[account(18, name = "spl_stake_pool_program", desc = "SPL Stake Pool Program")]
     #[account(19, name = "system_program", desc = "System program")]
+    #[account(
+        20,
+        optional,
+        writable,
+        name = "rebate_budget",
+        desc = "Optional RebateBudget PDA tracking the whitelisted signer's rebates per epoch"
+    )]
+    #[account(
+        21,
+        optional,
+        writable,
+        name = "rebate_vault",
+        desc = "Optional RebateVault PDA paying the fee rebate in pool tokens"
+    )]
     WithdrawStakeWhitelisted {
         pool_tokens_in: u64,
         minimum_lamports_out: u64,
@@ -546,394 +691,2139 @@ pub enum StakeDepositInterceptorInstruction {
     )]
     #[account(5, name = "system_program", desc = "System program")]
     WithdrawFromHopper { amount: u64 },
-}
 
-pub const STAKE_POOL_DEPOSIT_STAKE_AUTHORITY: &[u8] = b"deposit_stake_authority";
-pub const DEPOSIT_RECEIPT: &[u8] = b"deposit_receipt";
+    ///   Deposit SOL into the pool. The "pool" token minted is held by the DepositReceipt's
+    ///   Vault token Account rather than a token Account designated by the depositor.
+    ///   Inputs are converted to the current ratio.
+    ///
+    ///   0. `[w,s]` payer of the new account rent
+    ///   1. `[]` stake pool program id
+    ///   2. `[w]` DepositReceipt to be created
+    ///   3. `[w]` Stake pool
+    ///   4. `[w]` Stake pool SOL deposit authority (aka the StakePoolDepositStakeAuthority PDA)
+    ///   5. `[s]` Base for PDA seed
+    ///   6. `[]` Stake pool withdraw authority
+    ///   7. `[w]` Reserve stake account, to deposit SOL
+    ///   8. `[w,s]` Account providing the lamports to be deposited into the pool
+    ///   9. `[w]` Vault account to receive pool tokens
+    ///   10. `[w]` Account to receive pool fee tokens
+    ///   11. `[w]` Account to receive a portion of pool fee tokens as referral fees
+    ///   12. `[w]` Pool token mint account
+    ///   13. `[]` Pool token program id
+    ///   14. `[]` System program id
+    #[account(0, writable, signer, name = "payer", desc = "Funding account")]
+    #[account(1, name = "stake_pool_program", desc = "Stake pool program id")]
+    #[account(
+        2,
+        writable,
+        name = "deposit_receipt",
+        desc = "PDA to store deposit receipt"
+    )]
+    #[account(3, writable, name = "stake_pool", desc = "StakePool to deposit into")]
+    #[account(
+        4,
+        writable,
+        name = "deposit_stake_authority",
+        desc = "StakePool sol_deposit_authority"
+    )]
+    #[account(5, signer, name = "base", desc = "Base for PDA seed")]
+    #[account(
+        6,
+        name = "stake_pool_withdraw_authority",
+        desc = "Stake pool withdraw authority"
+    )]
+    #[account(
+        7,
+        writable,
+        name = "reserve_stake_account",
+        desc = "Reserve stake account, to deposit SOL"
+    )]
+    #[account(
+        8,
+        writable,
+        signer,
+        name = "lamports_from",
+        desc = "Account providing the lamports to be deposited into the pool"
+    )]
+    #[account(
+        9,
+        writable,
+        name = "vault",
+        desc = "Vault account to receive pool tokens"
+    )]
+    #[account(
+        10,
+        writable,
+        name = "manager_fee_account",
+        desc = "Account to receive pool fee tokens"
+    )]
+    #[account(
+        11,
+        writable,
+        name = "referrer_pool_tokens_account",
+        desc = "Account to receive a portion of pool fee tokens as referral fees"
+    )]
+    #[account(12, writable, name = "pool_mint", desc = "Pool token mint account")]
+    #[account(13, name = "token_program", desc = "Pool token program id")]
+    #[account(14, name = "system_program", desc = "System program id")]
+    DepositSol(DepositSolArgs),
 
-/// Derive the StakePoolDepositStakeAuthority pubkey for a given program
-pub fn derive_stake_pool_deposit_stake_authority(
-    program_id: &Pubkey,
-    stake_pool: &Pubkey,
-    base: &Pubkey,
-) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[
-            STAKE_POOL_DEPOSIT_STAKE_AUTHORITY,
-            &stake_pool.to_bytes(),
-            &base.to_bytes(),
-        ],
-        program_id,
-    )
-}
+    ///   Deposit SOL with slippage protection. The "pool" token minted is held by the DepositReceipt's
+    ///   Vault token Account rather than a token Account designated by the depositor.
+    ///   Inputs are converted to the current ratio.
+    ///
+    ///   0. `[w,s]` payer of the new account rent
+    ///   1. `[]` stake pool program id
+    ///   2. `[w]` DepositReceipt to be created
+    ///   3. `[w]` Stake pool
+    ///   4. `[w]` Stake pool SOL deposit authority (aka the StakePoolDepositStakeAuthority PDA)
+    ///   5. `[s]` Base for PDA seed
+    ///   6. `[]` Stake pool withdraw authority
+    ///   7. `[w]` Reserve stake account, to deposit SOL
+    ///   8. `[w,s]` Account providing the lamports to be deposited into the pool
+    ///   9. `[w]` Vault account to receive pool tokens
+    ///   10. `[w]` Account to receive pool fee tokens
+    ///   11. `[w]` Account to receive a portion of pool fee tokens as referral fees
+    ///   12. `[w]` Pool token mint account
+    ///   13. `[]` Pool token program id
+    ///   14. `[]` System program id
+    #[account(0, writable, signer, name = "payer", desc = "Funding account")]
+    #[account(1, name = "stake_pool_program", desc = "Stake pool program id")]
+    #[account(
+        2,
+        writable,
+        name = "deposit_receipt",
+        desc = "PDA to store deposit receipt"
+    )]
+    #[account(3, writable, name = "stake_pool", desc = "StakePool to deposit into")]
+    #[account(
+        4,
+        writable,
+        name = "deposit_stake_authority",
+        desc = "StakePool sol_deposit_authority"
+    )]
+    #[account(5, signer, name = "base", desc = "Base for PDA seed")]
+    #[account(
+        6,
+        name = "stake_pool_withdraw_authority",
+        desc = "Stake pool withdraw authority"
+    )]
+    #[account(
+        7,
+        writable,
+        name = "reserve_stake_account",
+        desc = "Reserve stake account, to deposit SOL"
+    )]
+    #[account(
+        8,
+        writable,
+        signer,
+        name = "lamports_from",
+        desc = "Account providing the lamports to be deposited into the pool"
+    )]
+    #[account(
+        9,
+        writable,
+        name = "vault",
+        desc = "Vault account to receive pool tokens"
+    )]
+    #[account(
+        10,
+        writable,
+        name = "manager_fee_account",
+        desc = "Account to receive pool fee tokens"
+    )]
+    #[account(
+        11,
+        writable,
+        name = "referrer_pool_tokens_account",
+        desc = "Account to receive a portion of pool fee tokens as referral fees"
+    )]
+    #[account(12, writable, name = "pool_mint", desc = "Pool token mint account")]
+    #[account(13, name = "token_program", desc = "Pool token program id")]
+    #[account(14, name = "system_program", desc = "System program id")]
+    DepositSolWithSlippage(DepositSolWithSlippageArgs),
 
-/// Derive the DepositReceipt pubkey for a given program
-pub fn derive_stake_deposit_receipt(
-    program_id: &Pubkey,
-    stake_pool: &Pubkey,
-    base: &Pubkey,
-) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[DEPOSIT_RECEIPT, &stake_pool.to_bytes(), &base.to_bytes()],
-        program_id,
-    )
-}
+    ///   Claim `amount` of the "pool" tokens held by the program from a former deposit,
+    ///   leaving the remainder on the DepositReceipt. Fees are charged pro-rata on `amount`
+    ///   if this instruction is invoked during the cool down period. The DepositReceipt is
+    ///   closed once its balance reaches zero.
+    ///
+    ///   0. `[w]` DepositReceipt PDA
+    ///   1. `[w,s]` owner or delegate of the DepositReceipt
+    ///   2. `[w]` vault token account to send tokens from
+    ///   3. `[w]` destination token account
+    ///   4. `[w]` fee wallet token account
+    ///   5. `[w]` StakePoolDepositStakeAuthority PDA
+    ///   6. `[]` Pool token mint
+    ///   7. `[]` Token program id
+    ///   8. `[]` System program id
+    ///   9. `[w]` owner of the DepositReceipt, receiving its rent once closed. Required ONLY
+    ///      when account 1 is the delegate; a claim by the owner may omit it, and the
+    ///      accounts below then move up by one
+    ///   10. `[w]` (Optional) Referrer token account recorded on the DepositReceipt
+    ///   11. `[]` (Optional) FeeSplit of the StakePoolDepositStakeAuthority
+    ///   12..12+N. `[w]` (Optional) FeeSplit recipient token accounts, in FeeSplit order
+    ///
+    ///   When the StakePoolDepositStakeAuthority has a FeeSplit and a fee is owed, the fee is
+    ///   paid to the FeeSplit recipients instead of the fee wallet token account. The referrer
+    ///   account must then be present, using the program id as a placeholder when the
+    ///   DepositReceipt has no referrer.
+    #[account(
+        0,
+        writable,
+        name = "deposit_receipt",
+        desc = "PDA storing deposit receipt"
+    )]
+    #[account(
+        1,
+        writable,
+        signer,
+        name = "owner",
+        desc = "Owner or delegate of the receipt"
+    )]
+    #[account(2, writable, name = "vault", desc = "Vault token account")]
+    #[account(3, writable, name = "destination", desc = "Destination token account")]
+    #[account(4, writable, name = "fee_wallet", desc = "Fee wallet token account")]
+    #[account(
+        5,
+        writable,
+        name = "deposit_authority",
+        desc = "Deposit authority PDA"
+    )]
+    #[account(6, name = "pool_mint", desc = "Pool token mint")]
+    #[account(7, name = "token_program", desc = "Token program")]
+    #[account(8, name = "system_program", desc = "System program")]
+    #[account(
+        9,
+        writable,
+        name = "receipt_owner",
+        desc = "Owner of the receipt, receiving its rent once closed. Only required when the delegate claims"
+    )]
+    #[account(
+        10,
+        optional,
+        writable,
+        name = "referrer",
+        desc = "Referrer token account"
+    )]
+    ClaimPoolTokensPartial { amount: u64 },
 
-/// Creates instruction to set up the StakePoolDepositStakeAuthority to be used in the
-#[allow(clippy::too_many_arguments)]
-pub fn create_init_deposit_stake_authority_instruction(
-    program_id: &Pubkey,
-    payer: &Pubkey,
-    stake_pool: &Pubkey,
-    stake_pool_mint: &Pubkey,
-    stake_pool_program_id: &Pubkey,
-    token_program_id: &Pubkey,
-    fee_wallet: &Pubkey,
-    cool_down_seconds: u64,
-    initial_fee_bps: u32,
-    authority: &Pubkey,
-    base: &Pubkey,
-) -> Instruction {
-    let (deposit_stake_authority_pubkey, _bump_seed) =
-        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, base);
-    let vault_ata = get_associated_token_address(&deposit_stake_authority_pubkey, stake_pool_mint);
+    ///   Split `amount` of the "pool" tokens off an existing DepositReceipt into a new
+    ///   DepositReceipt for `new_owner`. Both receipts keep the original deposit time,
+    ///   cool down and initial fee rate. A whitelist discounted fee rate is reset for a
+    ///   different `new_owner` unless they are on the deposit Whitelist.
+    ///
+    ///   0. `[w,s]` payer of the new account rent
+    ///   1. `[w]` DepositReceipt PDA to split from
+    ///   2. `[s]` owner of the DepositReceipt
+    ///   3. `[w]` New DepositReceipt to be created
+    ///   4. `[s]` Base for the new DepositReceipt PDA seed
+    ///   5. `[]` owner of the new DepositReceipt
+    ///   6. `[]` System program id
+    ///   7. `[w]` StakePoolDepositStakeAuthority PDA of the DepositReceipt
+    ///   8. `[]` (Optional) Jito Whitelist. When the new owner is on it, the discount is kept.
+    #[account(0, writable, signer, name = "payer", desc = "Funding account")]
+    #[account(
+        1,
+        writable,
+        name = "deposit_receipt",
+        desc = "PDA storing deposit receipt to split from"
+    )]
+    #[account(2, signer, name = "owner", desc = "Owner of the receipt")]
+    #[account(
+        3,
+        writable,
+        name = "new_deposit_receipt",
+        desc = "PDA to store the new deposit receipt"
+    )]
+    #[account(
+        4,
+        signer,
+        name = "new_base",
+        desc = "Base for the new deposit receipt PDA seed"
+    )]
+    #[account(5, name = "new_owner", desc = "Owner of the new receipt")]
+    #[account(6, name = "system_program", desc = "System program")]
+    #[account(
+        7,
+        writable,
+        name = "deposit_authority",
+        desc = "Deposit authority PDA"
+    )]
+    #[account(
+        8,
+        optional,
+        name = "whitelist",
+        desc = "Optional Jito Whitelist the new owner is on"
+    )]
+    SplitDepositReceipt { amount: u64 },
+
+    ///   Fold the balance of one DepositReceipt into another owned by the same owner.
+    ///   The merged DepositReceipt keeps the later deposit time and the larger cool down
+    ///   and initial fee rate. The source DepositReceipt is closed and its rent refunded.
+    ///
+    ///   0. `[w]` DepositReceipt PDA to merge into
+    ///   1. `[w]` DepositReceipt PDA to merge from and close
+    ///   2. `[w,s]` owner of both DepositReceipts
+    ///   3. `[w]` StakePoolDepositStakeAuthority PDA of both DepositReceipts
+    #[account(
+        0,
+        writable,
+        name = "destination_deposit_receipt",
+        desc = "PDA storing deposit receipt to merge into"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "source_deposit_receipt",
+        desc = "PDA storing deposit receipt to merge from"
+    )]
+    #[account(2, writable, signer, name = "owner", desc = "Owner of both receipts")]
+    #[account(
+        3,
+        writable,
+        name = "deposit_authority",
+        desc = "Deposit authority PDA"
+    )]
+    MergeDepositReceipts,
+
+    ///   Pause the given operations on the StakePoolDepositStakeAuthority. `operations` is a
+    ///   bit set of the `StakePoolDepositStakeAuthority::PAUSE_*` flags.
+    ///
+    ///   0. `[w]` StakePoolDepositStakeAuthority PDA
+    ///   1. `[s]` Authority
+    #[account(
+        0,
+        writable,
+        name = "deposit_stake_authority",
+        desc = "PDA storing deposit authority data"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authority of the deposit authority"
+    )]
+    Pause { operations: u8 },
+
+    ///   Unpause the given operations on the StakePoolDepositStakeAuthority. `operations` is a
+    ///   bit set of the `StakePoolDepositStakeAuthority::PAUSE_*` flags.
+    ///
+    ///   0. `[w]` StakePoolDepositStakeAuthority PDA
+    ///   1. `[s]` Authority
+    #[account(
+        0,
+        writable,
+        name = "deposit_stake_authority",
+        desc = "PDA storing deposit authority data"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authority of the deposit authority"
+    )]
+    Unpause { operations: u8 },
+
+    ///   Propose a new authority for the StakePoolDepositStakeAuthority. The authority only
+    ///   changes once the proposed authority signs `AcceptAuthority`.
+    ///
+    ///   0. `[w]` StakePoolDepositStakeAuthority PDA
+    ///   1. `[s]` Authority
+    ///   2. `[]` Proposed new authority
+    #[account(
+        0,
+        writable,
+        name = "deposit_stake_authority",
+        desc = "PDA storing deposit authority data"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authority of the deposit authority"
+    )]
+    #[account(2, name = "pending_authority", desc = "Proposed new authority")]
+    ProposeAuthority,
+
+    ///   Accept a pending authority proposal, making the signer the authority of the
+    ///   StakePoolDepositStakeAuthority.
+    ///
+    ///   0. `[w]` StakePoolDepositStakeAuthority PDA
+    ///   1. `[s]` Pending authority
+    #[account(
+        0,
+        writable,
+        name = "deposit_stake_authority",
+        desc = "PDA storing deposit authority data"
+    )]
+    #[account(1, signer, name = "pending_authority", desc = "Proposed new authority")]
+    AcceptAuthority,
+
+    ///   Cancel a pending authority proposal.
+    ///
+    ///   0. `[w]` StakePoolDepositStakeAuthority PDA
+    ///   1. `[s]` Authority
+    #[account(
+        0,
+        writable,
+        name = "deposit_stake_authority",
+        desc = "PDA storing deposit authority data"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authority of the deposit authority"
+    )]
+    CancelAuthorityProposal,
+
+    ///   Close the StakePoolDepositStakeAuthority, its Vault, RebateVault and FeeSplit, returning
+    ///   the rent. Fails while either vault holds pool tokens or the StakePool still names the PDA
+    ///   as its stake or sol deposit authority, or as its sol withdraw authority.
+    ///
+    ///   0. `[w]` StakePoolDepositStakeAuthority PDA to close
+    ///   1. `[s]` Authority
+    ///   2. `[w]` Vault token account to close
+    ///   3. `[]` StakePool
+    ///   4. `[w]` Destination of the returned rent
+    ///   5. `[]` Token program
+    ///   6. `[w]` RebateVault PDA, closed if it was ever funded
+    ///   7. `[w]` FeeSplit PDA, closed if one is set
+    #[account(
+        0,
+        writable,
+        name = "deposit_stake_authority",
+        desc = "PDA storing deposit authority data"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authority of the deposit authority"
+    )]
+    #[account(2, writable, name = "vault", desc = "Vault token account to close")]
+    #[account(3, name = "stake_pool", desc = "StakePool")]
+    #[account(
+        4,
+        writable,
+        name = "destination",
+        desc = "Destination of the returned rent"
+    )]
+    #[account(5, name = "token_program", desc = "Token program")]
+    #[account(
+        6,
+        writable,
+        name = "rebate_vault",
+        desc = "RebateVault PDA, closed if it was ever funded"
+    )]
+    #[account(
+        7,
+        writable,
+        name = "fee_split",
+        desc = "FeeSplit PDA, closed if one is set"
+    )]
+    CloseDepositStakeAuthority,
+
+    ///   Transfer the Vault's pool tokens in excess of what open DepositReceipts are owed to
+    ///   the fee wallet token account. Fails if the Vault holds less than is owed.
+    ///
+    ///   0. `[]` StakePoolDepositStakeAuthority PDA
+    ///   1. `[s]` Authority
+    ///   2. `[w]` Vault token account
+    ///   3. `[w]` Fee wallet token account
+    ///   4. `[]` Pool token mint
+    ///   5. `[]` Token program
+    #[account(
+        0,
+        name = "deposit_stake_authority",
+        desc = "PDA storing deposit authority data"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authority of the deposit authority"
+    )]
+    #[account(2, writable, name = "vault", desc = "Vault token account")]
+    #[account(3, writable, name = "fee_wallet", desc = "Fee wallet token account")]
+    #[account(4, name = "pool_mint", desc = "Pool token mint")]
+    #[account(5, name = "token_program", desc = "Token program")]
+    SweepVaultSurplus,
+
+    ///   Distribute the claim-time fees across `recipients` in place of the fee wallet.
+    ///   Shares must sum to 100%. An empty `recipients` removes the FeeSplit, closing its
+    ///   account and refunding the payer.
+    ///
+    ///   0. `[w,s]` Payer of the FeeSplit account rent
+    ///   1. `[w]` StakePoolDepositStakeAuthority PDA
+    ///   2. `[s]` Authority
+    ///   3. `[w]` FeeSplit PDA
+    ///   4. `[]` System program
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer of the FeeSplit account rent"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "deposit_stake_authority",
+        desc = "PDA storing deposit authority data"
+    )]
+    #[account(
+        2,
+        signer,
+        name = "authority",
+        desc = "Authority of the deposit authority"
+    )]
+    #[account(3, writable, name = "fee_split", desc = "PDA storing the fee split")]
+    #[account(4, name = "system_program", desc = "System program")]
+    SetFeeSplit {
+        recipients: Vec<FeeSplitRecipientArgs>,
+    },
+
+    ///   Allow `delegate` to claim the DepositReceipt in place of the owner, including
+    ///   during the cool down period. The "pool" tokens are still paid to a token account
+    ///   of the owner. Replaces any existing delegate.
+    ///
+    ///   0. `[w]` DepositReceipt PDA
+    ///   1. `[s]` owner of the DepositReceipt
+    ///   2. `[]` delegate
+    #[account(
+        0,
+        writable,
+        name = "deposit_receipt",
+        desc = "PDA storing deposit receipt"
+    )]
+    #[account(1, signer, name = "owner", desc = "Owner of the receipt")]
+    #[account(2, name = "delegate", desc = "Key allowed to claim the receipt")]
+    ApproveClaimDelegate,
+
+    ///   Remove the claim delegate of the DepositReceipt.
+    ///
+    ///   0. `[w]` DepositReceipt PDA
+    ///   1. `[s]` owner of the DepositReceipt
+    #[account(
+        0,
+        writable,
+        name = "deposit_receipt",
+        desc = "PDA storing deposit receipt"
+    )]
+    #[account(1, signer, name = "owner", desc = "Owner of the receipt")]
+    RevokeClaimDelegate,
+
+    ///   Upgrade a StakePoolDepositStakeAuthority or DepositReceipt written with an older
+    ///   layout version to the current one. Permissionless. The payer funds any rent needed
+    ///   when the account is reallocated.
+    ///
+    ///   The authority of a StakePoolDepositStakeAuthority that predates `deposit_whitelist` and
+    ///   `withdraw_whitelist` may append itself and the Whitelist it has been using, binding
+    ///   both to that Whitelist. Otherwise both are left unset.
+    ///
+    ///   0. `[w,s]` Payer
+    ///   1. `[w]` StakePoolDepositStakeAuthority or DepositReceipt PDA
+    ///   2. `[]` System program
+    ///   3. `[s]` (Optional) StakePoolDepositStakeAuthority's authority
+    ///   4. `[]` (Optional) Whitelist to bind for whitelisted deposits and withdrawals
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Funding account for any additional rent"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "account",
+        desc = "StakePoolDepositStakeAuthority or DepositReceipt to upgrade"
+    )]
+    #[account(2, name = "system_program", desc = "System program")]
+    #[account(
+        3,
+        optional,
+        signer,
+        name = "authority",
+        desc = "Optional authority binding the whitelists of an older StakePoolDepositStakeAuthority"
+    )]
+    #[account(
+        4,
+        optional,
+        name = "whitelist",
+        desc = "Optional Whitelist bound as both the deposit_whitelist and withdraw_whitelist"
+    )]
+    MigrateAccount,
+
+    /// Deposits SOL directly into the spl-stake-pool — bypassing the Ticket/cooldown mechanism.
+    ///
+    ///   0. `[w,s]` Whitelisted Signer, funding the deposit
+    ///   1. `[]` Whitelist PDA
+    ///   2. `[w]` Stake pool account
+    ///   3. `[w]` StakePoolDepositStakeAuthority PDA
+    ///   4. `[]` Pool withdraw authority
+    ///   5. `[w]` Reserve stake account
+    ///   6. `[w]` Destination for minted pool token
+    ///   7. `[w]` Manager fee account
+    ///   8. `[w]` Referral fee account
+    ///   9. `[w]` Pool mint account
+    ///   10. `[]` Pool token program id
+    ///   11. `[]` SPL stake pool program id
+    ///   12. `[]` System program id
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "whitelisted_signer",
+        desc = "Must be present in the Whitelist.whitelist array"
+    )]
+    #[account(
+        1,
+        name = "whitelist",
+        desc = "Whitelist account from WhitelistManagementProgram"
+    )]
+    #[account(2, writable, name = "stake_pool", desc = "Stake pool account")]
+    #[account(
+        3,
+        writable,
+        name = "stake_deposit_authority",
+        desc = "Interceptor PDA - the SOL deposit authority on the pool"
+    )]
+    #[account(4, name = "withdraw_authority", desc = "Pool withdraw authority")]
+    #[account(5, writable, name = "reserve_stake", desc = "Reserve stake account")]
+    #[account(
+        6,
+        writable,
+        name = "pool_tokens_to",
+        desc = "Destination for minted pool token - goes directly to depositor, no Ticket"
+    )]
+    #[account(
+        7,
+        writable,
+        name = "manager_fee_account",
+        desc = "Manager fee account"
+    )]
+    #[account(
+        8,
+        writable,
+        name = "referral_fee_account",
+        desc = "Referral fee account"
+    )]
+    #[account(9, writable, name = "pool_mint", desc = "Pool token mint account")]
+    #[account(10, name = "token_program", desc = "Pool token program id")]
+    #[account(11, name = "spl_stake_pool_program", desc = "SPL Stake Pool Program")]
+    #[account(12, name = "system_program", desc = "System program")]
+    DepositSolWhitelisted {
+        lamports_in: u64,
+        minimum_pool_tokens_out: Option<u64>,
+    },
+
+    /// Wraps spl-stake-pool WithdrawSol with whitelist verification.
+    ///
+    ///   0. `[w,s]` Whitelisted Signer
+    ///   1. `[]` Whitelist PDA
+    ///   2. `[w]` Stake pool account
+    ///   3. `[w]` StakePoolDepositStakeAuthority PDA
+    ///   4. `[]` Pool withdraw authority
+    ///   5. `[s]` Authority over the pool token account
+    ///   6. `[w]` Pool token account (burned from)
+    ///   7. `[w]` Reserve stake account
+    ///   8. `[w]` Account receiving the withdrawn lamports
+    ///   9. `[w]` Manager fee account
+    ///   10. `[w]` Pool mint account
+    ///   11. `[w]` Pre-funded SOL account that covers the withdrawal fee rebate
+    ///   12. `[w]` Recipient of the fee rebate (the withdrawer)
+    ///   13. `[]` Clock
+    ///   14. `[]` Stake history
+    ///   15. `[]` Pool token program id
+    ///   16. `[]` Stake program id
+    ///   17. `[]` SPL stake pool program id
+    ///   18. `[]` System program id
+    ///   19. `[w]` (Optional) RebateBudget PDA of the whitelisted signer, required when the
+    ///       StakePoolDepositStakeAuthority has an `epoch_hopper_rebate_budget`
+    ///   20. `[w]` (Optional) RebateVault PDA, required when the StakePoolDepositStakeAuthority
+    ///       rebates with `RebateMode::RebateVault`
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "whitelisted_signer",
+        desc = "Must be present in the Whitelist.whitelist array"
+    )]
+    #[account(
+        1,
+        name = "whitelist",
+        desc = "Whitelist account from WhitelistManagementProgram"
+    )]
+    #[account(2, writable, name = "stake_pool", desc = "Stake pool account")]
+    #[account(
+        3,
+        writable,
+        name = "stake_deposit_authority",
+        desc = "Interceptor PDA - the SOL withdraw authority on the pool"
+    )]
+    #[account(4, name = "withdraw_authority", desc = "Pool withdraw authority")]
+    #[account(
+        5,
+        signer,
+        name = "user_transfer_authority",
+        desc = "Authority over the Pool token account"
+    )]
+    #[account(
+        6,
+        writable,
+        name = "user_pool_token_account",
+        desc = "Pool token account (burned from)"
+    )]
+    #[account(7, writable, name = "reserve_stake", desc = "Reserve stake account")]
+    #[account(
+        8,
+        writable,
+        name = "lamports_to",
+        desc = "Account receiving the withdrawn lamports"
+    )]
+    #[account(
+        9,
+        writable,
+        name = "manager_fee_account",
+        desc = "Manager fee account"
+    )]
+    #[account(10, writable, name = "pool_mint", desc = "Pool token mint account")]
+    #[account(
+        11,
+        writable,
+        name = "fee_rebate_hopper",
+        desc = "Pre-funded SOL account that covers the withdrawal fee rebate"
+    )]
+    #[account(
+        12,
+        writable,
+        name = "fee_rebate_recipient",
+        desc = "Recipient of the fee rebate (the withdrawer)"
+    )]
+    #[account(13, name = "clock", desc = "Sysvar clock account")]
+    #[account(14, name = "stake_history", desc = "Sysvar stake history account")]
+    #[account(15, name = "token_program", desc = "Pool token program id")]
+    #[account(16, name = "stake_program", desc = "Stake program id")]
+    #[account(17, name = "spl_stake_pool_program", desc = "SPL Stake Pool Program")]
+    #[account(18, name = "system_program", desc = "System program")]
+    #[account(
+        19,
+        optional,
+        writable,
+        name = "rebate_budget",
+        desc = "Optional RebateBudget PDA tracking the whitelisted signer's rebates per epoch"
+    )]
+    #[account(
+        20,
+        optional,
+        writable,
+        name = "rebate_vault",
+        desc = "Optional RebateVault PDA paying the fee rebate in pool tokens"
+    )]
+    WithdrawSolWhitelisted {
+        pool_tokens_in: u64,
+        minimum_lamports_out: u64,
+    },
+
+    ///   Transfer "pool" tokens into the RebateVault of the StakePoolDepositStakeAuthority,
+    ///   creating the RebateVault token account on its first funding. Permissionless.
+    ///
+    ///   0. `[w,s]` Funder, owner of the source token account and payer of the RebateVault rent
+    ///   1. `[]` StakePoolDepositStakeAuthority PDA
+    ///   2. `[w]` RebateVault PDA
+    ///   3. `[w]` Source pool token account
+    ///   4. `[]` Pool token mint
+    ///   5. `[]` Token program
+    ///   6. `[]` System program
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "funder",
+        desc = "Owner of the source token account and payer of the RebateVault rent"
+    )]
+    #[account(
+        1,
+        name = "deposit_stake_authority",
+        desc = "PDA storing deposit authority data"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "rebate_vault",
+        desc = "RebateVault PDA holding pool tokens for fee rebates"
+    )]
+    #[account(3, writable, name = "source", desc = "Source pool token account")]
+    #[account(4, name = "pool_mint", desc = "Pool token mint")]
+    #[account(5, name = "token_program", desc = "Token program")]
+    #[account(6, name = "system_program", desc = "System program")]
+    FundRebateVault { amount: u64 },
+
+    ///   Withdraw "pool" tokens from the RebateVault. Requires the deposit stake authority's
+    ///   authority.
+    ///
+    ///   0. `[s]` Authority (must match StakePoolDepositStakeAuthority.authority)
+    ///   1. `[]` StakePoolDepositStakeAuthority PDA
+    ///   2. `[w]` RebateVault PDA
+    ///   3. `[w]` Destination pool token account
+    ///   4. `[]` Pool token mint
+    ///   5. `[]` Token program
+    #[account(
+        0,
+        signer,
+        name = "authority",
+        desc = "Must match StakePoolDepositStakeAuthority.authority"
+    )]
+    #[account(
+        1,
+        name = "deposit_stake_authority",
+        desc = "PDA storing deposit authority data"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "rebate_vault",
+        desc = "RebateVault PDA holding pool tokens for fee rebates"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "destination",
+        desc = "Destination pool token account"
+    )]
+    #[account(4, name = "pool_mint", desc = "Pool token mint")]
+    #[account(5, name = "token_program", desc = "Token program")]
+    WithdrawFromRebateVault { amount: u64 },
+
+    ///   Cancel a DepositReceipt by withdrawing its "pool" tokens from the StakePool as stake.
+    ///   The fee ClaimPoolTokens would owe is paid in "pool" tokens first, then the rest is
+    ///   burned by the StakePool's WithdrawStake into `stake_split_to`, with the DepositReceipt
+    ///   owner as its stake and withdraw authority. The DepositReceipt is closed.
+    ///
+    ///   0. `[w]` DepositReceipt PDA
+    ///   1. `[w,s]` owner of the DepositReceipt
+    ///   2. `[w]` vault token account to burn "pool" tokens from
+    ///   3. `[w]` fee wallet token account
+    ///   4. `[w]` StakePoolDepositStakeAuthority PDA
+    ///   5. `[w]` Pool token mint
+    ///   6. `[]` Token program id
+    ///   7. `[]` System program id
+    ///   8. `[w]` Stake pool
+    ///   9. `[w]` Validator stake list storage account
+    ///   10. `[]` Stake pool withdraw authority
+    ///   11. `[w]` Validator or reserve stake account to split from
+    ///   12. `[w]` Uninitialized stake account to receive the withdrawal
+    ///   13. `[w]` Manager fee account
+    ///   14. `[]` Clock
+    ///   15. `[]` Stake program id
+    ///   16. `[]` SPL stake pool program id
+    ///   17. `[w]` (Optional) Referrer token account recorded on the DepositReceipt
+    ///   18. `[]` (Optional) FeeSplit of the StakePoolDepositStakeAuthority
+    ///   19..19+N. `[w]` (Optional) FeeSplit recipient token accounts, in FeeSplit order
+    #[account(
+        0,
+        writable,
+        name = "deposit_receipt",
+        desc = "PDA storing deposit receipt"
+    )]
+    #[account(1, writable, signer, name = "owner", desc = "Owner of the receipt")]
+    #[account(2, writable, name = "vault", desc = "Vault token account")]
+    #[account(3, writable, name = "fee_wallet", desc = "Fee wallet token account")]
+    #[account(
+        4,
+        writable,
+        name = "deposit_authority",
+        desc = "Deposit authority PDA"
+    )]
+    #[account(5, writable, name = "pool_mint", desc = "Pool token mint")]
+    #[account(6, name = "token_program", desc = "Token program")]
+    #[account(7, name = "system_program", desc = "System program")]
+    #[account(8, writable, name = "stake_pool", desc = "Stake pool account")]
+    #[account(9, writable, name = "validator_list", desc = "Validator List")]
+    #[account(10, name = "withdraw_authority", desc = "Pool withdraw authority")]
+    #[account(
+        11,
+        writable,
+        name = "stake_split_from",
+        desc = "Validator or reserve stake account to split from"
+    )]
+    #[account(
+        12,
+        writable,
+        name = "stake_split_to",
+        desc = "Uninitialized stake account to receive the withdrawal"
+    )]
+    #[account(
+        13,
+        writable,
+        name = "manager_fee_account",
+        desc = "Manager fee account"
+    )]
+    #[account(14, name = "clock", desc = "Sysvar clock account")]
+    #[account(15, name = "stake_program", desc = "Stake program id")]
+    #[account(16, name = "spl_stake_pool_program", desc = "SPL Stake Pool Program")]
+    #[account(
+        17,
+        optional,
+        writable,
+        name = "referrer",
+        desc = "Referrer token account"
+    )]
+    RedeemReceiptToStake { minimum_lamports_out: u64 },
+}
+
+pub const STAKE_POOL_DEPOSIT_STAKE_AUTHORITY: &[u8] = b"deposit_stake_authority";
+pub const DEPOSIT_RECEIPT: &[u8] = b"deposit_receipt";
+
+/// Derive the StakePoolDepositStakeAuthority pubkey for a given program
+pub fn derive_stake_pool_deposit_stake_authority(
+    program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    base: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            STAKE_POOL_DEPOSIT_STAKE_AUTHORITY,
+            &stake_pool.to_bytes(),
+            &base.to_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive the DepositReceipt pubkey for a given program
+pub fn derive_stake_deposit_receipt(
+    program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    base: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DEPOSIT_RECEIPT, &stake_pool.to_bytes(), &base.to_bytes()],
+        program_id,
+    )
+}
+
+/// Creates instruction to set up the StakePoolDepositStakeAuthority to be used in the
+#[allow(clippy::too_many_arguments)]
+pub fn create_init_deposit_stake_authority_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    stake_pool: &Pubkey,
+    stake_pool_mint: &Pubkey,
+    stake_pool_program_id: &Pubkey,
+    token_program_id: &Pubkey,
+    fee_wallet: &Pubkey,
+    cool_down_seconds: u64,
+    initial_fee_bps: u32,
+    fee_curve: FeeCurve,
+    cool_down_unit: CoolDownUnit,
+    authority: &Pubkey,
+    base: &Pubkey,
+) -> Instruction {
+    let (deposit_stake_authority_pubkey, _bump_seed) =
+        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, base);
+    let vault_ata = get_associated_token_address_with_program_id(
+        &deposit_stake_authority_pubkey,
+        stake_pool_mint,
+        token_program_id,
+    );
     let args = InitStakePoolDepositStakeAuthorityArgs {
         fee_wallet: *fee_wallet,
         initial_fee_bps,
         cool_down_seconds,
+        fee_curve,
+        cool_down_unit,
+    };
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(deposit_stake_authority_pubkey, false),
+        AccountMeta::new(vault_ata, false),
+        AccountMeta::new_readonly(*authority, false),
+        AccountMeta::new_readonly(*base, true),
+        AccountMeta::new_readonly(*stake_pool, false),
+        AccountMeta::new_readonly(*stake_pool_mint, false),
+        AccountMeta::new_readonly(*stake_pool_program_id, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(spl_associated_token_account_interface::program::id(), false),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(
+            &StakeDepositInterceptorInstruction::InitStakePoolDepositStakeAuthority(args),
+        )
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_update_deposit_stake_authority_instruction(
+    program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    authority: &Pubkey,
+    base: &Pubkey,
+    new_authority: Option<Pubkey>,
+    fee_wallet: Option<Pubkey>,
+    cool_down_seconds: Option<u64>,
+    initial_fee_bps: Option<u32>,
+    jito_whitelist_management_program_id: Option<Pubkey>,
+    fee_curve: Option<FeeCurve>,
+    whitelist_fee_discount_bps: Option<u32>,
+    max_deposit_lamports: Option<u64>,
+    epoch_deposit_cap: Option<u64>,
+    referrer_fee_share_bps: Option<u32>,
+    cool_down_unit: Option<CoolDownUnit>,
+    hopper_rebate_discount_bps: Option<u32>,
+    max_hopper_rebate_lamports: Option<u64>,
+    epoch_hopper_rebate_budget: Option<u64>,
+    rebate_mode: Option<RebateMode>,
+    deposit_whitelist: Option<Pubkey>,
+    withdraw_whitelist: Option<Pubkey>,
+) -> Instruction {
+    let (deposit_stake_authority_pubkey, _bump_seed) =
+        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, base);
+    let args = UpdateStakePoolDepositStakeAuthorityArgs {
+        fee_wallet,
+        initial_fee_bps,
+        cool_down_seconds,
+        jito_whitelist_management_program_id,
+        fee_curve,
+        whitelist_fee_discount_bps,
+        max_deposit_lamports,
+        epoch_deposit_cap,
+        referrer_fee_share_bps,
+        cool_down_unit,
+        hopper_rebate_discount_bps,
+        max_hopper_rebate_lamports,
+        epoch_hopper_rebate_budget,
+        rebate_mode,
+        deposit_whitelist,
+        withdraw_whitelist,
+    };
+    let mut accounts = vec![
+        AccountMeta::new(deposit_stake_authority_pubkey, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
+    if let Some(new_authority) = new_authority {
+        accounts.push(AccountMeta::new_readonly(new_authority, true));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(
+            &StakeDepositInterceptorInstruction::UpdateStakePoolDepositStakeAuthority(args),
+        )
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn deposit_stake_internal(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    stake_pool_program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    validator_list_storage: &Pubkey,
+    stake_pool_deposit_authority: &Pubkey,
+    stake_pool_withdraw_authority: &Pubkey,
+    deposit_stake_address: &Pubkey,
+    deposit_stake_withdraw_authority: &Pubkey,
+    validator_stake_account: &Pubkey,
+    reserve_stake_account: &Pubkey,
+    pool_tokens_to: &Pubkey,
+    manager_fee_account: &Pubkey,
+    referrer_pool_tokens_account: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    base: &Pubkey,
+    minimum_pool_tokens_out: Option<u64>,
+) -> Vec<Instruction> {
+    let (deposit_receipt_pubkey, _bump_seed) =
+        derive_stake_deposit_receipt(program_id, stake_pool, base);
+    let mut instructions = vec![];
+    let mut accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*stake_pool_program_id, false),
+        AccountMeta::new(deposit_receipt_pubkey, false),
+        AccountMeta::new(*stake_pool, false),
+        AccountMeta::new(*validator_list_storage, false),
+        // This is our PDA that will signed the CPI
+        AccountMeta::new(*stake_pool_deposit_authority, false),
+        AccountMeta::new_readonly(*base, true),
+    ];
+    // NOTE: Assumes the withdrawer and staker authorities are the same (i.e. `deposit_stake_withdraw_authority`).
+    instructions.extend_from_slice(&[
+        solana_stake_interface::instruction::authorize(
+            deposit_stake_address,
+            deposit_stake_withdraw_authority,
+            stake_pool_deposit_authority,
+            solana_stake_interface::state::StakeAuthorize::Staker,
+            None,
+        ),
+        solana_stake_interface::instruction::authorize(
+            deposit_stake_address,
+            deposit_stake_withdraw_authority,
+            stake_pool_deposit_authority,
+            solana_stake_interface::state::StakeAuthorize::Withdrawer,
+            None,
+        ),
+    ]);
+
+    accounts.extend_from_slice(&[
+        AccountMeta::new_readonly(*stake_pool_withdraw_authority, false),
+        AccountMeta::new(*deposit_stake_address, false),
+        AccountMeta::new(*validator_stake_account, false),
+        AccountMeta::new(*reserve_stake_account, false),
+        AccountMeta::new(*pool_tokens_to, false),
+        AccountMeta::new(*manager_fee_account, false),
+        AccountMeta::new(*referrer_pool_tokens_account, false),
+        AccountMeta::new(*pool_mint, false),
+        AccountMeta::new_readonly(solana_clock::sysvar::id(), false),
+        AccountMeta::new_readonly(solana_stake_interface::sysvar::stake_history::id(), false),
+        AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(solana_stake_interface::program::id(), false),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ]);
+    instructions.push(
+        if let Some(minimum_pool_tokens_out) = minimum_pool_tokens_out {
+            let args = DepositStakeWithSlippageArgs {
+                owner: *deposit_stake_withdraw_authority,
+                minimum_pool_tokens_out,
+            };
+            Instruction {
+                program_id: *program_id,
+                accounts,
+                data: borsh::to_vec(
+                    &StakeDepositInterceptorInstruction::DepositStakeWithSlippage(args),
+                )
+                .unwrap(),
+            }
+        } else {
+            let args = DepositStakeArgs {
+                owner: *deposit_stake_withdraw_authority,
+            };
+            Instruction {
+                program_id: *program_id,
+                accounts,
+                data: borsh::to_vec(&StakeDepositInterceptorInstruction::DepositStake(args))
+                    .unwrap(),
+            }
+        },
+    );
+    instructions
+}
+
+/// Creates instructions required to deposit into a stake pool, given a stake
+/// account owned by the user.
+#[allow(clippy::too_many_arguments)]
+pub fn create_deposit_stake_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    stake_pool_program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    validator_list_storage: &Pubkey,
+    stake_pool_withdraw_authority: &Pubkey,
+    deposit_stake_address: &Pubkey,
+    deposit_stake_withdraw_authority: &Pubkey,
+    validator_stake_account: &Pubkey,
+    reserve_stake_account: &Pubkey,
+    pool_tokens_to: &Pubkey,
+    manager_fee_account: &Pubkey,
+    referrer_pool_tokens_account: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    deposit_receipt_base: &Pubkey,
+    deposit_authority_base: &Pubkey,
+) -> Vec<Instruction> {
+    // The StakePool's deposit authority is assumed to be the PDA owned by
+    // the stake-deposit-interceptor program
+    let (deposit_stake_authority_pubkey, _bump_seed) =
+        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, deposit_authority_base);
+    deposit_stake_internal(
+        program_id,
+        payer,
+        stake_pool_program_id,
+        stake_pool,
+        validator_list_storage,
+        &deposit_stake_authority_pubkey,
+        stake_pool_withdraw_authority,
+        deposit_stake_address,
+        deposit_stake_withdraw_authority,
+        validator_stake_account,
+        reserve_stake_account,
+        pool_tokens_to,
+        manager_fee_account,
+        referrer_pool_tokens_account,
+        pool_mint,
+        token_program_id,
+        deposit_receipt_base,
+        None,
+    )
+}
+
+/// Creates instructions required to deposit into a stake pool, given a stake
+/// account owned by the user. StakePool program verifies the minimum tokens are minted.
+#[allow(clippy::too_many_arguments)]
+pub fn create_deposit_stake_with_slippage_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    stake_pool_program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    validator_list_storage: &Pubkey,
+    stake_pool_withdraw_authority: &Pubkey,
+    deposit_stake_address: &Pubkey,
+    deposit_stake_withdraw_authority: &Pubkey,
+    validator_stake_account: &Pubkey,
+    reserve_stake_account: &Pubkey,
+    pool_tokens_to: &Pubkey,
+    manager_fee_account: &Pubkey,
+    referrer_pool_tokens_account: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    deposit_receipt_base: &Pubkey,
+    deposit_authority_base: &Pubkey,
+    minimum_pool_tokens_out: u64,
+) -> Vec<Instruction> {
+    // The StakePool's deposit authority is assumed to be the PDA owned by
+    // the stake-deposit-interceptor program
+    let (deposit_stake_authority_pubkey, _bump_seed) =
+        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, deposit_authority_base);
+    deposit_stake_internal(
+        program_id,
+        payer,
+        stake_pool_program_id,
+        stake_pool,
+        validator_list_storage,
+        &deposit_stake_authority_pubkey,
+        stake_pool_withdraw_authority,
+        deposit_stake_address,
+        deposit_stake_withdraw_authority,
+        validator_stake_account,
+        reserve_stake_account,
+        pool_tokens_to,
+        manager_fee_account,
+        referrer_pool_tokens_account,
+        pool_mint,
+        token_program_id,
+        deposit_receipt_base,
+        Some(minimum_pool_tokens_out),
+    )
+}
+
+/// Creates instructions required to deposit into a stake pool, given a stake
+/// account owned by a user on the Jito `whitelist`. The DepositReceipt is stamped
+/// with the discounted whitelisted fee rate.
+#[allow(clippy::too_many_arguments)]
+pub fn create_deposit_stake_whitelisted_owner_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    stake_pool_program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    validator_list_storage: &Pubkey,
+    stake_pool_withdraw_authority: &Pubkey,
+    deposit_stake_address: &Pubkey,
+    deposit_stake_withdraw_authority: &Pubkey,
+    validator_stake_account: &Pubkey,
+    reserve_stake_account: &Pubkey,
+    pool_tokens_to: &Pubkey,
+    manager_fee_account: &Pubkey,
+    referrer_pool_tokens_account: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    deposit_receipt_base: &Pubkey,
+    deposit_authority_base: &Pubkey,
+    whitelist: &Pubkey,
+) -> Vec<Instruction> {
+    let mut instructions = create_deposit_stake_instruction(
+        program_id,
+        payer,
+        stake_pool_program_id,
+        stake_pool,
+        validator_list_storage,
+        stake_pool_withdraw_authority,
+        deposit_stake_address,
+        deposit_stake_withdraw_authority,
+        validator_stake_account,
+        reserve_stake_account,
+        pool_tokens_to,
+        manager_fee_account,
+        referrer_pool_tokens_account,
+        pool_mint,
+        token_program_id,
+        deposit_receipt_base,
+        deposit_authority_base,
+    );
+    // The DepositStake instruction follows the stake account authorizations
+    if let Some(deposit_stake_ix) = instructions.last_mut() {
+        deposit_stake_ix
+            .accounts
+            .push(AccountMeta::new_readonly(*whitelist, false));
+    }
+    instructions
+}
+
+/// Creates the Instruction to change the current owner of the DepositReceipt.
+pub fn create_change_deposit_receipt_owner(
+    program_id: &Pubkey,
+    deposit_receipt_address: &Pubkey,
+    owner: &Pubkey,
+    new_owner: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*deposit_receipt_address, false),
+        AccountMeta::new_readonly(*owner, true),
+        AccountMeta::new_readonly(*new_owner, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::ChangeDepositReceiptOwner)
+            .unwrap(),
+    }
+}
+
+/// Creates the Instruction to change the current owner of a whitelist discounted
+/// DepositReceipt. The discount is only kept when the new owner is on the `whitelist`.
+pub fn create_change_discounted_deposit_receipt_owner(
+    program_id: &Pubkey,
+    deposit_receipt_address: &Pubkey,
+    owner: &Pubkey,
+    new_owner: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    whitelist: Option<&Pubkey>,
+) -> Instruction {
+    let mut instruction =
+        create_change_deposit_receipt_owner(program_id, deposit_receipt_address, owner, new_owner);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*deposit_stake_authority, false));
+    if let Some(whitelist) = whitelist {
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(*whitelist, false));
+    }
+    instruction
+}
+
+#[allow(clippy::too_many_arguments)]
+fn claim_pool_tokens_internal(
+    program_id: &Pubkey,
+    deposit_receipt_address: &Pubkey,
+    owner: &Pubkey,
+    vault_token_account: &Pubkey,
+    destination_token_account: &Pubkey,
+    fee_token_account: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program: &Pubkey,
+    receipt_owner: Option<&Pubkey>,
+    after_cool_down: bool,
+    referrer: Option<&Pubkey>,
+    amount: Option<u64>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*deposit_receipt_address, false),
+        AccountMeta::new(*owner, !after_cool_down),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*destination_token_account, false),
+        AccountMeta::new(*fee_token_account, false),
+        AccountMeta::new(*deposit_stake_authority, false),
+        AccountMeta::new_readonly(*pool_mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    // Only a claim by the delegate names the DepositReceipt owner
+    if let Some(receipt_owner) = receipt_owner {
+        accounts.push(AccountMeta::new(*receipt_owner, false));
+    }
+    // The referrer slot is always filled so FeeSplit accounts can follow it
+    accounts.push(match referrer {
+        Some(referrer) => AccountMeta::new(*referrer, false),
+        None => AccountMeta::new_readonly(*program_id, false),
+    });
+    let data = if let Some(amount) = amount {
+        borsh::to_vec(&StakeDepositInterceptorInstruction::ClaimPoolTokensPartial { amount })
+            .unwrap()
+    } else {
+        borsh::to_vec(&StakeDepositInterceptorInstruction::ClaimPoolTokens).unwrap()
+    };
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    }
+}
+
+/// Creates a ClaimPoolTokens instruction to transfer the held "pool" tokens to
+/// destination token account. Also closes the DepositReceipt and refunds the owner.
+/// `referrer` is the DepositReceipt's `referrer`, if it has one.
+#[allow(clippy::too_many_arguments)]
+pub fn create_claim_pool_tokens_instruction(
+    program_id: &Pubkey,
+    deposit_receipt_address: &Pubkey,
+    owner: &Pubkey,
+    vault_token_account: &Pubkey,
+    destination_token_account: &Pubkey,
+    fee_token_account: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program: &Pubkey,
+    after_cool_down: bool,
+    referrer: Option<&Pubkey>,
+) -> Instruction {
+    claim_pool_tokens_internal(
+        program_id,
+        deposit_receipt_address,
+        owner,
+        vault_token_account,
+        destination_token_account,
+        fee_token_account,
+        deposit_stake_authority,
+        pool_mint,
+        token_program,
+        None,
+        after_cool_down,
+        referrer,
+        None,
+    )
+}
+
+/// Creates a ClaimPoolTokensPartial instruction to transfer `amount` of the held "pool"
+/// tokens to destination token account. The DepositReceipt is closed and the owner refunded
+/// only when the remaining balance reaches zero.
+/// `referrer` is the DepositReceipt's `referrer`, if it has one.
+#[allow(clippy::too_many_arguments)]
+pub fn create_claim_pool_tokens_partial_instruction(
+    program_id: &Pubkey,
+    deposit_receipt_address: &Pubkey,
+    owner: &Pubkey,
+    vault_token_account: &Pubkey,
+    destination_token_account: &Pubkey,
+    fee_token_account: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program: &Pubkey,
+    after_cool_down: bool,
+    referrer: Option<&Pubkey>,
+    amount: u64,
+) -> Instruction {
+    claim_pool_tokens_internal(
+        program_id,
+        deposit_receipt_address,
+        owner,
+        vault_token_account,
+        destination_token_account,
+        fee_token_account,
+        deposit_stake_authority,
+        pool_mint,
+        token_program,
+        None,
+        after_cool_down,
+        referrer,
+        Some(amount),
+    )
+}
+
+/// Creates a ClaimPoolTokens instruction signed by the DepositReceipt's `delegate`.
+/// Pass `amount` to claim only part of the held "pool" tokens. Once the DepositReceipt
+/// is closed, its rent is refunded to `owner` rather than the delegate.
+#[allow(clippy::too_many_arguments)]
+pub fn create_delegate_claim_pool_tokens_instruction(
+    program_id: &Pubkey,
+    deposit_receipt_address: &Pubkey,
+    delegate: &Pubkey,
+    owner: &Pubkey,
+    vault_token_account: &Pubkey,
+    destination_token_account: &Pubkey,
+    fee_token_account: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program: &Pubkey,
+    referrer: Option<&Pubkey>,
+    amount: Option<u64>,
+) -> Instruction {
+    claim_pool_tokens_internal(
+        program_id,
+        deposit_receipt_address,
+        delegate,
+        vault_token_account,
+        destination_token_account,
+        fee_token_account,
+        deposit_stake_authority,
+        pool_mint,
+        token_program,
+        Some(owner),
+        false,
+        referrer,
+        amount,
+    )
+}
+
+/// Creates a WithdrawFromHopper instruction to withdraw SOL from a hopper account.
+pub fn create_withdraw_from_hopper_instruction(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    stake_deposit_authority: &Pubkey,
+    whitelist: &Pubkey,
+    recipient: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (hopper, _, _) = crate::state::hopper::Hopper::find_program_address(
+        program_id,
+        whitelist,
+        stake_deposit_authority,
+    );
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(*stake_deposit_authority, false),
+        AccountMeta::new_readonly(*whitelist, false),
+        AccountMeta::new(hopper, false),
+        AccountMeta::new(*recipient, false),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::WithdrawFromHopper { amount })
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn deposit_sol_internal(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    stake_pool_program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    stake_pool_deposit_authority: &Pubkey,
+    stake_pool_withdraw_authority: &Pubkey,
+    reserve_stake_account: &Pubkey,
+    lamports_from: &Pubkey,
+    pool_tokens_to: &Pubkey,
+    manager_fee_account: &Pubkey,
+    referrer_pool_tokens_account: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    base: &Pubkey,
+    lamports_in: u64,
+    minimum_pool_tokens_out: Option<u64>,
+) -> Instruction {
+    let (deposit_receipt_pubkey, _bump_seed) =
+        derive_stake_deposit_receipt(program_id, stake_pool, base);
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*stake_pool_program_id, false),
+        AccountMeta::new(deposit_receipt_pubkey, false),
+        AccountMeta::new(*stake_pool, false),
+        // This is our PDA that will sign the CPI
+        AccountMeta::new(*stake_pool_deposit_authority, false),
+        AccountMeta::new_readonly(*base, true),
+        AccountMeta::new_readonly(*stake_pool_withdraw_authority, false),
+        AccountMeta::new(*reserve_stake_account, false),
+        AccountMeta::new(*lamports_from, true),
+        AccountMeta::new(*pool_tokens_to, false),
+        AccountMeta::new(*manager_fee_account, false),
+        AccountMeta::new(*referrer_pool_tokens_account, false),
+        AccountMeta::new(*pool_mint, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    let data = if let Some(minimum_pool_tokens_out) = minimum_pool_tokens_out {
+        let args = DepositSolWithSlippageArgs {
+            owner: *lamports_from,
+            lamports_in,
+            minimum_pool_tokens_out,
+        };
+        borsh::to_vec(&StakeDepositInterceptorInstruction::DepositSolWithSlippage(
+            args,
+        ))
+        .unwrap()
+    } else {
+        let args = DepositSolArgs {
+            owner: *lamports_from,
+            lamports_in,
+        };
+        borsh::to_vec(&StakeDepositInterceptorInstruction::DepositSol(args)).unwrap()
     };
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    }
+}
+
+/// Creates instruction required to deposit SOL into a stake pool. The `lamports_from`
+/// account becomes the owner of the DepositReceipt.
+#[allow(clippy::too_many_arguments)]
+pub fn create_deposit_sol_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    stake_pool_program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    stake_pool_withdraw_authority: &Pubkey,
+    reserve_stake_account: &Pubkey,
+    lamports_from: &Pubkey,
+    pool_tokens_to: &Pubkey,
+    manager_fee_account: &Pubkey,
+    referrer_pool_tokens_account: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    deposit_receipt_base: &Pubkey,
+    deposit_authority_base: &Pubkey,
+    lamports_in: u64,
+) -> Instruction {
+    // The StakePool's SOL deposit authority is assumed to be the PDA owned by
+    // the stake-deposit-interceptor program
+    let (deposit_stake_authority_pubkey, _bump_seed) =
+        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, deposit_authority_base);
+    deposit_sol_internal(
+        program_id,
+        payer,
+        stake_pool_program_id,
+        stake_pool,
+        &deposit_stake_authority_pubkey,
+        stake_pool_withdraw_authority,
+        reserve_stake_account,
+        lamports_from,
+        pool_tokens_to,
+        manager_fee_account,
+        referrer_pool_tokens_account,
+        pool_mint,
+        token_program_id,
+        deposit_receipt_base,
+        lamports_in,
+        None,
+    )
+}
+
+/// Creates instruction required to deposit SOL into a stake pool. StakePool
+/// program verifies the minimum tokens are minted.
+#[allow(clippy::too_many_arguments)]
+pub fn create_deposit_sol_with_slippage_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    stake_pool_program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    stake_pool_withdraw_authority: &Pubkey,
+    reserve_stake_account: &Pubkey,
+    lamports_from: &Pubkey,
+    pool_tokens_to: &Pubkey,
+    manager_fee_account: &Pubkey,
+    referrer_pool_tokens_account: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    deposit_receipt_base: &Pubkey,
+    deposit_authority_base: &Pubkey,
+    lamports_in: u64,
+    minimum_pool_tokens_out: u64,
+) -> Instruction {
+    // The StakePool's SOL deposit authority is assumed to be the PDA owned by
+    // the stake-deposit-interceptor program
+    let (deposit_stake_authority_pubkey, _bump_seed) =
+        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, deposit_authority_base);
+    deposit_sol_internal(
+        program_id,
+        payer,
+        stake_pool_program_id,
+        stake_pool,
+        &deposit_stake_authority_pubkey,
+        stake_pool_withdraw_authority,
+        reserve_stake_account,
+        lamports_from,
+        pool_tokens_to,
+        manager_fee_account,
+        referrer_pool_tokens_account,
+        pool_mint,
+        token_program_id,
+        deposit_receipt_base,
+        lamports_in,
+        Some(minimum_pool_tokens_out),
+    )
+}
+
+/// Creates a SplitDepositReceipt instruction to move `amount` of the "pool" tokens
+/// owed by an existing DepositReceipt to a new DepositReceipt derived from `new_base`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_split_deposit_receipt_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    deposit_receipt_address: &Pubkey,
+    owner: &Pubkey,
+    stake_pool: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    new_base: &Pubkey,
+    new_owner: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (new_deposit_receipt_pubkey, _bump_seed) =
+        derive_stake_deposit_receipt(program_id, stake_pool, new_base);
     let accounts = vec![
         AccountMeta::new(*payer, true),
-        AccountMeta::new(deposit_stake_authority_pubkey, false),
-        AccountMeta::new(vault_ata, false),
-        AccountMeta::new_readonly(*authority, false),
-        AccountMeta::new_readonly(*base, true),
-        AccountMeta::new_readonly(*stake_pool, false),
-        AccountMeta::new_readonly(*stake_pool_mint, false),
-        AccountMeta::new_readonly(*stake_pool_program_id, false),
-        AccountMeta::new_readonly(*token_program_id, false),
-        AccountMeta::new_readonly(spl_associated_token_account_interface::program::id(), false),
+        AccountMeta::new(*deposit_receipt_address, false),
+        AccountMeta::new_readonly(*owner, true),
+        AccountMeta::new(new_deposit_receipt_pubkey, false),
+        AccountMeta::new_readonly(*new_base, true),
+        AccountMeta::new_readonly(*new_owner, false),
         AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+        AccountMeta::new(*deposit_stake_authority, false),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: borsh::to_vec(
-            &StakeDepositInterceptorInstruction::InitStakePoolDepositStakeAuthority(args),
-        )
-        .unwrap(),
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::SplitDepositReceipt { amount })
+            .unwrap(),
     }
 }
 
+/// Creates a SplitDepositReceipt instruction for a whitelist discounted DepositReceipt whose
+/// `new_owner` is on the Jito `whitelist`, so the new DepositReceipt keeps the discount.
 #[allow(clippy::too_many_arguments)]
-pub fn create_update_deposit_stake_authority_instruction(
+pub fn create_split_deposit_receipt_whitelisted_owner_instruction(
     program_id: &Pubkey,
+    payer: &Pubkey,
+    deposit_receipt_address: &Pubkey,
+    owner: &Pubkey,
     stake_pool: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    new_base: &Pubkey,
+    new_owner: &Pubkey,
+    whitelist: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let mut instruction = create_split_deposit_receipt_instruction(
+        program_id,
+        payer,
+        deposit_receipt_address,
+        owner,
+        stake_pool,
+        deposit_stake_authority,
+        new_base,
+        new_owner,
+        amount,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*whitelist, false));
+    instruction
+}
+
+/// Creates a MergeDepositReceipts instruction to fold the source DepositReceipt into the
+/// destination DepositReceipt. Also closes the source DepositReceipt and refunds the owner.
+pub fn create_merge_deposit_receipts_instruction(
+    program_id: &Pubkey,
+    destination_deposit_receipt_address: &Pubkey,
+    source_deposit_receipt_address: &Pubkey,
+    owner: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*destination_deposit_receipt_address, false),
+        AccountMeta::new(*source_deposit_receipt_address, false),
+        AccountMeta::new(*owner, true),
+        AccountMeta::new(*deposit_stake_authority, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::MergeDepositReceipts).unwrap(),
+    }
+}
+
+/// Creates a Pause instruction to pause the given `StakePoolDepositStakeAuthority::PAUSE_*`
+/// operations.
+pub fn create_pause_instruction(
+    program_id: &Pubkey,
+    stake_deposit_authority: &Pubkey,
     authority: &Pubkey,
-    base: &Pubkey,
-    new_authority: Option<Pubkey>,
-    fee_wallet: Option<Pubkey>,
-    cool_down_seconds: Option<u64>,
-    initial_fee_bps: Option<u32>,
-    jito_whitelist_management_program_id: Option<Pubkey>,
+    operations: u8,
 ) -> Instruction {
-    let (deposit_stake_authority_pubkey, _bump_seed) =
-        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, base);
-    let args = UpdateStakePoolDepositStakeAuthorityArgs {
-        fee_wallet,
-        initial_fee_bps,
-        cool_down_seconds,
-        jito_whitelist_management_program_id,
-    };
-    let mut accounts = vec![
-        AccountMeta::new(deposit_stake_authority_pubkey, false),
+    let accounts = vec![
+        AccountMeta::new(*stake_deposit_authority, false),
         AccountMeta::new_readonly(*authority, true),
     ];
-    if let Some(new_authority) = new_authority {
-        accounts.push(AccountMeta::new_readonly(new_authority, false));
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::Pause { operations }).unwrap(),
     }
+}
+
+/// Creates an Unpause instruction to resume the given `StakePoolDepositStakeAuthority::PAUSE_*`
+/// operations.
+pub fn create_unpause_instruction(
+    program_id: &Pubkey,
+    stake_deposit_authority: &Pubkey,
+    authority: &Pubkey,
+    operations: u8,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*stake_deposit_authority, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: borsh::to_vec(
-            &StakeDepositInterceptorInstruction::UpdateStakePoolDepositStakeAuthority(args),
-        )
-        .unwrap(),
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::Unpause { operations }).unwrap(),
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn deposit_stake_internal(
+/// Creates a ProposeAuthority instruction to start handing the `StakePoolDepositStakeAuthority`
+/// over to `pending_authority`.
+pub fn create_propose_authority_instruction(
     program_id: &Pubkey,
-    payer: &Pubkey,
-    stake_pool_program_id: &Pubkey,
-    stake_pool: &Pubkey,
-    validator_list_storage: &Pubkey,
-    stake_pool_deposit_authority: &Pubkey,
-    stake_pool_withdraw_authority: &Pubkey,
-    deposit_stake_address: &Pubkey,
-    deposit_stake_withdraw_authority: &Pubkey,
-    validator_stake_account: &Pubkey,
-    reserve_stake_account: &Pubkey,
-    pool_tokens_to: &Pubkey,
-    manager_fee_account: &Pubkey,
-    referrer_pool_tokens_account: &Pubkey,
-    pool_mint: &Pubkey,
-    token_program_id: &Pubkey,
-    base: &Pubkey,
-    minimum_pool_tokens_out: Option<u64>,
-) -> Vec<Instruction> {
-    let (deposit_receipt_pubkey, _bump_seed) =
-        derive_stake_deposit_receipt(program_id, stake_pool, base);
-    let mut instructions = vec![];
-    let mut accounts = vec![
-        AccountMeta::new(*payer, true),
-        AccountMeta::new_readonly(*stake_pool_program_id, false),
-        AccountMeta::new(deposit_receipt_pubkey, false),
-        AccountMeta::new(*stake_pool, false),
-        AccountMeta::new(*validator_list_storage, false),
-        // This is our PDA that will signed the CPI
-        AccountMeta::new_readonly(*stake_pool_deposit_authority, false),
-        AccountMeta::new_readonly(*base, true),
+    stake_deposit_authority: &Pubkey,
+    authority: &Pubkey,
+    pending_authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*stake_deposit_authority, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(*pending_authority, false),
     ];
-    // NOTE: Assumes the withdrawer and staker authorities are the same (i.e. `deposit_stake_withdraw_authority`).
-    instructions.extend_from_slice(&[
-        solana_stake_interface::instruction::authorize(
-            deposit_stake_address,
-            deposit_stake_withdraw_authority,
-            stake_pool_deposit_authority,
-            solana_stake_interface::state::StakeAuthorize::Staker,
-            None,
-        ),
-        solana_stake_interface::instruction::authorize(
-            deposit_stake_address,
-            deposit_stake_withdraw_authority,
-            stake_pool_deposit_authority,
-            solana_stake_interface::state::StakeAuthorize::Withdrawer,
-            None,
-        ),
-    ]);
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::ProposeAuthority).unwrap(),
+    }
+}
 
-    accounts.extend_from_slice(&[
-        AccountMeta::new_readonly(*stake_pool_withdraw_authority, false),
-        AccountMeta::new(*deposit_stake_address, false),
-        AccountMeta::new(*validator_stake_account, false),
-        AccountMeta::new(*reserve_stake_account, false),
-        AccountMeta::new(*pool_tokens_to, false),
-        AccountMeta::new(*manager_fee_account, false),
-        AccountMeta::new(*referrer_pool_tokens_account, false),
-        AccountMeta::new(*pool_mint, false),
-        AccountMeta::new_readonly(solana_clock::sysvar::id(), false),
-        AccountMeta::new_readonly(solana_stake_interface::sysvar::stake_history::id(), false),
-        AccountMeta::new_readonly(*token_program_id, false),
-        AccountMeta::new_readonly(solana_stake_interface::program::id(), false),
-        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
-    ]);
-    instructions.push(
-        if let Some(minimum_pool_tokens_out) = minimum_pool_tokens_out {
-            let args = DepositStakeWithSlippageArgs {
-                owner: *deposit_stake_withdraw_authority,
-                minimum_pool_tokens_out,
-            };
-            Instruction {
-                program_id: *program_id,
-                accounts,
-                data: borsh::to_vec(
-                    &StakeDepositInterceptorInstruction::DepositStakeWithSlippage(args),
-                )
-                .unwrap(),
-            }
-        } else {
-            let args = DepositStakeArgs {
-                owner: *deposit_stake_withdraw_authority,
-            };
-            Instruction {
-                program_id: *program_id,
-                accounts,
-                data: borsh::to_vec(&StakeDepositInterceptorInstruction::DepositStake(args))
-                    .unwrap(),
-            }
-        },
-    );
-    instructions
+/// Creates an AcceptAuthority instruction to complete the handoff of the
+/// `StakePoolDepositStakeAuthority` to `pending_authority`.
+pub fn create_accept_authority_instruction(
+    program_id: &Pubkey,
+    stake_deposit_authority: &Pubkey,
+    pending_authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*stake_deposit_authority, false),
+        AccountMeta::new_readonly(*pending_authority, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::AcceptAuthority).unwrap(),
+    }
+}
+
+/// Creates a CancelAuthorityProposal instruction to clear the pending authority of the
+/// `StakePoolDepositStakeAuthority`.
+pub fn create_cancel_authority_proposal_instruction(
+    program_id: &Pubkey,
+    stake_deposit_authority: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*stake_deposit_authority, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::CancelAuthorityProposal).unwrap(),
+    }
+}
+
+/// Creates a CloseDepositStakeAuthority instruction to close the `StakePoolDepositStakeAuthority`,
+/// its Vault, RebateVault and FeeSplit, sending the rent to `destination`.
+pub fn create_close_deposit_stake_authority_instruction(
+    program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    base: &Pubkey,
+    authority: &Pubkey,
+    vault: &Pubkey,
+    destination: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    let (deposit_stake_authority_pubkey, _bump_seed) =
+        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, base);
+    let (rebate_vault, _bump_seed, _seeds) =
+        RebateVault::find_program_address(program_id, &deposit_stake_authority_pubkey);
+    let (fee_split, _bump_seed, _seeds) =
+        FeeSplit::find_program_address(program_id, &deposit_stake_authority_pubkey);
+    let accounts = vec![
+        AccountMeta::new(deposit_stake_authority_pubkey, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*stake_pool, false),
+        AccountMeta::new(*destination, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new(rebate_vault, false),
+        AccountMeta::new(fee_split, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::CloseDepositStakeAuthority)
+            .unwrap(),
+    }
 }
 
-/// Creates instructions required to deposit into a stake pool, given a stake
-/// account owned by the user.
-#[allow(clippy::too_many_arguments)]
-pub fn create_deposit_stake_instruction(
+/// Creates a SweepVaultSurplus instruction to move the Vault's pool tokens that no
+/// DepositReceipt is owed to the fee wallet's token account.
+pub fn create_sweep_vault_surplus_instruction(
     program_id: &Pubkey,
-    payer: &Pubkey,
-    stake_pool_program_id: &Pubkey,
-    stake_pool: &Pubkey,
-    validator_list_storage: &Pubkey,
-    stake_pool_withdraw_authority: &Pubkey,
-    deposit_stake_address: &Pubkey,
-    deposit_stake_withdraw_authority: &Pubkey,
-    validator_stake_account: &Pubkey,
-    reserve_stake_account: &Pubkey,
-    pool_tokens_to: &Pubkey,
-    manager_fee_account: &Pubkey,
-    referrer_pool_tokens_account: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    authority: &Pubkey,
+    vault: &Pubkey,
+    fee_token_account: &Pubkey,
     pool_mint: &Pubkey,
     token_program_id: &Pubkey,
-    deposit_receipt_base: &Pubkey,
-    deposit_authority_base: &Pubkey,
-) -> Vec<Instruction> {
-    // The StakePool's deposit authority is assumed to be the PDA owned by
-    // the stake-deposit-interceptor program
-    let (deposit_stake_authority_pubkey, _bump_seed) =
-        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, deposit_authority_base);
-    deposit_stake_internal(
-        program_id,
-        payer,
-        stake_pool_program_id,
-        stake_pool,
-        validator_list_storage,
-        &deposit_stake_authority_pubkey,
-        stake_pool_withdraw_authority,
-        deposit_stake_address,
-        deposit_stake_withdraw_authority,
-        validator_stake_account,
-        reserve_stake_account,
-        pool_tokens_to,
-        manager_fee_account,
-        referrer_pool_tokens_account,
-        pool_mint,
-        token_program_id,
-        deposit_receipt_base,
-        None,
-    )
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*deposit_stake_authority, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*fee_token_account, false),
+        AccountMeta::new_readonly(*pool_mint, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::SweepVaultSurplus).unwrap(),
+    }
 }
 
-/// Creates instructions required to deposit into a stake pool, given a stake
-/// account owned by the user. StakePool program verifies the minimum tokens are minted.
-#[allow(clippy::too_many_arguments)]
-pub fn create_deposit_stake_with_slippage_instruction(
+/// Creates a SetFeeSplit instruction distributing the claim-time fees of the
+/// `StakePoolDepositStakeAuthority` across `recipients`. An empty `recipients` removes the
+/// FeeSplit.
+pub fn create_set_fee_split_instruction(
     program_id: &Pubkey,
     payer: &Pubkey,
-    stake_pool_program_id: &Pubkey,
-    stake_pool: &Pubkey,
-    validator_list_storage: &Pubkey,
-    stake_pool_withdraw_authority: &Pubkey,
-    deposit_stake_address: &Pubkey,
-    deposit_stake_withdraw_authority: &Pubkey,
-    validator_stake_account: &Pubkey,
-    reserve_stake_account: &Pubkey,
-    pool_tokens_to: &Pubkey,
-    manager_fee_account: &Pubkey,
-    referrer_pool_tokens_account: &Pubkey,
-    pool_mint: &Pubkey,
-    token_program_id: &Pubkey,
-    deposit_receipt_base: &Pubkey,
-    deposit_authority_base: &Pubkey,
-    minimum_pool_tokens_out: u64,
-) -> Vec<Instruction> {
-    // The StakePool's deposit authority is assumed to be the PDA owned by
-    // the stake-deposit-interceptor program
-    let (deposit_stake_authority_pubkey, _bump_seed) =
-        derive_stake_pool_deposit_stake_authority(program_id, stake_pool, deposit_authority_base);
-    deposit_stake_internal(
-        program_id,
-        payer,
-        stake_pool_program_id,
-        stake_pool,
-        validator_list_storage,
-        &deposit_stake_authority_pubkey,
-        stake_pool_withdraw_authority,
-        deposit_stake_address,
-        deposit_stake_withdraw_authority,
-        validator_stake_account,
-        reserve_stake_account,
-        pool_tokens_to,
-        manager_fee_account,
-        referrer_pool_tokens_account,
-        pool_mint,
-        token_program_id,
-        deposit_receipt_base,
-        Some(minimum_pool_tokens_out),
-    )
+    deposit_stake_authority: &Pubkey,
+    authority: &Pubkey,
+    recipients: Vec<FeeSplitRecipientArgs>,
+) -> Instruction {
+    let (fee_split, _bump_seed, _seeds) =
+        FeeSplit::find_program_address(program_id, deposit_stake_authority);
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(*deposit_stake_authority, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(fee_split, false),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::SetFeeSplit { recipients })
+            .unwrap(),
+    }
 }
 
-/// Creates the Instruction to change the current owner of the DepositReceipt.
-pub fn create_change_deposit_receipt_owner(
+/// Appends the FeeSplit accounts to a ClaimPoolTokens, ClaimPoolTokensPartial or
+/// RedeemReceiptToStake instruction. `recipient_token_accounts` must follow the order of the FeeSplit recipients.
+pub fn append_fee_split_accounts(
+    program_id: &Pubkey,
+    claim_ix: &mut Instruction,
+    deposit_stake_authority: &Pubkey,
+    recipient_token_accounts: &[Pubkey],
+) {
+    let (fee_split, _bump_seed, _seeds) =
+        FeeSplit::find_program_address(program_id, deposit_stake_authority);
+    claim_ix
+        .accounts
+        .push(AccountMeta::new_readonly(fee_split, false));
+    claim_ix.accounts.extend(
+        recipient_token_accounts
+            .iter()
+            .map(|token_account| AccountMeta::new(*token_account, false)),
+    );
+}
+
+/// Creates an ApproveClaimDelegate instruction allowing `delegate` to claim the
+/// DepositReceipt on behalf of its owner.
+pub fn create_approve_claim_delegate_instruction(
     program_id: &Pubkey,
     deposit_receipt_address: &Pubkey,
     owner: &Pubkey,
-    new_owner: &Pubkey,
+    delegate: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*deposit_receipt_address, false),
         AccountMeta::new_readonly(*owner, true),
-        AccountMeta::new_readonly(*new_owner, false),
+        AccountMeta::new_readonly(*delegate, false),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: borsh::to_vec(&StakeDepositInterceptorInstruction::ChangeDepositReceiptOwner)
-            .unwrap(),
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::ApproveClaimDelegate).unwrap(),
     }
 }
 
-/// Creates a ClaimPoolTokens instruction to transfer the held "pool" tokens to
-/// destination token account. Also closes the DepositReceipt and refunds the owner.
-#[allow(clippy::too_many_arguments)]
-pub fn create_claim_pool_tokens_instruction(
+/// Creates a RevokeClaimDelegate instruction removing the claim delegate of the DepositReceipt.
+pub fn create_revoke_claim_delegate_instruction(
     program_id: &Pubkey,
     deposit_receipt_address: &Pubkey,
     owner: &Pubkey,
-    vault_token_account: &Pubkey,
-    destination_token_account: &Pubkey,
-    fee_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*deposit_receipt_address, false),
+        AccountMeta::new_readonly(*owner, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::RevokeClaimDelegate).unwrap(),
+    }
+}
+
+/// Creates a MigrateAccount instruction upgrading a StakePoolDepositStakeAuthority or
+/// DepositReceipt to the current layout version.
+pub fn create_migrate_account_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    account: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::MigrateAccount).unwrap(),
+    }
+}
+
+/// Creates a MigrateAccount instruction upgrading a StakePoolDepositStakeAuthority that predates
+/// `deposit_whitelist` and `withdraw_whitelist`, binding both to `whitelist`.
+pub fn create_migrate_deposit_stake_authority_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    authority: &Pubkey,
+    whitelist: &Pubkey,
+) -> Instruction {
+    let mut instruction =
+        create_migrate_account_instruction(program_id, payer, deposit_stake_authority);
+    instruction.accounts.extend([
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(*whitelist, false),
+    ]);
+    instruction
+}
+
+/// Creates a FundRebateVault instruction transferring `amount` pool tokens from `source` into
+/// the RebateVault of the StakePoolDepositStakeAuthority.
+pub fn create_fund_rebate_vault_instruction(
+    program_id: &Pubkey,
+    funder: &Pubkey,
     deposit_stake_authority: &Pubkey,
+    source: &Pubkey,
     pool_mint: &Pubkey,
-    token_program: &Pubkey,
-    after_cool_down: bool,
+    token_program_id: &Pubkey,
+    amount: u64,
 ) -> Instruction {
+    let (rebate_vault, _bump_seed, _seeds) =
+        RebateVault::find_program_address(program_id, deposit_stake_authority);
     let accounts = vec![
-        AccountMeta::new(*deposit_receipt_address, false),
-        AccountMeta::new(*owner, !after_cool_down),
-        AccountMeta::new(*vault_token_account, false),
-        AccountMeta::new(*destination_token_account, false),
-        AccountMeta::new(*fee_token_account, false),
+        AccountMeta::new(*funder, true),
         AccountMeta::new_readonly(*deposit_stake_authority, false),
+        AccountMeta::new(rebate_vault, false),
+        AccountMeta::new(*source, false),
         AccountMeta::new_readonly(*pool_mint, false),
-        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(*token_program_id, false),
         AccountMeta::new_readonly(solana_system_interface::program::id(), false),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: borsh::to_vec(&StakeDepositInterceptorInstruction::ClaimPoolTokens).unwrap(),
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::FundRebateVault { amount })
+            .unwrap(),
     }
 }
 
-/// Creates a WithdrawFromHopper instruction to withdraw SOL from a hopper account.
-pub fn create_withdraw_from_hopper_instruction(
+/// Creates a WithdrawFromRebateVault instruction transferring `amount` pool tokens from the
+/// RebateVault to `destination`.
+pub fn create_withdraw_from_rebate_vault_instruction(
     program_id: &Pubkey,
     authority: &Pubkey,
-    stake_deposit_authority: &Pubkey,
-    whitelist: &Pubkey,
-    recipient: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    destination: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program_id: &Pubkey,
     amount: u64,
 ) -> Instruction {
-    let (hopper, _, _) = crate::state::hopper::Hopper::find_program_address(
-        program_id,
-        whitelist,
-        stake_deposit_authority,
-    );
+    let (rebate_vault, _bump_seed, _seeds) =
+        RebateVault::find_program_address(program_id, deposit_stake_authority);
     let accounts = vec![
         AccountMeta::new_readonly(*authority, true),
-        AccountMeta::new_readonly(*stake_deposit_authority, false),
-        AccountMeta::new_readonly(*whitelist, false),
-        AccountMeta::new(hopper, false),
-        AccountMeta::new(*recipient, false),
+        AccountMeta::new_readonly(*deposit_stake_authority, false),
+        AccountMeta::new(rebate_vault, false),
+        AccountMeta::new(*destination, false),
+        AccountMeta::new_readonly(*pool_mint, false),
+        AccountMeta::new_readonly(*token_program_id, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: borsh::to_vec(
+            &StakeDepositInterceptorInstruction::WithdrawFromRebateVault { amount },
+        )
+        .unwrap(),
+    }
+}
+
+/// Creates a RedeemReceiptToStake instruction withdrawing the "pool" tokens of a
+/// DepositReceipt, net of its fee, into `stake_split_to`, an uninitialized stake account
+/// created beforehand. `referrer` is the DepositReceipt's `referrer`, if it has one.
+#[allow(clippy::too_many_arguments)]
+pub fn create_redeem_receipt_to_stake_instruction(
+    program_id: &Pubkey,
+    deposit_receipt_address: &Pubkey,
+    owner: &Pubkey,
+    vault_token_account: &Pubkey,
+    fee_token_account: &Pubkey,
+    deposit_stake_authority: &Pubkey,
+    pool_mint: &Pubkey,
+    token_program: &Pubkey,
+    stake_pool_program_id: &Pubkey,
+    stake_pool: &Pubkey,
+    validator_list_storage: &Pubkey,
+    stake_pool_withdraw_authority: &Pubkey,
+    stake_split_from: &Pubkey,
+    stake_split_to: &Pubkey,
+    manager_fee_account: &Pubkey,
+    referrer: Option<&Pubkey>,
+    minimum_lamports_out: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*deposit_receipt_address, false),
+        AccountMeta::new(*owner, true),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*fee_token_account, false),
+        AccountMeta::new(*deposit_stake_authority, false),
+        AccountMeta::new(*pool_mint, false),
+        AccountMeta::new_readonly(*token_program, false),
         AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+        AccountMeta::new(*stake_pool, false),
+        AccountMeta::new(*validator_list_storage, false),
+        AccountMeta::new_readonly(*stake_pool_withdraw_authority, false),
+        AccountMeta::new(*stake_split_from, false),
+        AccountMeta::new(*stake_split_to, false),
+        AccountMeta::new(*manager_fee_account, false),
+        AccountMeta::new_readonly(solana_clock::sysvar::id(), false),
+        AccountMeta::new_readonly(solana_stake_interface::program::id(), false),
+        AccountMeta::new_readonly(*stake_pool_program_id, false),
+        // The referrer slot is always filled so FeeSplit accounts can follow it
+        match referrer {
+            Some(referrer) => AccountMeta::new(*referrer, false),
+            None => AccountMeta::new_readonly(*program_id, false),
+        },
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: borsh::to_vec(&StakeDepositInterceptorInstruction::WithdrawFromHopper { amount })
-            .unwrap(),
+        data: borsh::to_vec(&StakeDepositInterceptorInstruction::RedeemReceiptToStake {
+            minimum_lamports_out,
+        })
+        .unwrap(),
     }
 }
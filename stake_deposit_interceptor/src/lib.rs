@@ -3,9 +3,11 @@ use solana_program::declare_id;
 use solana_security_txt::security_txt;
 
 pub mod error;
+pub mod events;
 pub mod instruction;
 pub mod macros;
 pub mod processor;
+pub mod return_data;
 pub mod state;
 
 pub const BASIS_POINTS_MAX: u16 = 10_000;
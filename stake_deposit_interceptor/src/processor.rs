@@ -1,6 +1,7 @@
 use std::mem;
 
 use borsh::BorshDeserialize;
+use bytemuck::Zeroable;
 use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_whitelist_management_core::whitelist::Whitelist;
 use solana_program::{
@@ -12,29 +13,38 @@ use solana_program::{
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
-    program_pack::Pack,
     pubkey::Pubkey,
     rent::Rent,
     sysvar::Sysvar,
 };
 use solana_system_interface::instruction::transfer;
-use spl_associated_token_account_interface::address::get_associated_token_address;
+use spl_associated_token_account_interface::address::get_associated_token_address_with_program_id;
 use spl_stake_pool::state::{is_extension_supported_for_fee_account, StakePool};
 use spl_token_2022_interface::{
-    extension::{BaseStateWithExtensions, StateWithExtensions},
-    state::{Account, AccountState},
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::{Account, AccountState, Mint},
 };
 
 use crate::{
     deposit_receipt_signer_seeds, deposit_stake_authority_signer_seeds,
     error::StakeDepositInterceptorError,
+    events::StakeDepositInterceptorEvent,
     instruction::{
-        derive_stake_deposit_receipt, derive_stake_pool_deposit_stake_authority, DepositStakeArgs,
-        InitStakePoolDepositStakeAuthorityArgs, StakeDepositInterceptorInstruction,
-        UpdateStakePoolDepositStakeAuthorityArgs, DEPOSIT_RECEIPT,
-        STAKE_POOL_DEPOSIT_STAKE_AUTHORITY,
+        derive_stake_deposit_receipt, derive_stake_pool_deposit_stake_authority, DepositSolArgs,
+        DepositStakeArgs, FeeSplitRecipientArgs, InitStakePoolDepositStakeAuthorityArgs,
+        StakeDepositInterceptorInstruction, UpdateStakePoolDepositStakeAuthorityArgs,
+        DEPOSIT_RECEIPT, STAKE_POOL_DEPOSIT_STAKE_AUTHORITY,
+    },
+    return_data::{ClaimReturnData, DepositReturnData},
+    state::{
+        account_version,
+        fee_split::{FeeSplit, FeeSplitRecipient},
+        hopper::Hopper,
+        rebate_budget::RebateBudget,
+        rebate_vault::RebateVault,
+        DepositReceipt, RebateMode, StakePoolDepositStakeAuthority, VersionedAccount,
+        ACCOUNT_VERSION_OFFSET,
     },
-    state::{hopper::Hopper, DepositReceipt, StakePoolDepositStakeAuthority},
 };
 
 pub struct Processor;
@@ -80,6 +90,11 @@ impl Processor {
             return Err(StakeDepositInterceptorError::InitialFeeRateMaxExceeded.into());
         }
 
+        // Validate: fee curve parameters must fit the cool down period
+        init_deposit_stake_authority_args
+            .fee_curve
+            .check(init_deposit_stake_authority_args.cool_down_seconds)?;
+
         // Validate: StakePool must be owned by the correct program
         if stake_pool_info.owner != stake_pool_program_info.key {
             return Err(StakeDepositInterceptorError::InvalidStakePool.into());
@@ -102,6 +117,10 @@ impl Processor {
             return Err(StakeDepositInterceptorError::InvalidTokenProgram.into());
         }
 
+        // Validate: Token-2022 pool mints must not carry extensions that let a third party move,
+        // hide or block the Vault's "pool" tokens
+        check_pool_mint_extensions(stake_pool_mint_info)?;
+
         let (deposit_stake_authority_pda, bump_seed) = derive_stake_pool_deposit_stake_authority(
             program_id,
             stake_pool_info.key,
@@ -129,8 +148,11 @@ impl Processor {
             &pda_seeds,
         )?;
 
-        let vault_ata =
-            get_associated_token_address(&deposit_stake_authority_pda, &stake_pool.pool_mint);
+        let vault_ata = get_associated_token_address_with_program_id(
+            &deposit_stake_authority_pda,
+            &stake_pool.pool_mint,
+            token_program_info.key,
+        );
 
         // Validate: Vault must be the ATA for the StakePoolDepositStakeAuthority PDA
         if vault_ata != *vault_ata_info.key {
@@ -159,10 +181,11 @@ impl Processor {
         let mut deposit_stake_authority_data =
             deposit_stake_authority_info.try_borrow_mut_data()?;
         deposit_stake_authority_data[0] = StakePoolDepositStakeAuthority::DISCRIMINATOR;
-        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_unchecked_mut(
+        deposit_stake_authority_data[ACCOUNT_VERSION_OFFSET] =
+            StakePoolDepositStakeAuthority::VERSION;
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned_mut(
             &mut deposit_stake_authority_data,
-        )
-        .unwrap();
+        )?;
 
         // Set StakePoolDepositStakeAuthority values
         deposit_stake_authority.base = *base_info.key;
@@ -176,12 +199,17 @@ impl Processor {
             init_deposit_stake_authority_args.cool_down_seconds.into();
         deposit_stake_authority.inital_fee_bps =
             init_deposit_stake_authority_args.initial_fee_bps.into();
+        deposit_stake_authority.fee_curve = init_deposit_stake_authority_args.fee_curve.into();
+        deposit_stake_authority.cool_down_unit =
+            init_deposit_stake_authority_args.cool_down_unit.into();
         deposit_stake_authority.bump_seed = bump_seed;
+        deposit_stake_authority.receipt_accounting_complete = 1;
 
         Ok(())
     }
 
-    /// Update `StakePoolDepositStakeAuthority` authority, fee_wallet, cool_down_seconds, and/or initial_fee_bps.
+    /// Update `StakePoolDepositStakeAuthority` authority, fee_wallet, cool_down_seconds, initial_fee_bps,
+    /// fee_curve and/or cool_down_unit.
     /// ONLY accessible by the currnet authority.
     pub fn process_update_deposit_stake_authority(
         program_id: &Pubkey,
@@ -191,7 +219,10 @@ impl Processor {
         let account_info_iter = &mut accounts.iter();
         let deposit_stake_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
         let authority_info = next_account_info(account_info_iter)?;
-        let new_authority_info = next_account_info(account_info_iter).ok();
+        // Generated clients pass the program id in place of an omitted optional account
+        let new_authority_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|new_authority_info| new_authority_info.key != program_id);
 
         // Validate: program owns `StakePoolDepositStakeAuthority`
         check_account_owner(deposit_stake_authority_info, program_id)?;
@@ -208,10 +239,9 @@ impl Processor {
 
         let mut deposit_stake_authority_data =
             deposit_stake_authority_info.try_borrow_mut_data()?;
-        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_unchecked_mut(
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned_mut(
             &mut deposit_stake_authority_data,
-        )
-        .unwrap();
+        )?;
 
         check_deposit_stake_authority_address(
             program_id,
@@ -225,7 +255,13 @@ impl Processor {
         }
 
         if let Some(new_authority) = new_authority_info {
+            // Validate: the new authority must sign to accept the transfer
+            if !new_authority.is_signer {
+                return Err(StakeDepositInterceptorError::SignatureMissing.into());
+            }
             deposit_stake_authority.authority = *new_authority.key;
+            // A direct transfer supersedes any pending proposal
+            deposit_stake_authority.pending_authority = Pubkey::default();
         }
 
         if let Some(cool_down_seconds) = update_deposit_stake_authority_args.cool_down_seconds {
@@ -249,6 +285,75 @@ impl Processor {
                 jito_whitelist_management_program_id;
         }
 
+        if let Some(fee_curve) = update_deposit_stake_authority_args.fee_curve {
+            deposit_stake_authority.fee_curve = fee_curve.into();
+        }
+        if let Some(cool_down_unit) = update_deposit_stake_authority_args.cool_down_unit {
+            deposit_stake_authority.cool_down_unit = cool_down_unit.into();
+        }
+        if let Some(whitelist_fee_discount_bps) =
+            update_deposit_stake_authority_args.whitelist_fee_discount_bps
+        {
+            // Validate: `whitelist_fee_discount_bps` cannot exceed 100%
+            if whitelist_fee_discount_bps.gt(&DepositReceipt::FEE_BPS_DENOMINATOR) {
+                return Err(StakeDepositInterceptorError::WhitelistFeeDiscountMaxExceeded.into());
+            }
+            deposit_stake_authority.whitelist_fee_discount_bps = whitelist_fee_discount_bps.into();
+        }
+        if let Some(max_deposit_lamports) = update_deposit_stake_authority_args.max_deposit_lamports
+        {
+            deposit_stake_authority.max_deposit_lamports = max_deposit_lamports.into();
+        }
+        if let Some(epoch_deposit_cap) = update_deposit_stake_authority_args.epoch_deposit_cap {
+            deposit_stake_authority.epoch_deposit_cap = epoch_deposit_cap.into();
+        }
+        if let Some(referrer_fee_share_bps) =
+            update_deposit_stake_authority_args.referrer_fee_share_bps
+        {
+            // Validate: `referrer_fee_share_bps` cannot exceed 100%
+            if referrer_fee_share_bps.gt(&DepositReceipt::FEE_BPS_DENOMINATOR) {
+                return Err(StakeDepositInterceptorError::ReferrerFeeShareMaxExceeded.into());
+            }
+            deposit_stake_authority.referrer_fee_share_bps = referrer_fee_share_bps.into();
+        }
+        if let Some(hopper_rebate_discount_bps) =
+            update_deposit_stake_authority_args.hopper_rebate_discount_bps
+        {
+            // Validate: `hopper_rebate_discount_bps` cannot exceed 100%
+            if hopper_rebate_discount_bps.gt(&DepositReceipt::FEE_BPS_DENOMINATOR) {
+                return Err(StakeDepositInterceptorError::HopperRebateDiscountMaxExceeded.into());
+            }
+            deposit_stake_authority.hopper_rebate_discount_bps = hopper_rebate_discount_bps.into();
+        }
+        if let Some(max_hopper_rebate_lamports) =
+            update_deposit_stake_authority_args.max_hopper_rebate_lamports
+        {
+            deposit_stake_authority.max_hopper_rebate_lamports = max_hopper_rebate_lamports.into();
+        }
+        if let Some(epoch_hopper_rebate_budget) =
+            update_deposit_stake_authority_args.epoch_hopper_rebate_budget
+        {
+            deposit_stake_authority.epoch_hopper_rebate_budget = epoch_hopper_rebate_budget.into();
+        }
+        if let Some(rebate_mode) = update_deposit_stake_authority_args.rebate_mode {
+            deposit_stake_authority.rebate_mode = rebate_mode.into();
+        }
+        if let Some(deposit_whitelist) = update_deposit_stake_authority_args.deposit_whitelist {
+            deposit_stake_authority.deposit_whitelist = deposit_whitelist;
+        }
+        if let Some(withdraw_whitelist) = update_deposit_stake_authority_args.withdraw_whitelist {
+            deposit_stake_authority.withdraw_whitelist = withdraw_whitelist;
+        }
+        // Validate: fee curve parameters must fit the (possibly updated) cool down period
+        deposit_stake_authority
+            .fee_curve()
+            .check(deposit_stake_authority.cool_down_seconds.into())?;
+
+        emit_deposit_stake_authority_updated(
+            deposit_stake_authority_info.key,
+            deposit_stake_authority,
+        );
+
         Ok(())
     }
 
@@ -281,6 +386,10 @@ impl Processor {
         let token_program_info = next_account_info(account_info_iter)?;
         let stake_program_info = next_account_info(account_info_iter)?;
         let system_program_info = next_account_info(account_info_iter)?;
+        // Generated clients pass the program id in place of an omitted optional account
+        let whitelist_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|whitelist_info| whitelist_info.key != program_id);
 
         // Validate: System program is correct native program
         check_system_program(system_program_info.key)?;
@@ -297,9 +406,9 @@ impl Processor {
         // NOTE: we assume that stake-pool program makes all of the assertions that the SPL stake-pool program does.
 
         let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
-        let deposit_stake_authority =
-            StakePoolDepositStakeAuthority::try_from_slice_unchecked(&deposit_stake_authority_data)
-                .unwrap();
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
+            &deposit_stake_authority_data,
+        )?;
 
         // Validate StakePoolDepositStakeAuthority PDA is correct
         check_deposit_stake_authority_address(
@@ -307,6 +416,12 @@ impl Processor {
             deposit_stake_authority_info.key,
             deposit_stake_authority,
         )?;
+        // Validate: deposit must not be paused
+        deposit_stake_authority.check_not_paused(StakePoolDepositStakeAuthority::PAUSE_DEPOSIT)?;
+        // Validate: deposit must fit within the deposit caps
+        let clock = Clock::get()?;
+        let deposit_lamports = stake_info.lamports();
+        deposit_stake_authority.check_deposit_caps(deposit_lamports, clock.epoch)?;
         // Validate Vault token account to receive pool tokens is coorect.
         if pool_tokens_vault_info.key != &deposit_stake_authority.vault {
             return Err(StakeDepositInterceptorError::InvalidVault.into());
@@ -322,7 +437,21 @@ impl Processor {
             return Err(StakeDepositInterceptorError::InvalidStakePool.into());
         }
 
-        let vault_token_account_before = Account::unpack(&pool_tokens_vault_info.data.borrow())?;
+        // Whitelisted owners are stamped with the discounted fee rate
+        let initial_fee_bps = match whitelist_info {
+            Some(whitelist_info) => {
+                // Validate: DepositReceipt owner must be on the deposit Whitelist
+                check_whitelisted_owner(
+                    deposit_stake_authority,
+                    whitelist_info,
+                    &deposit_stake_args.owner,
+                )?;
+                deposit_stake_authority.whitelisted_initial_fee_bps()
+            }
+            None => deposit_stake_authority.inital_fee_bps.into(),
+        };
+
+        let vault_amount_before = token_account_amount(pool_tokens_vault_info)?;
 
         // CPI to SPL stake-pool program to invoke DepositStake with the `StakePoolDepositStakeAuthority` as the
         // `stake_deposit_authority`.
@@ -347,62 +476,176 @@ impl Processor {
             minimum_pool_tokens_out,
         )?;
 
-        let vault_token_account_after = Account::unpack(&pool_tokens_vault_info.data.borrow())?;
-        let pool_tokens_minted = vault_token_account_after
-            .amount
-            .checked_sub(vault_token_account_before.amount)
+        let pool_tokens_minted = token_account_amount(pool_tokens_vault_info)?
+            .checked_sub(vault_amount_before)
             .expect("overflow");
 
         // Create the DepositReceipt
+        create_deposit_receipt(
+            program_id,
+            payer_info,
+            deposit_receipt_info,
+            stake_pool_info,
+            base_info,
+            deposit_stake_authority_info,
+            system_program_info,
+            deposit_stake_authority,
+            deposit_stake_args.owner,
+            referrer_fee_info,
+            pool_tokens_minted,
+            initial_fee_bps,
+            whitelist_info.is_some(),
+        )?;
 
-        let rent = Rent::get()?;
-        let clock = Clock::get()?;
+        drop(deposit_stake_authority_data);
+        update_deposit_stake_authority(deposit_stake_authority_info, |deposit_stake_authority| {
+            deposit_stake_authority.record_deposit(deposit_lamports, clock.epoch);
+            deposit_stake_authority.record_receipt_opened(pool_tokens_minted);
+        })?;
+
+        DepositReturnData {
+            deposit_receipt: *deposit_receipt_info.key,
+            lst_amount: pool_tokens_minted,
+        }
+        .set();
 
-        let (deposit_receipt_pda, bump_seed) =
-            derive_stake_deposit_receipt(program_id, stake_pool_info.key, base_info.key);
+        Ok(())
+    }
 
-        // Validate: DepositReceipt should be canonical PDA
-        if deposit_receipt_pda != *deposit_receipt_info.key {
-            return Err(StakeDepositInterceptorError::InvalidSeeds.into());
+    /// Invoke the provided stake-pool program's DepositSol (or DepositSolWithSlippage), but use
+    /// the vault account from the `StakePoolDepositStakeAuthority` to custody the "pool" tokens.
+    pub fn process_deposit_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        deposit_sol_args: DepositSolArgs,
+        minimum_pool_tokens_out: Option<u64>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payer_info = next_account_info(account_info_iter)?;
+        let stake_pool_program_info = next_account_info(account_info_iter)?;
+        let deposit_receipt_info = next_account_info(account_info_iter)?;
+        let stake_pool_info = next_account_info(account_info_iter)?;
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let base_info = next_account_info(account_info_iter)?;
+        let withdraw_authority_info = next_account_info(account_info_iter)?;
+        let reserve_stake_account_info = next_account_info(account_info_iter)?;
+        let lamports_from_info = next_account_info(account_info_iter)?;
+        let pool_tokens_vault_info = next_account_info(account_info_iter)?;
+        let manager_fee_info = next_account_info(account_info_iter)?;
+        let referrer_fee_info = next_account_info(account_info_iter)?;
+        let pool_mint_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Validate: System program is correct native program
+        check_system_program(system_program_info.key)?;
+        // Validate `StakePoolDepositStakeAuthority` is owned by current program.
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+        // Validate: DepositReceipt should be owned by system program and not initialized
+        check_system_account(deposit_receipt_info, true)?;
+
+        // Validate: base signed the TX
+        if !base_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
         }
 
-        let pda_seeds = [
-            DEPOSIT_RECEIPT,
-            &stake_pool_info.key.to_bytes(),
-            &base_info.key.to_bytes(),
-            &[bump_seed],
-        ];
-        // Create and initialize the DepositReceipt account
-        create_pda_account(
-            payer_info,
-            &rent,
-            8 + mem::size_of::<DepositReceipt>(),
+        // Validate: lamports_from signed the TX
+        if !lamports_from_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
+            &deposit_stake_authority_data,
+        )?;
+
+        // Validate StakePoolDepositStakeAuthority PDA is correct
+        check_deposit_stake_authority_address(
             program_id,
+            deposit_stake_authority_info.key,
+            deposit_stake_authority,
+        )?;
+        // Validate: deposit must not be paused
+        deposit_stake_authority.check_not_paused(StakePoolDepositStakeAuthority::PAUSE_DEPOSIT)?;
+        // Validate: deposit must fit within the deposit caps
+        let clock = Clock::get()?;
+        deposit_stake_authority.check_deposit_caps(deposit_sol_args.lamports_in, clock.epoch)?;
+        // Validate Vault token account to receive pool tokens is correct.
+        if pool_tokens_vault_info.key != &deposit_stake_authority.vault {
+            return Err(StakeDepositInterceptorError::InvalidVault.into());
+        }
+
+        // Validate: stake-pool program must match the program used to set up the authority
+        if &deposit_stake_authority.stake_pool_program_id != stake_pool_program_info.key {
+            return Err(StakeDepositInterceptorError::InvalidStakePoolProgram.into());
+        }
+
+        // Validate: StakePool must match the `StakePoolDepositStakeAuthority` StakePool
+        if &deposit_stake_authority.stake_pool != stake_pool_info.key {
+            return Err(StakeDepositInterceptorError::InvalidStakePool.into());
+        }
+
+        let vault_amount_before = token_account_amount(pool_tokens_vault_info)?;
+
+        // CPI to SPL stake-pool program to invoke DepositSol with the `StakePoolDepositStakeAuthority` as the
+        // `sol_deposit_authority`.
+        deposit_sol_cpi(
+            stake_pool_program_info,
+            stake_pool_info,
+            withdraw_authority_info,
+            reserve_stake_account_info,
+            lamports_from_info,
+            pool_tokens_vault_info,
+            manager_fee_info,
+            referrer_fee_info,
+            pool_mint_info,
             system_program_info,
+            token_program_info,
+            deposit_stake_authority_info,
+            deposit_stake_authority,
+            deposit_sol_args.lamports_in,
+            minimum_pool_tokens_out,
+        )?;
+
+        let pool_tokens_minted = token_account_amount(pool_tokens_vault_info)?
+            .checked_sub(vault_amount_before)
+            .expect("overflow");
+
+        // Create the DepositReceipt
+        create_deposit_receipt(
+            program_id,
+            payer_info,
             deposit_receipt_info,
-            &pda_seeds,
+            stake_pool_info,
+            base_info,
+            deposit_stake_authority_info,
+            system_program_info,
+            deposit_stake_authority,
+            deposit_sol_args.owner,
+            referrer_fee_info,
+            pool_tokens_minted,
+            deposit_stake_authority.inital_fee_bps.into(),
+            false,
         )?;
 
-        let mut deposit_receipt_data = deposit_receipt_info.try_borrow_mut_data()?;
-        deposit_receipt_data[0] = DepositReceipt::DISCRIMINATOR;
-        let deposit_receipt =
-            DepositReceipt::try_from_slice_unchecked_mut(&mut deposit_receipt_data).unwrap();
-
-        deposit_receipt.base = *base_info.key;
-        deposit_receipt.owner = deposit_stake_args.owner;
-        deposit_receipt.stake_pool = *stake_pool_info.key;
-        deposit_receipt.stake_pool_deposit_stake_authority = *deposit_stake_authority_info.key;
-        deposit_receipt.deposit_time = clock.unix_timestamp.unsigned_abs().into();
-        deposit_receipt.lst_amount = pool_tokens_minted.into();
-        deposit_receipt.cool_down_seconds = deposit_stake_authority.cool_down_seconds;
-        deposit_receipt.initial_fee_bps = deposit_stake_authority.inital_fee_bps;
-        deposit_receipt.bump_seed = bump_seed;
+        drop(deposit_stake_authority_data);
+        update_deposit_stake_authority(deposit_stake_authority_info, |deposit_stake_authority| {
+            deposit_stake_authority.record_deposit(deposit_sol_args.lamports_in, clock.epoch);
+            deposit_stake_authority.record_receipt_opened(pool_tokens_minted);
+        })?;
+
+        DepositReturnData {
+            deposit_receipt: *deposit_receipt_info.key,
+            lst_amount: pool_tokens_minted,
+        }
+        .set();
 
         Ok(())
     }
 
     /// Update the `owner` of the DepositReceipt, allowing a different address
-    /// to receive the tokens during Claim.
+    /// to receive the tokens during Claim. A whitelist discounted fee rate is only kept
+    /// when the new owner is on the deposit Whitelist.
     pub fn process_change_deposit_receipt_owner(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
@@ -411,6 +654,13 @@ impl Processor {
         let deposit_receipt_info = next_account_info(account_info_iter)?;
         let owner_info = next_account_info(account_info_iter)?;
         let new_owner_info = next_account_info(account_info_iter)?;
+        // Generated clients pass the program id in place of an omitted optional account
+        let deposit_stake_authority_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|deposit_stake_authority_info| deposit_stake_authority_info.key != program_id);
+        let whitelist_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|whitelist_info| whitelist_info.key != program_id);
 
         // Validate: program owns `DepositReceipt`
         check_account_owner(deposit_receipt_info, program_id)?;
@@ -422,7 +672,7 @@ impl Processor {
 
         let mut deposit_receipt_data = deposit_receipt_info.try_borrow_mut_data()?;
         let deposit_receipt =
-            DepositReceipt::try_from_slice_unchecked_mut(&mut deposit_receipt_data).unwrap();
+            DepositReceipt::try_from_slice_versioned_mut(&mut deposit_receipt_data)?;
 
         // Validate: DepositReceipt address must match expected PDA
         check_deposit_receipt_address(program_id, deposit_receipt_info.key, deposit_receipt)?;
@@ -432,8 +682,94 @@ impl Processor {
             return Err(StakeDepositInterceptorError::InvalidDepositReceiptOwner.into());
         }
 
-        // Update owner to new_owner
+        // A discounted DepositReceipt passing to another owner needs its
+        // StakePoolDepositStakeAuthority to check the discount
+        if deposit_receipt.whitelist_discounted == 1 && new_owner_info.key != owner_info.key {
+            let deposit_stake_authority_info = deposit_stake_authority_info
+                .ok_or(StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority)?;
+
+            // Validate: program owns `StakePoolDepositStakeAuthority`
+            check_account_owner(deposit_stake_authority_info, program_id)?;
+
+            // Validate: StakePoolDepositStakeAuthority must match the same during creation of DepositReceipt
+            if deposit_stake_authority_info.key
+                != &deposit_receipt.stake_pool_deposit_stake_authority
+            {
+                return Err(
+                    StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority.into(),
+                );
+            }
+
+            let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
+            let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
+                &deposit_stake_authority_data,
+            )?;
+            transfer_whitelist_discount(
+                deposit_receipt,
+                deposit_stake_authority,
+                new_owner_info.key,
+                whitelist_info,
+            )?;
+        }
+
+        // Update owner to new_owner, revoking the previous owner's delegate
         deposit_receipt.owner = *new_owner_info.key;
+        deposit_receipt.delegate = Pubkey::default();
+
+        StakeDepositInterceptorEvent::DepositReceiptOwnerChanged {
+            deposit_receipt: *deposit_receipt_info.key,
+            previous_owner: *owner_info.key,
+            new_owner: *new_owner_info.key,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Set (`approve`) or remove the claim delegate of a DepositReceipt. Only the
+    /// DepositReceipt `owner` may change its delegate.
+    pub fn process_set_claim_delegate(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        approve: bool,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let deposit_receipt_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+        let delegate = if approve {
+            *next_account_info(account_info_iter)?.key
+        } else {
+            Pubkey::default()
+        };
+
+        // Validate: program owns `DepositReceipt`
+        check_account_owner(deposit_receipt_info, program_id)?;
+
+        // Validate: owner must be a signer
+        if !owner_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        let mut deposit_receipt_data = deposit_receipt_info.try_borrow_mut_data()?;
+        let deposit_receipt =
+            DepositReceipt::try_from_slice_versioned_mut(&mut deposit_receipt_data)?;
+
+        // Validate: DepositReceipt address must match expected PDA
+        check_deposit_receipt_address(program_id, deposit_receipt_info.key, deposit_receipt)?;
+
+        // Validate: owner should match that of the DepositReceipt
+        if owner_info.key != &deposit_receipt.owner {
+            return Err(StakeDepositInterceptorError::InvalidDepositReceiptOwner.into());
+        }
+
+        deposit_receipt.delegate = delegate;
+
+        StakeDepositInterceptorEvent::ClaimDelegateUpdated {
+            deposit_receipt: *deposit_receipt_info.key,
+            owner: *owner_info.key,
+            delegate,
+        }
+        .emit();
 
         Ok(())
     }
@@ -441,12 +777,16 @@ impl Processor {
     /// Transfers "pool" tokens to a token account owned by the DepositReceipt `owner`.
     /// If this instruction is invoked during the `cool_down_seconds`, then fees will be
     /// sent to a token account owned by the `fee_wallet`. ONLY the DepositReceipt `owner`
-    /// may invoke this instruction during the `cool_down_seconds`. Once the `cool_down_seconds`
-    /// has ended, the instruction is permissionless and no fees are subtracted from the
-    /// depositors original amount of "pool" tokens.
+    /// (or its `delegate`) may invoke this instruction during the `cool_down_seconds`. Once
+    /// the `cool_down_seconds` has ended, the instruction is permissionless and no fees are
+    /// subtracted from the depositors original amount of "pool" tokens. When `amount` is
+    /// provided, only that portion of the balance is claimed (with a pro-rata fee) and the
+    /// DepositReceipt is closed once its balance reaches zero, returning its rent to the
+    /// DepositReceipt `owner`, even when its `delegate` claims.
     pub fn process_claim_pool_tokens(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
+        amount: Option<u64>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         let deposit_receipt_info = next_account_info(account_info_iter)?;
@@ -468,6 +808,30 @@ impl Processor {
         // Validate: program owns `DepositReceipt`
         check_account_owner(deposit_receipt_info, program_id)?;
 
+        // The DepositReceipt owner only follows the fixed accounts when its delegate claims, so
+        // claims by the owner keep their original account layout
+        let deposit_receipt_owner = {
+            let deposit_receipt_data = deposit_receipt_info.try_borrow_data()?;
+            DepositReceipt::try_from_slice_versioned(&deposit_receipt_data)?.owner
+        };
+        let receipt_owner_info = if owner_info.key == &deposit_receipt_owner {
+            // Clients always supplying the owner may still pass it explicitly
+            if account_info_iter
+                .as_slice()
+                .first()
+                .is_some_and(|info| info.key == owner_info.key)
+            {
+                account_info_iter.next();
+            }
+            owner_info
+        } else {
+            next_account_info(account_info_iter)?
+        };
+        let referrer_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|info| info.key != program_id);
+        let fee_split_infos = account_info_iter.as_slice();
+
         // Validate: no self transfer
         if vault_token_account_info.key == destination_token_account_info.key {
             return Err(StakeDepositInterceptorError::InvalidDestinationTokenAccount.into());
@@ -478,34 +842,32 @@ impl Processor {
             return Err(StakeDepositInterceptorError::InvalidFeeTokenAccount.into());
         }
 
-        {
+        let (claim_amount, fee_amount, fee_wallet_amount, net_amount, remaining_amount) = {
             let clock = Clock::get()?;
 
-            let deposit_receipt_data = deposit_receipt_info.try_borrow_data()?;
+            let mut deposit_receipt_data = deposit_receipt_info.try_borrow_mut_data()?;
             let deposit_receipt =
-                DepositReceipt::try_from_slice_unchecked(&deposit_receipt_data).unwrap();
-
-            let cool_down_end_time: i64 = u64::from(deposit_receipt.deposit_time)
-                .checked_add(deposit_receipt.cool_down_seconds.into())
-                .expect("overflow")
-                .try_into()
-                .expect("overflow");
+                DepositReceipt::try_from_slice_versioned_mut(&mut deposit_receipt_data)?;
 
             // Validate: Owner must be signer during cool down to prevent unintended fee payment
-            if cool_down_end_time > clock.unix_timestamp && !owner_info.is_signer {
+            if deposit_receipt.is_cooling_down(&clock) && !owner_info.is_signer {
                 return Err(StakeDepositInterceptorError::ActiveCooldown.into());
             }
 
-            // Validate: Owner must match that of DepositReceipt
-            if &deposit_receipt.owner != owner_info.key {
+            // Validate: Owner or its delegate must match that of DepositReceipt
+            if !deposit_receipt.is_owner_or_delegate(owner_info.key) {
+                return Err(StakeDepositInterceptorError::InvalidDepositReceiptOwner.into());
+            }
+
+            // Validate: the DepositReceipt rent is only ever refunded to its owner
+            if receipt_owner_info.key != &deposit_receipt.owner {
                 return Err(StakeDepositInterceptorError::InvalidDepositReceiptOwner.into());
             }
 
             let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
-            let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_unchecked(
+            let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
                 &deposit_stake_authority_data,
-            )
-            .unwrap();
+            )?;
 
             // Validate: StakePoolDepositStakeAuthority PDA is correct
             check_deposit_stake_authority_address(
@@ -513,6 +875,9 @@ impl Processor {
                 deposit_stake_authority_info.key,
                 deposit_stake_authority,
             )?;
+            // Validate: claim must not be paused
+            deposit_stake_authority
+                .check_not_paused(StakePoolDepositStakeAuthority::PAUSE_CLAIM)?;
 
             // Validate: DepositReceipt address must match expected PDA
             check_deposit_receipt_address(program_id, deposit_receipt_info.key, deposit_receipt)?;
@@ -536,41 +901,56 @@ impl Processor {
                 return Err(StakeDepositInterceptorError::InvalidPoolMint.into());
             }
 
-            let fee_token_account = Account::unpack(&fee_token_account_info.data.borrow())?;
-
-            // Validate: Fee token account must be owned by `fee_wallet`
-            if fee_token_account.owner != deposit_stake_authority.fee_wallet {
-                return Err(StakeDepositInterceptorError::InvalidFeeTokenAccount.into());
+            // Validate: token program must own the pool mint
+            if pool_mint_info.owner != token_program_info.key {
+                return Err(StakeDepositInterceptorError::InvalidTokenProgram.into());
             }
 
-            let destination_token_account =
-                Account::unpack(&destination_token_account_info.data.borrow())?;
+            check_fee_token_account(deposit_stake_authority, fee_token_account_info)?;
 
-            // Validate: Destination token account must be owned by DepositRecipt `owner`
-            if destination_token_account.owner != deposit_receipt.owner {
-                return Err(StakeDepositInterceptorError::InvalidDestinationTokenAccount.into());
+            {
+                let destination_token_account_data =
+                    destination_token_account_info.try_borrow_data()?;
+                let destination_token_account =
+                    StateWithExtensions::<Account>::unpack(&destination_token_account_data)?;
+
+                // Validate: Destination token account must be owned by DepositRecipt `owner`
+                if destination_token_account.base.owner != deposit_receipt.owner {
+                    return Err(StakeDepositInterceptorError::InvalidDestinationTokenAccount.into());
+                }
             }
 
-            let pool_mint =
-                spl_token_2022_interface::state::Mint::unpack(&pool_mint_info.data.borrow())?;
+            let pool_mint = {
+                let pool_mint_data = pool_mint_info.try_borrow_data()?;
+                StateWithExtensions::<Mint>::unpack(&pool_mint_data)?.base
+            };
 
-            let fee_amount = deposit_receipt.calculate_fee_amount(clock.unix_timestamp);
+            let lst_amount = u64::from(deposit_receipt.lst_amount);
+            let claim_amount = amount.unwrap_or(lst_amount);
 
-            // Transfer fee tokens to fee token account
-            transfer_tokens_cpi(
-                token_program_info.clone(),
-                vault_token_account_info.clone(),
-                pool_mint_info.clone(),
-                fee_token_account_info.clone(),
-                deposit_stake_authority_info.clone(),
+            // Validate: claim amount must be non-zero and within the DepositReceipt balance
+            if amount.is_some() && (claim_amount == 0 || claim_amount > lst_amount) {
+                return Err(StakeDepositInterceptorError::InvalidClaimAmount.into());
+            }
+
+            let fee_amount = deposit_receipt.calculate_partial_fee_amount(claim_amount, &clock);
+
+            let (referrer_fee_amount, fee_wallet_amount) = pay_deposit_receipt_fee(
+                program_id,
+                deposit_receipt,
+                deposit_stake_authority_info,
+                deposit_stake_authority,
+                vault_token_account_info,
+                fee_token_account_info,
+                referrer_info,
+                fee_split_infos,
+                pool_mint_info,
+                token_program_info,
                 fee_amount,
                 pool_mint.decimals,
-                deposit_stake_authority,
             )?;
 
-            let amount = u64::from(deposit_receipt.lst_amount)
-                .checked_sub(fee_amount)
-                .expect("overflow");
+            let amount = claim_amount.checked_sub(fee_amount).expect("overflow");
             // Transfer the rest of the tokens to the destination token account
             transfer_tokens_cpi(
                 token_program_info.clone(),
@@ -582,39 +962,1431 @@ impl Processor {
                 pool_mint.decimals,
                 deposit_stake_authority,
             )?;
-        }
 
-        // Close the DepositReceipt account
-        close_account(deposit_receipt_info, owner_info)?;
+            let remaining_amount = lst_amount.checked_sub(claim_amount).expect("overflow");
+            deposit_receipt.lst_amount = remaining_amount.into();
 
-        Ok(())
-    }
+            StakeDepositInterceptorEvent::PoolTokensClaimed {
+                deposit_receipt: *deposit_receipt_info.key,
+                owner: deposit_receipt.owner,
+                destination: *destination_token_account_info.key,
+                fee_amount,
+                referrer_fee_amount,
+                net_amount: amount,
+                remaining_amount,
+            }
+            .emit();
 
-    pub fn process_deposit_stake_whitelisted(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        minimum_pool_tokens_out: Option<u64>,
-    ) -> ProgramResult {
+            (
+                claim_amount,
+                fee_amount,
+                fee_wallet_amount,
+                amount,
+                remaining_amount,
+            )
+        };
+
+        update_deposit_stake_authority(deposit_stake_authority_info, |deposit_stake_authority| {
+            deposit_stake_authority.record_claim(
+                claim_amount,
+                fee_wallet_amount,
+                remaining_amount == 0,
+            )
+        })?;
+
+        // Close the DepositReceipt account once it has been fully claimed
+        if remaining_amount == 0 {
+            close_account(deposit_receipt_info, receipt_owner_info)?;
+        }
+
+        ClaimReturnData {
+            fee_amount,
+            net_amount,
+        }
+        .set();
+
+        Ok(())
+    }
+
+    /// Cancel a DepositReceipt by withdrawing its "pool" tokens from the StakePool as stake.
+    /// The fee a claim would owe is paid out of the Vault in "pool" tokens first. The rest is
+    /// burned by the StakePool's WithdrawStake into a new stake account whose stake and
+    /// withdraw authority is the DepositReceipt `owner`. Only the `owner` may redeem, and the
+    /// DepositReceipt is closed, returning its rent to the `owner`.
+    pub fn process_redeem_receipt_to_stake(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        minimum_lamports_out: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let deposit_receipt_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+        let vault_token_account_info = next_account_info(account_info_iter)?;
+        let fee_token_account_info = next_account_info(account_info_iter)?;
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let pool_mint_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let stake_pool_info = next_account_info(account_info_iter)?;
+        let validator_list_info = next_account_info(account_info_iter)?;
+        let withdraw_authority_info = next_account_info(account_info_iter)?;
+        let stake_split_from_info = next_account_info(account_info_iter)?;
+        let stake_split_to_info = next_account_info(account_info_iter)?;
+        let manager_fee_account_info = next_account_info(account_info_iter)?;
+        let clock_info = next_account_info(account_info_iter)?;
+        let stake_program_info = next_account_info(account_info_iter)?;
+        let spl_stake_pool_program_info = next_account_info(account_info_iter)?;
+        let referrer_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|info| info.key != program_id);
+        let fee_split_infos = account_info_iter.as_slice();
+
+        // Validate: System program is correct native program
+        check_system_program(system_program_info.key)?;
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: program owns `DepositReceipt`
+        check_account_owner(deposit_receipt_info, program_id)?;
+
+        // Validate: no self transfer
+        if vault_token_account_info.key == fee_token_account_info.key {
+            return Err(StakeDepositInterceptorError::InvalidFeeTokenAccount.into());
+        }
+
+        // Validate: owner signed the TX
+        if !owner_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        let (lst_amount, fee_amount, fee_wallet_amount, net_amount) = {
+            let clock = Clock::get()?;
+
+            let deposit_receipt_data = deposit_receipt_info.try_borrow_data()?;
+            let deposit_receipt = DepositReceipt::try_from_slice_versioned(&deposit_receipt_data)?;
+
+            // Validate: Owner must match that of DepositReceipt. A delegate may not redeem, as
+            // the stake account is handed to the owner.
+            if deposit_receipt.owner != *owner_info.key {
+                return Err(StakeDepositInterceptorError::InvalidDepositReceiptOwner.into());
+            }
+
+            let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
+            let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
+                &deposit_stake_authority_data,
+            )?;
+
+            // Validate: StakePoolDepositStakeAuthority PDA is correct
+            check_deposit_stake_authority_address(
+                program_id,
+                deposit_stake_authority_info.key,
+                deposit_stake_authority,
+            )?;
+            // Validate: claim must not be paused
+            deposit_stake_authority
+                .check_not_paused(StakePoolDepositStakeAuthority::PAUSE_CLAIM)?;
+
+            // Validate: DepositReceipt address must match expected PDA
+            check_deposit_receipt_address(program_id, deposit_receipt_info.key, deposit_receipt)?;
+
+            // Validate: StakePoolDepositStakeAuthority must match the same during creation of DepositReceipt
+            if deposit_stake_authority_info.key
+                != &deposit_receipt.stake_pool_deposit_stake_authority
+            {
+                return Err(
+                    StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority.into(),
+                );
+            }
+
+            // Validate: StakePool must match that of the `StakePoolDepositStakeAuthority`
+            deposit_stake_authority.check_stake_pool(*stake_pool_info.key)?;
+
+            // Validate: StakePool program must match that of the `StakePoolDepositStakeAuthority`
+            if deposit_stake_authority
+                .stake_pool_program_id
+                .ne(spl_stake_pool_program_info.key)
+            {
+                return Err(StakeDepositInterceptorError::InvalidStakePoolProgram.into());
+            }
+
+            // Validate: Vault token account must match that of the `StakePoolDepositStakeAuthority`
+            if &deposit_stake_authority.vault != vault_token_account_info.key {
+                return Err(StakeDepositInterceptorError::InvalidVault.into());
+            }
+
+            // Validate: Pool mint should match that of the `StakePoolDepositStakeAuthority`, which is the StakePool's mint
+            if &deposit_stake_authority.pool_mint != pool_mint_info.key {
+                return Err(StakeDepositInterceptorError::InvalidPoolMint.into());
+            }
+
+            // Validate: token program must own the pool mint
+            if pool_mint_info.owner != token_program_info.key {
+                return Err(StakeDepositInterceptorError::InvalidTokenProgram.into());
+            }
+
+            check_fee_token_account(deposit_stake_authority, fee_token_account_info)?;
+
+            let decimals = {
+                let pool_mint_data = pool_mint_info.try_borrow_data()?;
+                StateWithExtensions::<Mint>::unpack(&pool_mint_data)?
+                    .base
+                    .decimals
+            };
+
+            let lst_amount = u64::from(deposit_receipt.lst_amount);
+            let fee_amount = deposit_receipt.calculate_fee_amount_at(&clock);
+            let net_amount = lst_amount.checked_sub(fee_amount).expect("overflow");
+
+            // Validate: "pool" tokens must be left to withdraw once the fee is paid
+            if net_amount == 0 {
+                return Err(StakeDepositInterceptorError::InvalidClaimAmount.into());
+            }
+
+            let (referrer_fee_amount, fee_wallet_amount) = pay_deposit_receipt_fee(
+                program_id,
+                deposit_receipt,
+                deposit_stake_authority_info,
+                deposit_stake_authority,
+                vault_token_account_info,
+                fee_token_account_info,
+                referrer_info,
+                fee_split_infos,
+                pool_mint_info,
+                token_program_info,
+                fee_amount,
+                decimals,
+            )?;
+
+            // Burn the rest of the "pool" tokens from the Vault for a stake account owned by
+            // the DepositReceipt `owner`
+            invoke_signed(
+                &spl_stake_pool::instruction::withdraw_stake_with_slippage(
+                    spl_stake_pool_program_info.key,
+                    stake_pool_info.key,
+                    validator_list_info.key,
+                    withdraw_authority_info.key,
+                    stake_split_from_info.key,
+                    stake_split_to_info.key,
+                    owner_info.key,
+                    deposit_stake_authority_info.key,
+                    vault_token_account_info.key,
+                    manager_fee_account_info.key,
+                    pool_mint_info.key,
+                    token_program_info.key,
+                    net_amount,
+                    minimum_lamports_out,
+                ),
+                &[
+                    stake_pool_info.clone(),
+                    validator_list_info.clone(),
+                    withdraw_authority_info.clone(),
+                    stake_split_from_info.clone(),
+                    stake_split_to_info.clone(),
+                    owner_info.clone(),
+                    deposit_stake_authority_info.clone(),
+                    vault_token_account_info.clone(),
+                    manager_fee_account_info.clone(),
+                    pool_mint_info.clone(),
+                    clock_info.clone(),
+                    stake_program_info.clone(),
+                    token_program_info.clone(),
+                ],
+                &[deposit_stake_authority_signer_seeds!(
+                    deposit_stake_authority
+                )],
+            )?;
+
+            StakeDepositInterceptorEvent::DepositReceiptRedeemed {
+                deposit_receipt: *deposit_receipt_info.key,
+                owner: deposit_receipt.owner,
+                stake_account: *stake_split_to_info.key,
+                fee_amount,
+                referrer_fee_amount,
+                pool_tokens_redeemed: net_amount,
+            }
+            .emit();
+
+            (lst_amount, fee_amount, fee_wallet_amount, net_amount)
+        };
+
+        update_deposit_stake_authority(deposit_stake_authority_info, |deposit_stake_authority| {
+            deposit_stake_authority.record_claim(lst_amount, fee_wallet_amount, true)
+        })?;
+
+        close_account(deposit_receipt_info, owner_info)?;
+
+        ClaimReturnData {
+            fee_amount,
+            net_amount,
+        }
+        .set();
+
+        Ok(())
+    }
+
+    /// Carve `amount` of the "pool" tokens off an existing DepositReceipt into a new
+    /// DepositReceipt with its own owner. The new DepositReceipt inherits the deposit time,
+    /// cool down and initial fee rate so neither side's fee decay is reset. A whitelist
+    /// discounted fee rate is only inherited by a new owner on the deposit Whitelist.
+    pub fn process_split_deposit_receipt(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payer_info = next_account_info(account_info_iter)?;
+        let deposit_receipt_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+        let new_deposit_receipt_info = next_account_info(account_info_iter)?;
+        let new_base_info = next_account_info(account_info_iter)?;
+        let new_owner_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        // Generated clients pass the program id in place of an omitted optional account
+        let whitelist_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|whitelist_info| whitelist_info.key != program_id);
+
+        // Validate: System program is correct native program
+        check_system_program(system_program_info.key)?;
+
+        // Validate: program owns `DepositReceipt`
+        check_account_owner(deposit_receipt_info, program_id)?;
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: new DepositReceipt should be owned by system program and not initialized
+        check_system_account(new_deposit_receipt_info, true)?;
+
+        // Validate: owner must be a signer
+        if !owner_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        // Validate: new base signed the TX
+        if !new_base_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        let mut deposit_receipt_data = deposit_receipt_info.try_borrow_mut_data()?;
+        let deposit_receipt =
+            DepositReceipt::try_from_slice_versioned_mut(&mut deposit_receipt_data)?;
+
+        // Validate: DepositReceipt address must match expected PDA
+        check_deposit_receipt_address(program_id, deposit_receipt_info.key, deposit_receipt)?;
+
+        // Validate: owner should match that of the DepositReceipt
+        if owner_info.key != &deposit_receipt.owner {
+            return Err(StakeDepositInterceptorError::InvalidDepositReceiptOwner.into());
+        }
+
+        // Validate: StakePoolDepositStakeAuthority must match the same during creation of DepositReceipt
+        if deposit_stake_authority_info.key != &deposit_receipt.stake_pool_deposit_stake_authority {
+            return Err(StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority.into());
+        }
+
+        // Validate: both receipts must be left with a non-zero balance
+        let lst_amount = u64::from(deposit_receipt.lst_amount);
+        if amount == 0 || amount >= lst_amount {
+            return Err(StakeDepositInterceptorError::InvalidSplitAmount.into());
+        }
+
+        let (new_deposit_receipt_pda, bump_seed) = derive_stake_deposit_receipt(
+            program_id,
+            &deposit_receipt.stake_pool,
+            new_base_info.key,
+        );
+
+        // Validate: new DepositReceipt should be canonical PDA
+        if new_deposit_receipt_pda != *new_deposit_receipt_info.key {
+            return Err(StakeDepositInterceptorError::InvalidSeeds.into());
+        }
+
+        let rent = Rent::get()?;
+        let pda_seeds = [
+            DEPOSIT_RECEIPT,
+            &deposit_receipt.stake_pool.to_bytes(),
+            &new_base_info.key.to_bytes(),
+            &[bump_seed],
+        ];
+        // Create and initialize the new DepositReceipt account
+        create_pda_account(
+            payer_info,
+            &rent,
+            8 + mem::size_of::<DepositReceipt>(),
+            program_id,
+            system_program_info,
+            new_deposit_receipt_info,
+            &pda_seeds,
+        )?;
+
+        let mut new_deposit_receipt_data = new_deposit_receipt_info.try_borrow_mut_data()?;
+        new_deposit_receipt_data[0] = DepositReceipt::DISCRIMINATOR;
+        new_deposit_receipt_data[ACCOUNT_VERSION_OFFSET] = DepositReceipt::VERSION;
+        let new_deposit_receipt =
+            DepositReceipt::try_from_slice_versioned_mut(&mut new_deposit_receipt_data)?;
+
+        new_deposit_receipt.base = *new_base_info.key;
+        new_deposit_receipt.owner = *new_owner_info.key;
+        new_deposit_receipt.stake_pool = deposit_receipt.stake_pool;
+        new_deposit_receipt.stake_pool_deposit_stake_authority =
+            deposit_receipt.stake_pool_deposit_stake_authority;
+        new_deposit_receipt.deposit_time = deposit_receipt.deposit_time;
+        new_deposit_receipt.deposit_slot = deposit_receipt.deposit_slot;
+        new_deposit_receipt.deposit_epoch = deposit_receipt.deposit_epoch;
+        new_deposit_receipt.cool_down_unit = deposit_receipt.cool_down_unit;
+        new_deposit_receipt.lst_amount = amount.into();
+        new_deposit_receipt.cool_down_seconds = deposit_receipt.cool_down_seconds;
+        new_deposit_receipt.initial_fee_bps = deposit_receipt.initial_fee_bps;
+        new_deposit_receipt.whitelist_discounted = deposit_receipt.whitelist_discounted;
+        new_deposit_receipt.fee_curve = deposit_receipt.fee_curve;
+        new_deposit_receipt.bump_seed = bump_seed;
+        new_deposit_receipt.referrer = deposit_receipt.referrer;
+        new_deposit_receipt.referrer_fee_share_bps = deposit_receipt.referrer_fee_share_bps;
+
+        if new_owner_info.key != owner_info.key {
+            let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
+            let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
+                &deposit_stake_authority_data,
+            )?;
+            transfer_whitelist_discount(
+                new_deposit_receipt,
+                deposit_stake_authority,
+                new_owner_info.key,
+                whitelist_info,
+            )?;
+        }
+
+        StakeDepositInterceptorEvent::DepositReceiptCreated {
+            deposit_receipt: *new_deposit_receipt_info.key,
+            owner: new_deposit_receipt.owner,
+            stake_pool: new_deposit_receipt.stake_pool,
+            deposit_stake_authority: new_deposit_receipt.stake_pool_deposit_stake_authority,
+            lst_amount: amount,
+            initial_fee_bps: new_deposit_receipt.initial_fee_bps.into(),
+            deposit_time: new_deposit_receipt.deposit_time.into(),
+        }
+        .emit();
+
+        deposit_receipt.lst_amount = lst_amount.checked_sub(amount).expect("overflow").into();
+
+        update_deposit_stake_authority(deposit_stake_authority_info, |deposit_stake_authority| {
+            deposit_stake_authority.record_receipt_split()
+        })
+    }
+
+    /// Fold the source DepositReceipt into the destination DepositReceipt. Both must share the
+    /// same owner, StakePool, `StakePoolDepositStakeAuthority` and fee curve. The source is
+    /// closed and its rent refunded to the owner.
+    pub fn process_merge_deposit_receipts(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let destination_deposit_receipt_info = next_account_info(account_info_iter)?;
+        let source_deposit_receipt_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+
+        // Validate: program owns both `DepositReceipt`s
+        check_account_owner(destination_deposit_receipt_info, program_id)?;
+        check_account_owner(source_deposit_receipt_info, program_id)?;
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: owner must be a signer
+        if !owner_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        // Validate: cannot merge a DepositReceipt into itself
+        if destination_deposit_receipt_info.key == source_deposit_receipt_info.key {
+            return Err(StakeDepositInterceptorError::DepositReceiptMismatch.into());
+        }
+
+        let lst_amount = {
+            let mut destination_deposit_receipt_data =
+                destination_deposit_receipt_info.try_borrow_mut_data()?;
+            let destination_deposit_receipt = DepositReceipt::try_from_slice_versioned_mut(
+                &mut destination_deposit_receipt_data,
+            )?;
+            let source_deposit_receipt_data = source_deposit_receipt_info.try_borrow_data()?;
+            let source_deposit_receipt =
+                DepositReceipt::try_from_slice_versioned(&source_deposit_receipt_data)?;
+
+            // Validate: DepositReceipt addresses must match expected PDAs
+            check_deposit_receipt_address(
+                program_id,
+                destination_deposit_receipt_info.key,
+                destination_deposit_receipt,
+            )?;
+            check_deposit_receipt_address(
+                program_id,
+                source_deposit_receipt_info.key,
+                source_deposit_receipt,
+            )?;
+
+            // Validate: owner should match that of both DepositReceipts
+            if owner_info.key != &destination_deposit_receipt.owner
+                || owner_info.key != &source_deposit_receipt.owner
+            {
+                return Err(StakeDepositInterceptorError::InvalidDepositReceiptOwner.into());
+            }
+
+            // Validate: both DepositReceipts must draw from the same vault, decay fees
+            // along the same curve and unit and share them with the same referrer
+            if destination_deposit_receipt.stake_pool != source_deposit_receipt.stake_pool
+                || destination_deposit_receipt.stake_pool_deposit_stake_authority
+                    != source_deposit_receipt.stake_pool_deposit_stake_authority
+                || destination_deposit_receipt.fee_curve != source_deposit_receipt.fee_curve
+                || destination_deposit_receipt.cool_down_unit
+                    != source_deposit_receipt.cool_down_unit
+                || destination_deposit_receipt.referrer != source_deposit_receipt.referrer
+                || destination_deposit_receipt.referrer_fee_share_bps
+                    != source_deposit_receipt.referrer_fee_share_bps
+            {
+                return Err(StakeDepositInterceptorError::DepositReceiptMismatch.into());
+            }
+
+            // Validate: StakePoolDepositStakeAuthority must match that of both DepositReceipts
+            if deposit_stake_authority_info.key
+                != &destination_deposit_receipt.stake_pool_deposit_stake_authority
+            {
+                return Err(
+                    StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority.into(),
+                );
+            }
+
+            destination_deposit_receipt.merge(source_deposit_receipt);
+            u64::from(destination_deposit_receipt.lst_amount)
+        };
+
+        update_deposit_stake_authority(deposit_stake_authority_info, |deposit_stake_authority| {
+            deposit_stake_authority.record_receipt_closed()
+        })?;
+
+        // Close the source DepositReceipt account
+        close_account(source_deposit_receipt_info, owner_info)?;
+
+        StakeDepositInterceptorEvent::DepositReceiptsMerged {
+            destination_deposit_receipt: *destination_deposit_receipt_info.key,
+            source_deposit_receipt: *source_deposit_receipt_info.key,
+            owner: *owner_info.key,
+            lst_amount,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Set (`paused == true`) or clear the given `paused_operations` flags on the
+    /// `StakePoolDepositStakeAuthority`. ONLY accessible by the current authority.
+    pub fn process_set_paused(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        operations: u8,
+        paused: bool,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: authority is signer
+        if !authority_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        // Validate: only known operations can be paused
+        if operations & !StakePoolDepositStakeAuthority::PAUSE_ALL != 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut deposit_stake_authority_data =
+            deposit_stake_authority_info.try_borrow_mut_data()?;
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned_mut(
+            &mut deposit_stake_authority_data,
+        )?;
+
+        check_deposit_stake_authority_address(
+            program_id,
+            deposit_stake_authority_info.key,
+            deposit_stake_authority,
+        )?;
+
+        // Validate: authority matches
+        if deposit_stake_authority.authority != *authority_info.key {
+            return Err(StakeDepositInterceptorError::InvalidAuthority.into());
+        }
+
+        if paused {
+            deposit_stake_authority.paused_operations |= operations;
+        } else {
+            deposit_stake_authority.paused_operations &= !operations;
+        }
+
+        StakeDepositInterceptorEvent::PausedOperationsUpdated {
+            deposit_stake_authority: *deposit_stake_authority_info.key,
+            authority: *authority_info.key,
+            paused_operations: deposit_stake_authority.paused_operations,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Propose a new authority for the `StakePoolDepositStakeAuthority`. ONLY accessible by the
+    /// current authority. Replaces any existing proposal.
+    pub fn process_propose_authority(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+        let pending_authority_info = next_account_info(account_info_iter)?;
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: authority is signer
+        if !authority_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        let mut deposit_stake_authority_data =
+            deposit_stake_authority_info.try_borrow_mut_data()?;
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned_mut(
+            &mut deposit_stake_authority_data,
+        )?;
+
+        check_deposit_stake_authority_address(
+            program_id,
+            deposit_stake_authority_info.key,
+            deposit_stake_authority,
+        )?;
+
+        // Validate: authority matches
+        if deposit_stake_authority.authority != *authority_info.key {
+            return Err(StakeDepositInterceptorError::InvalidAuthority.into());
+        }
+
+        deposit_stake_authority.pending_authority = *pending_authority_info.key;
+
+        StakeDepositInterceptorEvent::PendingAuthorityUpdated {
+            deposit_stake_authority: *deposit_stake_authority_info.key,
+            authority: *authority_info.key,
+            pending_authority: *pending_authority_info.key,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Complete an authority handoff. ONLY accessible by the pending authority.
+    pub fn process_accept_authority(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let pending_authority_info = next_account_info(account_info_iter)?;
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: pending authority is signer
+        if !pending_authority_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        let mut deposit_stake_authority_data =
+            deposit_stake_authority_info.try_borrow_mut_data()?;
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned_mut(
+            &mut deposit_stake_authority_data,
+        )?;
+
+        check_deposit_stake_authority_address(
+            program_id,
+            deposit_stake_authority_info.key,
+            deposit_stake_authority,
+        )?;
+
+        // Validate: signer must be the pending authority
+        if deposit_stake_authority.pending_authority == Pubkey::default()
+            || deposit_stake_authority.pending_authority != *pending_authority_info.key
+        {
+            return Err(StakeDepositInterceptorError::InvalidPendingAuthority.into());
+        }
+
+        deposit_stake_authority.authority = *pending_authority_info.key;
+        deposit_stake_authority.pending_authority = Pubkey::default();
+
+        emit_deposit_stake_authority_updated(
+            deposit_stake_authority_info.key,
+            deposit_stake_authority,
+        );
+
+        Ok(())
+    }
+
+    /// Clear the pending authority of the `StakePoolDepositStakeAuthority`. ONLY accessible by
+    /// the current authority.
+    pub fn process_cancel_authority_proposal(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: authority is signer
+        if !authority_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        let mut deposit_stake_authority_data =
+            deposit_stake_authority_info.try_borrow_mut_data()?;
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned_mut(
+            &mut deposit_stake_authority_data,
+        )?;
+
+        check_deposit_stake_authority_address(
+            program_id,
+            deposit_stake_authority_info.key,
+            deposit_stake_authority,
+        )?;
+
+        // Validate: authority matches
+        if deposit_stake_authority.authority != *authority_info.key {
+            return Err(StakeDepositInterceptorError::InvalidAuthority.into());
+        }
+
+        deposit_stake_authority.pending_authority = Pubkey::default();
+
+        StakeDepositInterceptorEvent::PendingAuthorityUpdated {
+            deposit_stake_authority: *deposit_stake_authority_info.key,
+            authority: *authority_info.key,
+            pending_authority: Pubkey::default(),
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Close the `StakePoolDepositStakeAuthority`, its Vault, RebateVault and FeeSplit,
+    /// returning the rent to the destination. ONLY accessible by the current authority, once both vaults are
+    /// empty and the StakePool no longer uses the PDA as a deposit or sol withdraw authority.
+    pub fn process_close_deposit_stake_authority(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+        let vault_info = next_account_info(account_info_iter)?;
+        let stake_pool_info = next_account_info(account_info_iter)?;
+        let destination_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+        let rebate_vault_info = next_account_info(account_info_iter)?;
+        let fee_split_info = next_account_info(account_info_iter)?;
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: authority is signer
+        if !authority_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        // Validate: token program must be one of the SPL Token programs
+        spl_token_2022_interface::check_spl_token_program_account(token_program_info.key)?;
+
+        let deposit_stake_authority = {
+            let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
+            *StakePoolDepositStakeAuthority::try_from_slice_versioned(
+                &deposit_stake_authority_data,
+            )?
+        };
+
+        check_deposit_stake_authority_address(
+            program_id,
+            deposit_stake_authority_info.key,
+            &deposit_stake_authority,
+        )?;
+        deposit_stake_authority.check_stake_pool(*stake_pool_info.key)?;
+
+        // Validate: authority matches
+        if deposit_stake_authority.authority != *authority_info.key {
+            return Err(StakeDepositInterceptorError::InvalidAuthority.into());
+        }
+
+        // Validate: StakePool must be owned by the program used to set up the authority
+        if stake_pool_info.owner != &deposit_stake_authority.stake_pool_program_id {
+            return Err(StakeDepositInterceptorError::InvalidStakePool.into());
+        }
+
+        // Validate: StakePool must no longer use the PDA as its stake or sol deposit authority,
+        // nor as its sol withdraw authority
+        let stake_pool = try_from_slice_unchecked::<StakePool>(&stake_pool_info.data.borrow())?;
+        if stake_pool.stake_deposit_authority == *deposit_stake_authority_info.key
+            || stake_pool.sol_deposit_authority == Some(*deposit_stake_authority_info.key)
+            || stake_pool.sol_withdraw_authority == Some(*deposit_stake_authority_info.key)
+        {
+            return Err(StakeDepositInterceptorError::DepositStakeAuthorityInUse.into());
+        }
+
+        // Validate: Vault must match that of the `StakePoolDepositStakeAuthority`
+        if vault_info.key != &deposit_stake_authority.vault {
+            return Err(StakeDepositInterceptorError::InvalidVault.into());
+        }
+
+        // Validate: Vault must be empty
+        {
+            let vault_data = vault_info.try_borrow_data()?;
+            let vault = StateWithExtensions::<Account>::unpack(&vault_data)?;
+            if vault.base.amount != 0 {
+                return Err(StakeDepositInterceptorError::VaultNotEmpty.into());
+            }
+        }
+
+        // Validate: FeeSplit address must match expected PDA
+        let (fee_split_address, _bump_seed, _seeds) =
+            FeeSplit::find_program_address(program_id, deposit_stake_authority_info.key);
+        if fee_split_address != *fee_split_info.key {
+            return Err(StakeDepositInterceptorError::InvalidFeeSplitRecipient.into());
+        }
+
+        // Validate: RebateVault must be empty, as it can no longer be withdrawn from once the
+        // `StakePoolDepositStakeAuthority` is closed
+        let rebate_vault_initialized = RebateVault::load(
+            program_id,
+            rebate_vault_info,
+            deposit_stake_authority_info.key,
+            token_program_info.key,
+        )?;
+        if rebate_vault_initialized {
+            let rebate_vault_data = rebate_vault_info.try_borrow_data()?;
+            let rebate_vault = StateWithExtensions::<Account>::unpack(&rebate_vault_data)?;
+            if rebate_vault.base.amount != 0 {
+                return Err(StakeDepositInterceptorError::RebateVaultNotEmpty.into());
+            }
+        }
+
+        // Close the Vault, signed by the `StakePoolDepositStakeAuthority`
+        invoke_signed(
+            &spl_token_2022_interface::instruction::close_account(
+                token_program_info.key,
+                vault_info.key,
+                destination_info.key,
+                deposit_stake_authority_info.key,
+                &[],
+            )?,
+            &[
+                vault_info.clone(),
+                destination_info.clone(),
+                deposit_stake_authority_info.clone(),
+            ],
+            &[deposit_stake_authority_signer_seeds!(
+                deposit_stake_authority
+            )],
+        )?;
+
+        // Close the RebateVault, if it was ever funded
+        if rebate_vault_initialized {
+            invoke_signed(
+                &spl_token_2022_interface::instruction::close_account(
+                    token_program_info.key,
+                    rebate_vault_info.key,
+                    destination_info.key,
+                    deposit_stake_authority_info.key,
+                    &[],
+                )?,
+                &[
+                    rebate_vault_info.clone(),
+                    destination_info.clone(),
+                    deposit_stake_authority_info.clone(),
+                ],
+                &[deposit_stake_authority_signer_seeds!(
+                    deposit_stake_authority
+                )],
+            )?;
+        }
+
+        // Close the FeeSplit, if one is set
+        if fee_split_info.owner == program_id {
+            close_account(fee_split_info, destination_info)?;
+        }
+
+        // Close the `StakePoolDepositStakeAuthority`
+        close_account(deposit_stake_authority_info, destination_info)?;
+
+        StakeDepositInterceptorEvent::DepositStakeAuthorityClosed {
+            deposit_stake_authority: *deposit_stake_authority_info.key,
+            authority: *authority_info.key,
+            destination: *destination_info.key,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Create, replace or remove the FeeSplit distributing the claim-time fees of a
+    /// `StakePoolDepositStakeAuthority`. ONLY accessible by the current authority.
+    pub fn process_set_fee_split(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        recipients: Vec<FeeSplitRecipientArgs>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payer_info = next_account_info(account_info_iter)?;
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+        let fee_split_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Validate: System program is correct native program
+        check_system_program(system_program_info.key)?;
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: authority is signer
+        if !authority_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        let deposit_stake_authority = {
+            let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
+            *StakePoolDepositStakeAuthority::try_from_slice_versioned(
+                &deposit_stake_authority_data,
+            )?
+        };
+
+        check_deposit_stake_authority_address(
+            program_id,
+            deposit_stake_authority_info.key,
+            &deposit_stake_authority,
+        )?;
+
+        // Validate: authority matches
+        if deposit_stake_authority.authority != *authority_info.key {
+            return Err(StakeDepositInterceptorError::InvalidAuthority.into());
+        }
+
+        let (fee_split_address, bump_seed, seeds) =
+            FeeSplit::find_program_address(program_id, deposit_stake_authority_info.key);
+
+        // Validate: FeeSplit address must match expected PDA
+        if fee_split_address != *fee_split_info.key {
+            return Err(StakeDepositInterceptorError::InvalidFeeSplitRecipient.into());
+        }
+
+        if recipients.is_empty() {
+            // Remove the FeeSplit, returning the fees to the `fee_wallet`
+            if fee_split_info.owner == program_id {
+                close_account(fee_split_info, payer_info)?;
+            }
+            update_deposit_stake_authority(
+                deposit_stake_authority_info,
+                |deposit_stake_authority| {
+                    deposit_stake_authority.fee_split_enabled = 0;
+                },
+            )?;
+            StakeDepositInterceptorEvent::FeeSplitUpdated {
+                deposit_stake_authority: *deposit_stake_authority_info.key,
+                fee_split: fee_split_address,
+                recipients,
+            }
+            .emit();
+            return Ok(());
+        }
+
+        // Validate: recipients must be well formed and their shares sum to 100%
+        let mut fee_split = FeeSplit::zeroed();
+        fee_split.stake_pool_deposit_stake_authority = *deposit_stake_authority_info.key;
+        fee_split.bump_seed = bump_seed;
+        fee_split.set_recipients(
+            &recipients
+                .iter()
+                .map(|recipient| FeeSplitRecipient {
+                    wallet: recipient.wallet,
+                    fee_bps: recipient.fee_bps.into(),
+                })
+                .collect::<Vec<_>>(),
+        )?;
+
+        if fee_split_info.owner != program_id {
+            let bump = [bump_seed];
+            let mut pda_seeds: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+            pda_seeds.push(&bump);
+            create_pda_account(
+                payer_info,
+                &Rent::get()?,
+                8 + mem::size_of::<FeeSplit>(),
+                program_id,
+                system_program_info,
+                fee_split_info,
+                &pda_seeds,
+            )?;
+        }
+
+        {
+            let mut fee_split_data = fee_split_info.try_borrow_mut_data()?;
+            fee_split_data[0] = FeeSplit::DISCRIMINATOR;
+            *FeeSplit::try_from_slice_unchecked_mut(&mut fee_split_data)? = fee_split;
+        }
+
+        update_deposit_stake_authority(deposit_stake_authority_info, |deposit_stake_authority| {
+            deposit_stake_authority.fee_split_enabled = 1;
+        })?;
+
+        StakeDepositInterceptorEvent::FeeSplitUpdated {
+            deposit_stake_authority: *deposit_stake_authority_info.key,
+            fee_split: fee_split_address,
+            recipients,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Transfer the Vault's "pool" tokens beyond `outstanding_receipt_lst` to the `fee_wallet`
+    /// token account. ONLY accessible by the current authority.
+    pub fn process_sweep_vault_surplus(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+        let vault_info = next_account_info(account_info_iter)?;
+        let fee_token_account_info = next_account_info(account_info_iter)?;
+        let pool_mint_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: authority is signer
+        if !authority_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        // Validate: token program must be one of the SPL Token programs
+        spl_token_2022_interface::check_spl_token_program_account(token_program_info.key)?;
+
+        let deposit_stake_authority = {
+            let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
+            *StakePoolDepositStakeAuthority::try_from_slice_versioned(
+                &deposit_stake_authority_data,
+            )?
+        };
+
+        check_deposit_stake_authority_address(
+            program_id,
+            deposit_stake_authority_info.key,
+            &deposit_stake_authority,
+        )?;
+
+        // Validate: authority matches
+        if deposit_stake_authority.authority != *authority_info.key {
+            return Err(StakeDepositInterceptorError::InvalidAuthority.into());
+        }
+
+        // Validate: Vault must match that of the `StakePoolDepositStakeAuthority`
+        if vault_info.key != &deposit_stake_authority.vault {
+            return Err(StakeDepositInterceptorError::InvalidVault.into());
+        }
+
+        // Validate: no self transfer
+        if vault_info.key == fee_token_account_info.key {
+            return Err(StakeDepositInterceptorError::InvalidFeeTokenAccount.into());
+        }
+
+        // Validate: Pool mint should match that of the `StakePoolDepositStakeAuthority`
+        if pool_mint_info.key != &deposit_stake_authority.pool_mint {
+            return Err(StakeDepositInterceptorError::InvalidPoolMint.into());
+        }
+
+        // Validate: Fee token account must be owned by `fee_wallet`
+        {
+            let fee_token_account_data = fee_token_account_info.try_borrow_data()?;
+            let fee_token_account =
+                StateWithExtensions::<Account>::unpack(&fee_token_account_data)?;
+            if fee_token_account.base.owner != deposit_stake_authority.fee_wallet {
+                return Err(StakeDepositInterceptorError::InvalidFeeTokenAccount.into());
+            }
+        }
+
+        // Validate: Vault must cover every open DepositReceipt
+        let vault_amount = token_account_amount(vault_info)?;
+        let surplus = deposit_stake_authority
+            .vault_surplus(vault_amount)
+            .inspect_err(|_| {
+                msg!(
+                    "Vault holds {} pool tokens, DepositReceipts are owed {}",
+                    vault_amount,
+                    u64::from(deposit_stake_authority.outstanding_receipt_lst)
+                )
+            })?;
+
+        if surplus > 0 {
+            let decimals = {
+                let pool_mint_data = pool_mint_info.try_borrow_data()?;
+                StateWithExtensions::<Mint>::unpack(&pool_mint_data)?
+                    .base
+                    .decimals
+            };
+            transfer_tokens_cpi(
+                token_program_info.clone(),
+                vault_info.clone(),
+                pool_mint_info.clone(),
+                fee_token_account_info.clone(),
+                deposit_stake_authority_info.clone(),
+                surplus,
+                decimals,
+                &deposit_stake_authority,
+            )?;
+
+            StakeDepositInterceptorEvent::VaultSurplusSwept {
+                deposit_stake_authority: *deposit_stake_authority_info.key,
+                destination: *fee_token_account_info.key,
+                amount: surplus,
+            }
+            .emit();
+        }
+
+        Ok(())
+    }
+
+    pub fn process_deposit_stake_whitelisted(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        minimum_pool_tokens_out: Option<u64>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let whitelisted_signer_info = next_account_info(account_info_iter)?;
+        let whitelist_info = next_account_info(account_info_iter)?;
+        let stake_pool_info = next_account_info(account_info_iter)?;
+        let validator_list_info = next_account_info(account_info_iter)?;
+        let stake_deposit_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let withdraw_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let deposit_stake_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let validator_stake_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let reserve_stake_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let pool_tokens_to_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let manager_fee_account_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let referral_fee_account_info = next_account_info(account_info_iter)?;
+        let pool_mint_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let clock_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let stake_history_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let token_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let stake_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let spl_stake_pool_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let system_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+
+        // Validate: System program is correct native program
+        check_system_program(system_program_info.key)?;
+
+        // Validate `StakePoolDepositStakeAuthority` is owned by current program.
+        check_account_owner(stake_deposit_authority_info, program_id)?;
+
+        // Validate: base signed the TX
+        if !whitelisted_signer_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        let deposit_stake_authority_data = stake_deposit_authority_info.try_borrow_data()?;
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
+            &deposit_stake_authority_data,
+        )?;
+        deposit_stake_authority.check_stake_pool(*stake_pool_info.key)?;
+
+        // Validate: StakePoolDepositStakeAuthority PDA is correct
+        check_deposit_stake_authority_address(
+            program_id,
+            stake_deposit_authority_info.key,
+            deposit_stake_authority,
+        )?;
+        // Validate: whitelisted deposit must not be paused
+        deposit_stake_authority
+            .check_not_paused(StakePoolDepositStakeAuthority::PAUSE_DEPOSIT_WHITELISTED)?;
+        // Validate: deposit must fit within the deposit caps
+        let clock = Clock::get()?;
+        let deposit_lamports = deposit_stake_info.lamports();
+        deposit_stake_authority.check_deposit_caps(deposit_lamports, clock.epoch)?;
+
+        if deposit_stake_authority
+            .stake_pool_program_id
+            .ne(spl_stake_pool_program_info.key)
+        {
+            return Err(StakeDepositInterceptorError::InvalidStakePoolProgram.into());
+        }
+
+        // Validate: Whitelist must be the one set for deposits
+        deposit_stake_authority.check_deposit_whitelist(whitelist_info.key)?;
+
+        check_whitelisted_signer(
+            deposit_stake_authority,
+            whitelist_info,
+            whitelisted_signer_info,
+        )?;
+
+        let pool_tokens_before = token_account_amount(pool_tokens_to_info)?;
+
+        // CPI to SPL stake-pool program to invoke DepositStake with the `StakePoolDepositStakeAuthority` as the
+        // `stake_deposit_authority`.
+        deposit_stake_cpi(
+            spl_stake_pool_program_info,
+            stake_pool_info,
+            validator_list_info,
+            stake_deposit_authority_info,
+            withdraw_authority_info,
+            deposit_stake_info,
+            validator_stake_info,
+            reserve_stake_info,
+            pool_tokens_to_info,
+            manager_fee_account_info,
+            referral_fee_account_info,
+            pool_mint_info,
+            token_program_info,
+            clock_info,
+            stake_history_info,
+            stake_program_info,
+            deposit_stake_authority,
+            minimum_pool_tokens_out,
+        )?;
+
+        let pool_tokens_minted = token_account_amount(pool_tokens_to_info)?
+            .checked_sub(pool_tokens_before)
+            .expect("overflow");
+        StakeDepositInterceptorEvent::StakeDepositedWhitelisted {
+            deposit_stake_authority: *stake_deposit_authority_info.key,
+            whitelisted_signer: *whitelisted_signer_info.key,
+            deposit_stake: *deposit_stake_info.key,
+            lamports: deposit_lamports,
+            pool_tokens_minted,
+        }
+        .emit();
+
+        drop(deposit_stake_authority_data);
+        update_deposit_stake_authority(stake_deposit_authority_info, |deposit_stake_authority| {
+            deposit_stake_authority.record_deposit(deposit_lamports, clock.epoch)
+        })
+    }
+
+    pub fn process_withdraw_stake_whitelisted(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        pool_tokens_in: u64,
+        minimum_lamports_out: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let whitelisted_signer_info = next_account_info(account_info_iter)?;
+        let whitelist_info = next_account_info(account_info_iter)?;
+        let stake_pool_info = next_account_info(account_info_iter)?;
+        let validator_list_info = next_account_info(account_info_iter)?;
+        let stake_deposit_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let withdraw_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let stake_split_from_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let stake_split_to_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let user_stake_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let user_transfer_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let user_pool_token_account_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let manager_fee_account_info = next_account_info(account_info_iter)?;
+        let pool_mint_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let fee_rebate_hopper_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let fee_rebate_recipient_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let clock_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let token_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let stake_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let spl_stake_pool_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let system_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let rebate_budget_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|info| info.key != program_id);
+        let rebate_vault_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|info| info.key != program_id);
+
+        // Validate: System program is correct native program
+        check_system_program(system_program_info.key)?;
+
+        // Validate `StakePoolDepositStakeAuthority` is owned by current program.
+        check_account_owner(stake_deposit_authority_info, program_id)?;
+
+        let deposit_stake_authority_data = stake_deposit_authority_info.try_borrow_data()?;
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
+            &deposit_stake_authority_data,
+        )?;
+        deposit_stake_authority.check_stake_pool(*stake_pool_info.key)?;
+
+        // Validate: StakePoolDepositStakeAuthority PDA is correct
+        check_deposit_stake_authority_address(
+            program_id,
+            stake_deposit_authority_info.key,
+            deposit_stake_authority,
+        )?;
+        // Validate: whitelisted withdraw must not be paused
+        deposit_stake_authority
+            .check_not_paused(StakePoolDepositStakeAuthority::PAUSE_WITHDRAW_WHITELISTED)?;
+
+        // Validate: base signed the TX
+        if !whitelisted_signer_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        if !user_transfer_authority_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        // Validate: Whitelist must be the one set for withdrawals
+        deposit_stake_authority.check_withdraw_whitelist(whitelist_info.key)?;
+
+        check_whitelisted_signer(
+            deposit_stake_authority,
+            whitelist_info,
+            whitelisted_signer_info,
+        )?;
+
+        if deposit_stake_authority
+            .stake_pool_program_id
+            .ne(spl_stake_pool_program_info.key)
+        {
+            return Err(StakeDepositInterceptorError::InvalidStakePoolProgram.into());
+        }
+
+        let stake_pool: StakePool = try_from_slice_unchecked(&stake_pool_info.data.borrow())?;
+
+        if stake_pool
+            .stake_deposit_authority
+            .ne(stake_deposit_authority_info.key)
+        {
+            return Err(StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority.into());
+        }
+
+        // To prevent a faulty manager fee account from preventing withdrawals
+        // if the token program does not own the account, or if the account is not
+        // initialized
+        let (pool_tokens_fee, fee_lamports) = if stake_pool.manager_fee_account
+            == *user_pool_token_account_info.key
+            || check_manager_fee_info(manager_fee_account_info, &stake_pool).is_err()
+        {
+            (0, 0)
+        } else {
+            let pool_tokens_fee = stake_pool
+                .calc_pool_tokens_stake_withdrawal_fee(pool_tokens_in)
+                .ok_or(StakeDepositInterceptorError::CalculationFailure)?;
+            match stake_pool.calc_lamports_withdraw_amount(pool_tokens_fee) {
+                Some(lamports) => (pool_tokens_fee, lamports),
+                None => {
+                    msg!("Failed to calculate lamports withdraw amount from pool tokens fee; treating manager fee as 0");
+                    (0, 0)
+                }
+            }
+        };
+
+        invoke(
+            &spl_stake_pool::instruction::withdraw_stake_with_slippage(
+                spl_stake_pool_program_info.key,
+                stake_pool_info.key,
+                validator_list_info.key,
+                withdraw_authority_info.key,
+                stake_split_from_info.key,
+                stake_split_to_info.key,
+                user_stake_authority_info.key,
+                user_transfer_authority_info.key,
+                user_pool_token_account_info.key,
+                manager_fee_account_info.key,
+                pool_mint_info.key,
+                token_program_info.key,
+                pool_tokens_in,
+                minimum_lamports_out,
+            ),
+            &[
+                stake_pool_info.clone(),
+                validator_list_info.clone(),
+                withdraw_authority_info.clone(),
+                stake_split_from_info.clone(),
+                stake_split_to_info.clone(),
+                user_stake_authority_info.clone(),
+                user_transfer_authority_info.clone(),
+                user_pool_token_account_info.clone(),
+                manager_fee_account_info.clone(),
+                pool_mint_info.clone(),
+                clock_info.clone(),
+                stake_program_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+
+        StakeDepositInterceptorEvent::StakeWithdrawnWhitelisted {
+            deposit_stake_authority: *stake_deposit_authority_info.key,
+            whitelisted_signer: *whitelisted_signer_info.key,
+            pool_tokens_in,
+            fee_lamports,
+        }
+        .emit();
+
+        let rebate_mode = deposit_stake_authority.rebate_mode();
+        drop(deposit_stake_authority_data);
+        match rebate_mode {
+            RebateMode::Hopper => pay_hopper_rebate(
+                program_id,
+                whitelisted_signer_info,
+                whitelist_info,
+                stake_deposit_authority_info,
+                fee_rebate_hopper_info,
+                fee_rebate_recipient_info,
+                rebate_budget_info,
+                system_program_info,
+                fee_lamports,
+            ),
+            RebateMode::RebateVault => pay_vault_rebate(
+                program_id,
+                whitelisted_signer_info,
+                stake_deposit_authority_info,
+                rebate_vault_info,
+                user_pool_token_account_info,
+                pool_mint_info,
+                token_program_info,
+                rebate_budget_info,
+                system_program_info,
+                pool_tokens_fee,
+                fee_lamports,
+            ),
+        }
+    }
+
+    pub fn process_deposit_sol_whitelisted(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        lamports_in: u64,
+        minimum_pool_tokens_out: Option<u64>,
+    ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         let whitelisted_signer_info = next_account_info(account_info_iter)?;
         let whitelist_info = next_account_info(account_info_iter)?;
         let stake_pool_info = next_account_info(account_info_iter)?;
-        let validator_list_info = next_account_info(account_info_iter)?;
-        let stake_deposit_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let withdraw_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let deposit_stake_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let validator_stake_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let reserve_stake_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let pool_tokens_to_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let manager_fee_account_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let stake_deposit_authority_info = next_account_info(account_info_iter)?;
+        let withdraw_authority_info = next_account_info(account_info_iter)?;
+        let reserve_stake_info = next_account_info(account_info_iter)?;
+        let pool_tokens_to_info = next_account_info(account_info_iter)?;
+        let manager_fee_account_info = next_account_info(account_info_iter)?;
         let referral_fee_account_info = next_account_info(account_info_iter)?;
-        let pool_mint_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let clock_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let stake_history_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let token_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let stake_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let spl_stake_pool_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let system_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let pool_mint_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+        let spl_stake_pool_program_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
 
         // Validate: System program is correct native program
         check_system_program(system_program_info.key)?;
@@ -622,13 +2394,13 @@ impl Processor {
         // Validate `StakePoolDepositStakeAuthority` is owned by current program.
         check_account_owner(stake_deposit_authority_info, program_id)?;
 
-        // Validate: base signed the TX
+        // Validate: whitelisted signer signed the TX, as it funds the deposit
         if !whitelisted_signer_info.is_signer {
             return Err(StakeDepositInterceptorError::SignatureMissing.into());
         }
 
         let deposit_stake_authority_data = stake_deposit_authority_info.try_borrow_data()?;
-        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_unchecked(
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
             &deposit_stake_authority_data,
         )?;
         deposit_stake_authority.check_stake_pool(*stake_pool_info.key)?;
@@ -639,6 +2411,12 @@ impl Processor {
             stake_deposit_authority_info.key,
             deposit_stake_authority,
         )?;
+        // Validate: whitelisted deposit must not be paused
+        deposit_stake_authority
+            .check_not_paused(StakePoolDepositStakeAuthority::PAUSE_DEPOSIT_WHITELISTED)?;
+        // Validate: deposit must fit within the deposit caps
+        let clock = Clock::get()?;
+        deposit_stake_authority.check_deposit_caps(lamports_in, clock.epoch)?;
 
         if deposit_stake_authority
             .stake_pool_program_id
@@ -647,45 +2425,55 @@ impl Processor {
             return Err(StakeDepositInterceptorError::InvalidStakePoolProgram.into());
         }
 
-        Whitelist::load(
-            &deposit_stake_authority.jito_whitelist_management_program_id,
+        // Validate: Whitelist must be the one set for deposits
+        deposit_stake_authority.check_deposit_whitelist(whitelist_info.key)?;
+
+        check_whitelisted_signer(
+            deposit_stake_authority,
             whitelist_info,
-            false,
+            whitelisted_signer_info,
         )?;
-        let whitelist_data = whitelist_info.try_borrow_data()?;
-        let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
 
-        if !whitelist.whitelist.contains(whitelisted_signer_info.key) {
-            return Err(StakeDepositInterceptorError::InvalidWhitelistedSigner.into());
-        }
+        let pool_tokens_before = token_account_amount(pool_tokens_to_info)?;
 
-        // CPI to SPL stake-pool program to invoke DepositStake with the `StakePoolDepositStakeAuthority` as the
-        // `stake_deposit_authority`.
-        deposit_stake_cpi(
+        // CPI to SPL stake-pool program to invoke DepositSol with the `StakePoolDepositStakeAuthority` as the
+        // `sol_deposit_authority`.
+        deposit_sol_cpi(
             spl_stake_pool_program_info,
             stake_pool_info,
-            validator_list_info,
-            stake_deposit_authority_info,
             withdraw_authority_info,
-            deposit_stake_info,
-            validator_stake_info,
             reserve_stake_info,
+            whitelisted_signer_info,
             pool_tokens_to_info,
             manager_fee_account_info,
             referral_fee_account_info,
             pool_mint_info,
+            system_program_info,
             token_program_info,
-            clock_info,
-            stake_history_info,
-            stake_program_info,
+            stake_deposit_authority_info,
             deposit_stake_authority,
+            lamports_in,
             minimum_pool_tokens_out,
         )?;
 
-        Ok(())
+        let pool_tokens_minted = token_account_amount(pool_tokens_to_info)?
+            .checked_sub(pool_tokens_before)
+            .expect("overflow");
+        StakeDepositInterceptorEvent::SolDepositedWhitelisted {
+            deposit_stake_authority: *stake_deposit_authority_info.key,
+            whitelisted_signer: *whitelisted_signer_info.key,
+            lamports: lamports_in,
+            pool_tokens_minted,
+        }
+        .emit();
+
+        drop(deposit_stake_authority_data);
+        update_deposit_stake_authority(stake_deposit_authority_info, |deposit_stake_authority| {
+            deposit_stake_authority.record_deposit(lamports_in, clock.epoch)
+        })
     }
 
-    pub fn process_withdraw_stake_whitelisted(
+    pub fn process_withdraw_sol_whitelisted(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
         pool_tokens_in: u64,
@@ -695,23 +2483,28 @@ impl Processor {
         let whitelisted_signer_info = next_account_info(account_info_iter)?;
         let whitelist_info = next_account_info(account_info_iter)?;
         let stake_pool_info = next_account_info(account_info_iter)?;
-        let validator_list_info = next_account_info(account_info_iter)?;
-        let stake_deposit_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let withdraw_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let stake_split_from_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let stake_split_to_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let user_stake_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let user_transfer_authority_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let user_pool_token_account_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let stake_deposit_authority_info = next_account_info(account_info_iter)?;
+        let withdraw_authority_info = next_account_info(account_info_iter)?;
+        let user_transfer_authority_info = next_account_info(account_info_iter)?;
+        let user_pool_token_account_info = next_account_info(account_info_iter)?;
+        let reserve_stake_info = next_account_info(account_info_iter)?;
+        let lamports_to_info = next_account_info(account_info_iter)?;
         let manager_fee_account_info = next_account_info(account_info_iter)?;
-        let pool_mint_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let fee_rebate_hopper_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let fee_rebate_recipient_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let clock_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let token_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let stake_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let spl_stake_pool_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
-        let system_program_info: &AccountInfo<'_> = next_account_info(account_info_iter)?;
+        let pool_mint_info = next_account_info(account_info_iter)?;
+        let fee_rebate_hopper_info = next_account_info(account_info_iter)?;
+        let fee_rebate_recipient_info = next_account_info(account_info_iter)?;
+        let clock_info = next_account_info(account_info_iter)?;
+        let stake_history_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+        let stake_program_info = next_account_info(account_info_iter)?;
+        let spl_stake_pool_program_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let rebate_budget_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|info| info.key != program_id);
+        let rebate_vault_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|info| info.key != program_id);
 
         // Validate: System program is correct native program
         check_system_program(system_program_info.key)?;
@@ -720,7 +2513,7 @@ impl Processor {
         check_account_owner(stake_deposit_authority_info, program_id)?;
 
         let deposit_stake_authority_data = stake_deposit_authority_info.try_borrow_data()?;
-        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_unchecked(
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
             &deposit_stake_authority_data,
         )?;
         deposit_stake_authority.check_stake_pool(*stake_pool_info.key)?;
@@ -731,8 +2524,11 @@ impl Processor {
             stake_deposit_authority_info.key,
             deposit_stake_authority,
         )?;
+        // Validate: whitelisted withdraw must not be paused
+        deposit_stake_authority
+            .check_not_paused(StakePoolDepositStakeAuthority::PAUSE_WITHDRAW_WHITELISTED)?;
 
-        // Validate: base signed the TX
+        // Validate: whitelisted signer signed the TX
         if !whitelisted_signer_info.is_signer {
             return Err(StakeDepositInterceptorError::SignatureMissing.into());
         }
@@ -741,17 +2537,14 @@ impl Processor {
             return Err(StakeDepositInterceptorError::SignatureMissing.into());
         }
 
-        Whitelist::load(
-            &deposit_stake_authority.jito_whitelist_management_program_id,
+        // Validate: Whitelist must be the one set for withdrawals
+        deposit_stake_authority.check_withdraw_whitelist(whitelist_info.key)?;
+
+        check_whitelisted_signer(
+            deposit_stake_authority,
             whitelist_info,
-            false,
+            whitelisted_signer_info,
         )?;
-        let whitelist_data = whitelist_info.try_borrow_data()?;
-        let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
-
-        if !whitelist.whitelist.contains(whitelisted_signer_info.key) {
-            return Err(StakeDepositInterceptorError::InvalidWhitelistedSigner.into());
-        }
 
         if deposit_stake_authority
             .stake_pool_program_id
@@ -762,148 +2555,415 @@ impl Processor {
 
         let stake_pool: StakePool = try_from_slice_unchecked(&stake_pool_info.data.borrow())?;
 
-        if stake_pool
-            .stake_deposit_authority
-            .ne(stake_deposit_authority_info.key)
-        {
+        // Validate: SOL withdrawals from the StakePool must be gated by the interceptor PDA
+        if stake_pool.sol_withdraw_authority != Some(*stake_deposit_authority_info.key) {
             return Err(StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority.into());
         }
 
-        // To prevent a faulty manager fee account from preventing withdrawals
-        // if the token program does not own the account, or if the account is not
-        // initialized
-        let fee_lamports = if stake_pool.manager_fee_account == *user_pool_token_account_info.key
+        // Mirrors the SOL withdrawal fee charged by the StakePool, which is skipped when the
+        // pool tokens are burned from the manager fee account itself
+        let (pool_tokens_fee, fee_lamports) = if stake_pool.manager_fee_account
+            == *user_pool_token_account_info.key
             || check_manager_fee_info(manager_fee_account_info, &stake_pool).is_err()
         {
-            0
+            (0, 0)
         } else {
             let pool_tokens_fee = stake_pool
-                .calc_pool_tokens_stake_withdrawal_fee(pool_tokens_in)
+                .calc_pool_tokens_sol_withdrawal_fee(pool_tokens_in)
                 .ok_or(StakeDepositInterceptorError::CalculationFailure)?;
             match stake_pool.calc_lamports_withdraw_amount(pool_tokens_fee) {
-                Some(lamports) => lamports,
+                Some(lamports) => (pool_tokens_fee, lamports),
                 None => {
                     msg!("Failed to calculate lamports withdraw amount from pool tokens fee; treating manager fee as 0");
-                    0
+                    (0, 0)
+                }
+            }
+        };
+
+        // CPI to SPL stake-pool program to invoke WithdrawSol with the `StakePoolDepositStakeAuthority` as the
+        // `sol_withdraw_authority`.
+        invoke_signed(
+            &spl_stake_pool::instruction::withdraw_sol_with_authority_and_slippage(
+                spl_stake_pool_program_info.key,
+                stake_pool_info.key,
+                stake_deposit_authority_info.key,
+                withdraw_authority_info.key,
+                user_transfer_authority_info.key,
+                user_pool_token_account_info.key,
+                reserve_stake_info.key,
+                lamports_to_info.key,
+                manager_fee_account_info.key,
+                pool_mint_info.key,
+                token_program_info.key,
+                pool_tokens_in,
+                minimum_lamports_out,
+            ),
+            &[
+                stake_pool_info.clone(),
+                withdraw_authority_info.clone(),
+                user_transfer_authority_info.clone(),
+                user_pool_token_account_info.clone(),
+                reserve_stake_info.clone(),
+                lamports_to_info.clone(),
+                manager_fee_account_info.clone(),
+                pool_mint_info.clone(),
+                clock_info.clone(),
+                stake_history_info.clone(),
+                stake_program_info.clone(),
+                token_program_info.clone(),
+                stake_deposit_authority_info.clone(),
+            ],
+            &[deposit_stake_authority_signer_seeds!(
+                deposit_stake_authority
+            )],
+        )?;
+
+        StakeDepositInterceptorEvent::SolWithdrawnWhitelisted {
+            deposit_stake_authority: *stake_deposit_authority_info.key,
+            whitelisted_signer: *whitelisted_signer_info.key,
+            pool_tokens_in,
+            fee_lamports,
+        }
+        .emit();
+
+        let rebate_mode = deposit_stake_authority.rebate_mode();
+        drop(deposit_stake_authority_data);
+        match rebate_mode {
+            RebateMode::Hopper => pay_hopper_rebate(
+                program_id,
+                whitelisted_signer_info,
+                whitelist_info,
+                stake_deposit_authority_info,
+                fee_rebate_hopper_info,
+                fee_rebate_recipient_info,
+                rebate_budget_info,
+                system_program_info,
+                fee_lamports,
+            ),
+            RebateMode::RebateVault => pay_vault_rebate(
+                program_id,
+                whitelisted_signer_info,
+                stake_deposit_authority_info,
+                rebate_vault_info,
+                user_pool_token_account_info,
+                pool_mint_info,
+                token_program_info,
+                rebate_budget_info,
+                system_program_info,
+                pool_tokens_fee,
+                fee_lamports,
+            ),
+        }
+    }
+
+    pub fn process_withdraw_from_hopper(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let authority_info = next_account_info(account_info_iter)?;
+        let stake_deposit_authority_info = next_account_info(account_info_iter)?;
+        let whitelist_info = next_account_info(account_info_iter)?;
+        let hopper_info = next_account_info(account_info_iter)?;
+        let recipient_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Validate: System program
+        check_system_program(system_program_info.key)?;
+
+        // Validate: Authority signed the TX
+        if !authority_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        // Validate: StakePoolDepositStakeAuthority is owned by this program
+        check_account_owner(stake_deposit_authority_info, program_id)?;
+
+        let deposit_stake_authority_data = stake_deposit_authority_info.try_borrow_data()?;
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
+            &deposit_stake_authority_data,
+        )?;
+
+        // Validate: StakePoolDepositStakeAuthority PDA is correct
+        check_deposit_stake_authority_address(
+            program_id,
+            stake_deposit_authority_info.key,
+            deposit_stake_authority,
+        )?;
+        // Validate: hopper withdraw must not be paused
+        deposit_stake_authority
+            .check_not_paused(StakePoolDepositStakeAuthority::PAUSE_WITHDRAW_FROM_HOPPER)?;
+
+        // Validate: Authority matches the deposit stake authority's authority
+        if deposit_stake_authority.authority != *authority_info.key {
+            return Err(StakeDepositInterceptorError::InvalidAuthority.into());
+        }
+
+        // Validate: Hopper PDA
+        Hopper::load(
+            program_id,
+            hopper_info,
+            whitelist_info.key,
+            stake_deposit_authority_info.key,
+            true,
+        )?;
+
+        // Transfer SOL from hopper to recipient
+        let (_, hopper_bump, mut hopper_seeds) = Hopper::find_program_address(
+            program_id,
+            whitelist_info.key,
+            stake_deposit_authority_info.key,
+        );
+        hopper_seeds.push(vec![hopper_bump]);
+
+        invoke_signed(
+            &transfer(hopper_info.key, recipient_info.key, amount),
+            &[
+                hopper_info.clone(),
+                recipient_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[hopper_seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        )?;
+
+        StakeDepositInterceptorEvent::HopperWithdrawn {
+            hopper: *hopper_info.key,
+            recipient: *recipient_info.key,
+            lamports: amount,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Upgrade a `StakePoolDepositStakeAuthority` or `DepositReceipt` written with an older
+    /// layout version to the current one, reallocating it when the layout has grown. The
+    /// payer covers any additional rent. Permissionless, as the account's fields are only
+    /// brought up to date.
+    pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payer_info = next_account_info(account_info_iter)?;
+        let account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|info| info.key != program_id);
+        let whitelist_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|info| info.key != program_id);
+
+        // Validate: System program is correct native program
+        check_system_program(system_program_info.key)?;
+
+        // Validate: program owns the account
+        check_account_owner(account_info, program_id)?;
+
+        let discriminator = *account_info
+            .try_borrow_data()?
+            .first()
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let (from_version, to_version) =
+            if discriminator == StakePoolDepositStakeAuthority::DISCRIMINATOR {
+                let versions = migrate_account::<StakePoolDepositStakeAuthority>(
+                    payer_info,
+                    account_info,
+                    system_program_info,
+                )?;
+                // The authority may bind the whitelists of an account that predates them
+                if let Some((authority_info, whitelist_info)) = authority_info.zip(whitelist_info) {
+                    seed_operation_whitelists(
+                        program_id,
+                        account_info,
+                        authority_info,
+                        whitelist_info,
+                        versions.0,
+                    )?;
                 }
+                versions
+            } else if discriminator == DepositReceipt::DISCRIMINATOR {
+                migrate_account::<DepositReceipt>(payer_info, account_info, system_program_info)?
+            } else {
+                return Err(ProgramError::InvalidAccountData);
+            };
+
+        if from_version != to_version {
+            StakeDepositInterceptorEvent::AccountMigrated {
+                account: *account_info.key,
+                from_version,
+                to_version,
             }
+            .emit();
+        }
+
+        Ok(())
+    }
+
+    /// Transfer `amount` "pool" tokens from the funder into the RebateVault of the
+    /// `StakePoolDepositStakeAuthority`, creating the RebateVault token account if needed.
+    pub fn process_fund_rebate_vault(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let funder_info = next_account_info(account_info_iter)?;
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let rebate_vault_info = next_account_info(account_info_iter)?;
+        let source_info = next_account_info(account_info_iter)?;
+        let pool_mint_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Validate: System program
+        check_system_program(system_program_info.key)?;
+
+        // Validate: funder signed the TX
+        if !funder_info.is_signer {
+            return Err(StakeDepositInterceptorError::SignatureMissing.into());
+        }
+
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
+
+        // Validate: token program must be one of the SPL Token programs
+        spl_token_2022_interface::check_spl_token_program_account(token_program_info.key)?;
+
+        let deposit_stake_authority = {
+            let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
+            *StakePoolDepositStakeAuthority::try_from_slice_versioned(
+                &deposit_stake_authority_data,
+            )?
         };
 
+        check_deposit_stake_authority_address(
+            program_id,
+            deposit_stake_authority_info.key,
+            &deposit_stake_authority,
+        )?;
+
+        // Validate: Pool mint should match that of the `StakePoolDepositStakeAuthority`
+        if pool_mint_info.key != &deposit_stake_authority.pool_mint {
+            return Err(StakeDepositInterceptorError::InvalidPoolMint.into());
+        }
+
+        let rebate_vault_initialized = RebateVault::load(
+            program_id,
+            rebate_vault_info,
+            deposit_stake_authority_info.key,
+            token_program_info.key,
+        )?;
+        if !rebate_vault_initialized {
+            // Size the token account for the extensions the pool mint requires of it
+            let space = {
+                let pool_mint_data = pool_mint_info.try_borrow_data()?;
+                let pool_mint = StateWithExtensions::<Mint>::unpack(&pool_mint_data)?;
+                let account_extensions = ExtensionType::get_required_init_account_extensions(
+                    &pool_mint.get_extension_types()?,
+                );
+                ExtensionType::try_calculate_account_len::<Account>(&account_extensions)?
+            };
+            let (_, bump_seed, seeds) =
+                RebateVault::find_program_address(program_id, deposit_stake_authority_info.key);
+            let bump = [bump_seed];
+            let mut pda_seeds: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+            pda_seeds.push(&bump);
+            create_pda_account(
+                funder_info,
+                &Rent::get()?,
+                space,
+                token_program_info.key,
+                system_program_info,
+                rebate_vault_info,
+                &pda_seeds,
+            )?;
+            invoke(
+                &spl_token_2022_interface::instruction::initialize_account3(
+                    token_program_info.key,
+                    rebate_vault_info.key,
+                    pool_mint_info.key,
+                    deposit_stake_authority_info.key,
+                )?,
+                &[rebate_vault_info.clone(), pool_mint_info.clone()],
+            )?;
+        }
+
+        let decimals = {
+            let pool_mint_data = pool_mint_info.try_borrow_data()?;
+            StateWithExtensions::<Mint>::unpack(&pool_mint_data)?
+                .base
+                .decimals
+        };
         invoke(
-            &spl_stake_pool::instruction::withdraw_stake_with_slippage(
-                spl_stake_pool_program_info.key,
-                stake_pool_info.key,
-                validator_list_info.key,
-                withdraw_authority_info.key,
-                stake_split_from_info.key,
-                stake_split_to_info.key,
-                user_stake_authority_info.key,
-                user_transfer_authority_info.key,
-                user_pool_token_account_info.key,
-                manager_fee_account_info.key,
-                pool_mint_info.key,
+            &spl_token_2022_interface::instruction::transfer_checked(
                 token_program_info.key,
-                pool_tokens_in,
-                minimum_lamports_out,
-            ),
+                source_info.key,
+                pool_mint_info.key,
+                rebate_vault_info.key,
+                funder_info.key,
+                &[],
+                amount,
+                decimals,
+            )?,
             &[
-                stake_pool_info.clone(),
-                validator_list_info.clone(),
-                withdraw_authority_info.clone(),
-                stake_split_from_info.clone(),
-                stake_split_to_info.clone(),
-                user_stake_authority_info.clone(),
-                user_transfer_authority_info.clone(),
-                user_pool_token_account_info.clone(),
-                manager_fee_account_info.clone(),
+                source_info.clone(),
                 pool_mint_info.clone(),
-                clock_info.clone(),
-                stake_program_info.clone(),
-                token_program_info.clone(),
+                rebate_vault_info.clone(),
+                funder_info.clone(),
             ],
         )?;
 
-        if fee_lamports > 0 {
-            Hopper::load(
-                program_id,
-                fee_rebate_hopper_info,
-                whitelist_info.key,
-                stake_deposit_authority_info.key,
-                true,
-            )?;
-
-            let hopper_balance = fee_rebate_hopper_info.lamports();
-            let rent = Rent::get()?;
-            let min_balance = rent.minimum_balance(fee_rebate_hopper_info.data_len());
-            let available = hopper_balance.saturating_sub(min_balance);
-            let rebate_lamports = fee_lamports.min(available);
-
-            // If there are no funds in the Hopper, the TX should still succeed and no 0.1% rebate will be sent ( This is an extreme edge case )
-            if rebate_lamports > 0 {
-                let (_, hopper_bump, mut hopper_seeds) = Hopper::find_program_address(
-                    program_id,
-                    whitelist_info.key,
-                    stake_deposit_authority_info.key,
-                );
-                hopper_seeds.push(vec![hopper_bump]);
-
-                invoke_signed(
-                    &transfer(
-                        fee_rebate_hopper_info.key,
-                        fee_rebate_recipient_info.key,
-                        rebate_lamports,
-                    ),
-                    &[
-                        fee_rebate_hopper_info.clone(),
-                        fee_rebate_recipient_info.clone(),
-                        system_program_info.clone(),
-                    ],
-                    &[hopper_seeds
-                        .iter()
-                        .map(|seed| seed.as_slice())
-                        .collect::<Vec<&[u8]>>()
-                        .as_slice()],
-                )?;
-            }
+        StakeDepositInterceptorEvent::RebateVaultFunded {
+            deposit_stake_authority: *deposit_stake_authority_info.key,
+            funder: *funder_info.key,
+            amount,
         }
+        .emit();
 
         Ok(())
     }
 
-    pub fn process_withdraw_from_hopper(
+    /// Transfer `amount` "pool" tokens from the RebateVault to the destination token account.
+    /// ONLY accessible by the current authority.
+    pub fn process_withdraw_from_rebate_vault(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
         amount: u64,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         let authority_info = next_account_info(account_info_iter)?;
-        let stake_deposit_authority_info = next_account_info(account_info_iter)?;
-        let whitelist_info = next_account_info(account_info_iter)?;
-        let hopper_info = next_account_info(account_info_iter)?;
-        let recipient_info = next_account_info(account_info_iter)?;
-        let system_program_info = next_account_info(account_info_iter)?;
-
-        // Validate: System program
-        check_system_program(system_program_info.key)?;
+        let deposit_stake_authority_info = next_account_info(account_info_iter)?;
+        let rebate_vault_info = next_account_info(account_info_iter)?;
+        let destination_info = next_account_info(account_info_iter)?;
+        let pool_mint_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
 
         // Validate: Authority signed the TX
         if !authority_info.is_signer {
             return Err(StakeDepositInterceptorError::SignatureMissing.into());
         }
 
-        // Validate: StakePoolDepositStakeAuthority is owned by this program
-        check_account_owner(stake_deposit_authority_info, program_id)?;
+        // Validate: program owns `StakePoolDepositStakeAuthority`
+        check_account_owner(deposit_stake_authority_info, program_id)?;
 
-        let deposit_stake_authority_data = stake_deposit_authority_info.try_borrow_data()?;
-        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_unchecked(
-            &deposit_stake_authority_data,
-        )?;
+        // Validate: token program must be one of the SPL Token programs
+        spl_token_2022_interface::check_spl_token_program_account(token_program_info.key)?;
+
+        let deposit_stake_authority = {
+            let deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_data()?;
+            *StakePoolDepositStakeAuthority::try_from_slice_versioned(
+                &deposit_stake_authority_data,
+            )?
+        };
 
-        // Validate: StakePoolDepositStakeAuthority PDA is correct
         check_deposit_stake_authority_address(
             program_id,
-            stake_deposit_authority_info.key,
-            deposit_stake_authority,
+            deposit_stake_authority_info.key,
+            &deposit_stake_authority,
         )?;
 
         // Validate: Authority matches the deposit stake authority's authority
@@ -911,37 +2971,45 @@ impl Processor {
             return Err(StakeDepositInterceptorError::InvalidAuthority.into());
         }
 
-        // Validate: Hopper PDA
-        Hopper::load(
-            program_id,
-            hopper_info,
-            whitelist_info.key,
-            stake_deposit_authority_info.key,
-            true,
-        )?;
+        // Validate: Pool mint should match that of the `StakePoolDepositStakeAuthority`
+        if pool_mint_info.key != &deposit_stake_authority.pool_mint {
+            return Err(StakeDepositInterceptorError::InvalidPoolMint.into());
+        }
 
-        // Transfer SOL from hopper to recipient
-        let (_, hopper_bump, mut hopper_seeds) = Hopper::find_program_address(
+        // Validate: RebateVault PDA, which must have been funded
+        if !RebateVault::load(
             program_id,
-            whitelist_info.key,
-            stake_deposit_authority_info.key,
-        );
-        hopper_seeds.push(vec![hopper_bump]);
+            rebate_vault_info,
+            deposit_stake_authority_info.key,
+            token_program_info.key,
+        )? {
+            return Err(ProgramError::UninitializedAccount);
+        }
 
-        invoke_signed(
-            &transfer(hopper_info.key, recipient_info.key, amount),
-            &[
-                hopper_info.clone(),
-                recipient_info.clone(),
-                system_program_info.clone(),
-            ],
-            &[hopper_seeds
-                .iter()
-                .map(|seed| seed.as_slice())
-                .collect::<Vec<&[u8]>>()
-                .as_slice()],
+        let decimals = {
+            let pool_mint_data = pool_mint_info.try_borrow_data()?;
+            StateWithExtensions::<Mint>::unpack(&pool_mint_data)?
+                .base
+                .decimals
+        };
+        transfer_tokens_cpi(
+            token_program_info.clone(),
+            rebate_vault_info.clone(),
+            pool_mint_info.clone(),
+            destination_info.clone(),
+            deposit_stake_authority_info.clone(),
+            amount,
+            decimals,
+            &deposit_stake_authority,
         )?;
 
+        StakeDepositInterceptorEvent::RebateVaultWithdrawn {
+            deposit_stake_authority: *deposit_stake_authority_info.key,
+            destination: *destination_info.key,
+            amount,
+        }
+        .emit();
+
         Ok(())
     }
 
@@ -970,7 +3038,7 @@ impl Processor {
                 Self::process_change_deposit_receipt_owner(program_id, accounts)?;
             }
             StakeDepositInterceptorInstruction::ClaimPoolTokens => {
-                Self::process_claim_pool_tokens(program_id, accounts)?;
+                Self::process_claim_pool_tokens(program_id, accounts, None)?;
             }
             StakeDepositInterceptorInstruction::DepositStakeWhitelisted {
                 minimum_pool_tokens_out,
@@ -998,6 +3066,112 @@ impl Processor {
                 msg!("Instruction: WithdrawFromHopper");
                 Self::process_withdraw_from_hopper(program_id, accounts, amount)?;
             }
+            StakeDepositInterceptorInstruction::DepositSol(args) => {
+                Self::process_deposit_sol(program_id, accounts, args, None)?;
+            }
+            StakeDepositInterceptorInstruction::DepositSolWithSlippage(args) => {
+                let deposit_sol_args = DepositSolArgs {
+                    owner: args.owner,
+                    lamports_in: args.lamports_in,
+                };
+                Self::process_deposit_sol(
+                    program_id,
+                    accounts,
+                    deposit_sol_args,
+                    Some(args.minimum_pool_tokens_out),
+                )?;
+            }
+            StakeDepositInterceptorInstruction::ClaimPoolTokensPartial { amount } => {
+                Self::process_claim_pool_tokens(program_id, accounts, Some(amount))?;
+            }
+            StakeDepositInterceptorInstruction::SplitDepositReceipt { amount } => {
+                Self::process_split_deposit_receipt(program_id, accounts, amount)?;
+            }
+            StakeDepositInterceptorInstruction::MergeDepositReceipts => {
+                Self::process_merge_deposit_receipts(program_id, accounts)?;
+            }
+            StakeDepositInterceptorInstruction::Pause { operations } => {
+                msg!("Instruction: Pause");
+                Self::process_set_paused(program_id, accounts, operations, true)?;
+            }
+            StakeDepositInterceptorInstruction::Unpause { operations } => {
+                msg!("Instruction: Unpause");
+                Self::process_set_paused(program_id, accounts, operations, false)?;
+            }
+            StakeDepositInterceptorInstruction::ProposeAuthority => {
+                msg!("Instruction: ProposeAuthority");
+                Self::process_propose_authority(program_id, accounts)?;
+            }
+            StakeDepositInterceptorInstruction::AcceptAuthority => {
+                msg!("Instruction: AcceptAuthority");
+                Self::process_accept_authority(program_id, accounts)?;
+            }
+            StakeDepositInterceptorInstruction::CancelAuthorityProposal => {
+                msg!("Instruction: CancelAuthorityProposal");
+                Self::process_cancel_authority_proposal(program_id, accounts)?;
+            }
+            StakeDepositInterceptorInstruction::CloseDepositStakeAuthority => {
+                msg!("Instruction: CloseDepositStakeAuthority");
+                Self::process_close_deposit_stake_authority(program_id, accounts)?;
+            }
+            StakeDepositInterceptorInstruction::SweepVaultSurplus => {
+                msg!("Instruction: SweepVaultSurplus");
+                Self::process_sweep_vault_surplus(program_id, accounts)?;
+            }
+            StakeDepositInterceptorInstruction::SetFeeSplit { recipients } => {
+                msg!("Instruction: SetFeeSplit");
+                Self::process_set_fee_split(program_id, accounts, recipients)?;
+            }
+            StakeDepositInterceptorInstruction::ApproveClaimDelegate => {
+                msg!("Instruction: ApproveClaimDelegate");
+                Self::process_set_claim_delegate(program_id, accounts, true)?;
+            }
+            StakeDepositInterceptorInstruction::RevokeClaimDelegate => {
+                msg!("Instruction: RevokeClaimDelegate");
+                Self::process_set_claim_delegate(program_id, accounts, false)?;
+            }
+            StakeDepositInterceptorInstruction::MigrateAccount => {
+                msg!("Instruction: MigrateAccount");
+                Self::process_migrate_account(program_id, accounts)?;
+            }
+            StakeDepositInterceptorInstruction::DepositSolWhitelisted {
+                lamports_in,
+                minimum_pool_tokens_out,
+            } => {
+                msg!("Instruction: DepositSolWhitelisted");
+                Self::process_deposit_sol_whitelisted(
+                    program_id,
+                    accounts,
+                    lamports_in,
+                    minimum_pool_tokens_out,
+                )?;
+            }
+            StakeDepositInterceptorInstruction::WithdrawSolWhitelisted {
+                pool_tokens_in,
+                minimum_lamports_out,
+            } => {
+                msg!("Instruction: WithdrawSolWhitelisted");
+                Self::process_withdraw_sol_whitelisted(
+                    program_id,
+                    accounts,
+                    pool_tokens_in,
+                    minimum_lamports_out,
+                )?;
+            }
+            StakeDepositInterceptorInstruction::FundRebateVault { amount } => {
+                msg!("Instruction: FundRebateVault");
+                Self::process_fund_rebate_vault(program_id, accounts, amount)?;
+            }
+            StakeDepositInterceptorInstruction::WithdrawFromRebateVault { amount } => {
+                msg!("Instruction: WithdrawFromRebateVault");
+                Self::process_withdraw_from_rebate_vault(program_id, accounts, amount)?;
+            }
+            StakeDepositInterceptorInstruction::RedeemReceiptToStake {
+                minimum_lamports_out,
+            } => {
+                msg!("Instruction: RedeemReceiptToStake");
+                Self::process_redeem_receipt_to_stake(program_id, accounts, minimum_lamports_out)?;
+            }
         }
         Ok(())
     }
@@ -1020,6 +3194,93 @@ fn check_account_owner(
     }
 }
 
+/// Upgrade the account to layout `T::VERSION` in place, topping up rent from `payer` and
+/// reallocating when the layout has grown. Returns the previous and current layout versions.
+fn migrate_account<'a, T: VersionedAccount>(
+    payer: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<(u8, u8), ProgramError> {
+    let version = account_version(&account_info.try_borrow_data()?);
+
+    // Validate: account must not be written by a newer program
+    if version > T::VERSION {
+        return Err(StakeDepositInterceptorError::UnsupportedAccountVersion.into());
+    }
+    if version == T::VERSION && account_info.data_len() == T::LEN {
+        return Ok((version, version));
+    }
+
+    if account_info.data_len() < T::LEN {
+        let required_lamports = Rent::get()?
+            .minimum_balance(T::LEN)
+            .saturating_sub(account_info.lamports());
+        if required_lamports > 0 {
+            invoke(
+                &transfer(payer.key, account_info.key, required_lamports),
+                &[payer.clone(), account_info.clone(), system_program.clone()],
+            )?;
+        }
+        account_info.resize(T::LEN)?;
+    }
+
+    let mut data = account_info.try_borrow_mut_data()?;
+    data[ACCOUNT_VERSION_OFFSET] = T::VERSION;
+    T::try_from_slice_unchecked_mut(&mut data)?.upgrade(version);
+
+    Ok((version, T::VERSION))
+}
+
+/// Bind both `deposit_whitelist` and `withdraw_whitelist` of a StakePoolDepositStakeAuthority
+/// migrated from a layout `from_version` that predates them to the Whitelist its authority has
+/// been passing to the whitelisted instructions.
+fn seed_operation_whitelists(
+    program_id: &Pubkey,
+    deposit_stake_authority_info: &AccountInfo,
+    authority_info: &AccountInfo,
+    whitelist_info: &AccountInfo,
+    from_version: u8,
+) -> ProgramResult {
+    // Validate: later layouts set the whitelists with UpdateStakePoolDepositStakeAuthority
+    if from_version >= StakePoolDepositStakeAuthority::OPERATION_WHITELISTS_VERSION {
+        msg!("Whitelists are already part of the account layout");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate: authority signed the TX
+    if !authority_info.is_signer {
+        return Err(StakeDepositInterceptorError::SignatureMissing.into());
+    }
+
+    let mut deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_mut_data()?;
+    let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned_mut(
+        &mut deposit_stake_authority_data,
+    )?;
+
+    check_deposit_stake_authority_address(
+        program_id,
+        deposit_stake_authority_info.key,
+        deposit_stake_authority,
+    )?;
+
+    // Validate: authority matches
+    if deposit_stake_authority.authority != *authority_info.key {
+        return Err(StakeDepositInterceptorError::InvalidAuthority.into());
+    }
+
+    // Validate: Whitelist must be owned by the Jito Whitelist Management program
+    Whitelist::load(
+        &deposit_stake_authority.jito_whitelist_management_program_id,
+        whitelist_info,
+        false,
+    )?;
+
+    deposit_stake_authority.deposit_whitelist = *whitelist_info.key;
+    deposit_stake_authority.withdraw_whitelist = *whitelist_info.key;
+
+    Ok(())
+}
+
 /// Check system program address
 fn check_system_program(program_id: &Pubkey) -> Result<(), ProgramError> {
     if *program_id != solana_system_interface::program::id() {
@@ -1144,51 +3405,123 @@ fn deposit_stake_cpi<'a>(
 ) -> Result<(), ProgramError> {
     let account_infos = vec![
         stake_pool_info.clone(),
-        validator_list_storage_info.clone(),
-        stake_pool_deposit_authority_info.clone(),
+        validator_list_storage_info.clone(),
+        stake_pool_deposit_authority_info.clone(),
+        stake_pool_withdraw_authority_info.clone(),
+        deposit_stake_address_info.clone(),
+        validator_stake_account_info.clone(),
+        reserve_stake_account_info.clone(),
+        pool_tokens_to_info.clone(),
+        manager_fee_account_info.clone(),
+        referrer_pool_tokens_account_info.clone(),
+        pool_mint_info.clone(),
+        sysvar_clock_info.clone(),
+        sysvar_stake_history.clone(),
+        token_program_id_info.clone(),
+        stake_program_info.clone(),
+    ];
+    let accounts = vec![
+        AccountMeta::new(*stake_pool_info.key, false),
+        AccountMeta::new(*validator_list_storage_info.key, false),
+        AccountMeta::new_readonly(*stake_pool_deposit_authority_info.key, true),
+        AccountMeta::new_readonly(*stake_pool_withdraw_authority_info.key, false),
+        AccountMeta::new(*deposit_stake_address_info.key, false),
+        AccountMeta::new(*validator_stake_account_info.key, false),
+        AccountMeta::new(*reserve_stake_account_info.key, false),
+        AccountMeta::new(*pool_tokens_to_info.key, false),
+        AccountMeta::new(*manager_fee_account_info.key, false),
+        AccountMeta::new(*referrer_pool_tokens_account_info.key, false),
+        AccountMeta::new(*pool_mint_info.key, false),
+        AccountMeta::new_readonly(*sysvar_clock_info.key, false),
+        AccountMeta::new_readonly(*sysvar_stake_history.key, false),
+        AccountMeta::new_readonly(*token_program_id_info.key, false),
+        AccountMeta::new_readonly(*stake_program_info.key, false),
+    ];
+
+    let data;
+    if let Some(minimum_pool_tokens_out) = minimum_pool_tokens_out {
+        data = borsh::to_vec(
+            &spl_stake_pool::instruction::StakePoolInstruction::DepositStakeWithSlippage {
+                minimum_pool_tokens_out,
+            },
+        )
+        .unwrap()
+    } else {
+        data =
+            borsh::to_vec(&spl_stake_pool::instruction::StakePoolInstruction::DepositStake).unwrap()
+    }
+    let ix = Instruction {
+        program_id: *program_info.key,
+        accounts,
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &account_infos,
+        &[deposit_stake_authority_signer_seeds!(
+            deposit_stake_authority
+        )],
+    )
+}
+
+/// Invokes the `DepositSol` instruction for the given stake-pool program.
+#[allow(clippy::too_many_arguments)]
+fn deposit_sol_cpi<'a>(
+    program_info: &AccountInfo<'a>,
+    stake_pool_info: &AccountInfo<'a>,
+    stake_pool_withdraw_authority_info: &AccountInfo<'a>,
+    reserve_stake_account_info: &AccountInfo<'a>,
+    lamports_from_info: &AccountInfo<'a>,
+    pool_tokens_to_info: &AccountInfo<'a>,
+    manager_fee_account_info: &AccountInfo<'a>,
+    referrer_pool_tokens_account_info: &AccountInfo<'a>,
+    pool_mint_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    token_program_id_info: &AccountInfo<'a>,
+    sol_deposit_authority_info: &AccountInfo<'a>,
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+    lamports_in: u64,
+    minimum_pool_tokens_out: Option<u64>,
+) -> Result<(), ProgramError> {
+    let account_infos = vec![
+        stake_pool_info.clone(),
         stake_pool_withdraw_authority_info.clone(),
-        deposit_stake_address_info.clone(),
-        validator_stake_account_info.clone(),
         reserve_stake_account_info.clone(),
+        lamports_from_info.clone(),
         pool_tokens_to_info.clone(),
         manager_fee_account_info.clone(),
         referrer_pool_tokens_account_info.clone(),
         pool_mint_info.clone(),
-        sysvar_clock_info.clone(),
-        sysvar_stake_history.clone(),
+        system_program_info.clone(),
         token_program_id_info.clone(),
-        stake_program_info.clone(),
+        sol_deposit_authority_info.clone(),
     ];
     let accounts = vec![
         AccountMeta::new(*stake_pool_info.key, false),
-        AccountMeta::new(*validator_list_storage_info.key, false),
-        AccountMeta::new_readonly(*stake_pool_deposit_authority_info.key, true),
         AccountMeta::new_readonly(*stake_pool_withdraw_authority_info.key, false),
-        AccountMeta::new(*deposit_stake_address_info.key, false),
-        AccountMeta::new(*validator_stake_account_info.key, false),
         AccountMeta::new(*reserve_stake_account_info.key, false),
+        AccountMeta::new(*lamports_from_info.key, true),
         AccountMeta::new(*pool_tokens_to_info.key, false),
         AccountMeta::new(*manager_fee_account_info.key, false),
         AccountMeta::new(*referrer_pool_tokens_account_info.key, false),
         AccountMeta::new(*pool_mint_info.key, false),
-        AccountMeta::new_readonly(*sysvar_clock_info.key, false),
-        AccountMeta::new_readonly(*sysvar_stake_history.key, false),
+        AccountMeta::new_readonly(*system_program_info.key, false),
         AccountMeta::new_readonly(*token_program_id_info.key, false),
-        AccountMeta::new_readonly(*stake_program_info.key, false),
+        AccountMeta::new_readonly(*sol_deposit_authority_info.key, true),
     ];
 
-    let data;
-    if let Some(minimum_pool_tokens_out) = minimum_pool_tokens_out {
-        data = borsh::to_vec(
-            &spl_stake_pool::instruction::StakePoolInstruction::DepositStakeWithSlippage {
+    let data = if let Some(minimum_pool_tokens_out) = minimum_pool_tokens_out {
+        borsh::to_vec(
+            &spl_stake_pool::instruction::StakePoolInstruction::DepositSolWithSlippage {
+                lamports_in,
                 minimum_pool_tokens_out,
             },
         )
         .unwrap()
     } else {
-        data =
-            borsh::to_vec(&spl_stake_pool::instruction::StakePoolInstruction::DepositStake).unwrap()
-    }
+        borsh::to_vec(&spl_stake_pool::instruction::StakePoolInstruction::DepositSol(lamports_in))
+            .unwrap()
+    };
     let ix = Instruction {
         program_id: *program_info.key,
         accounts,
@@ -1203,6 +3536,145 @@ fn deposit_stake_cpi<'a>(
     )
 }
 
+/// Create and initialize a DepositReceipt for the "pool" tokens minted to the vault.
+#[allow(clippy::too_many_arguments)]
+fn create_deposit_receipt<'a>(
+    program_id: &Pubkey,
+    payer_info: &AccountInfo<'a>,
+    deposit_receipt_info: &AccountInfo<'a>,
+    stake_pool_info: &AccountInfo<'a>,
+    base_info: &AccountInfo<'a>,
+    deposit_stake_authority_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+    owner: Pubkey,
+    referrer_info: &AccountInfo<'a>,
+    pool_tokens_minted: u64,
+    initial_fee_bps: u32,
+    whitelist_discounted: bool,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    let clock = Clock::get()?;
+
+    // The StakePool referral fee account doubles as the referrer of the cool down fee, unless
+    // it was defaulted to the vault or no share is configured. A referral token account of
+    // the DepositReceipt `owner` is not recorded, so the share cannot become a discount on
+    // self-referred deposits.
+    let referrer_fee_share_bps = u32::from(deposit_stake_authority.referrer_fee_share_bps);
+    let record_referrer = referrer_fee_share_bps > 0
+        && referrer_info.key != &deposit_stake_authority.vault
+        && !is_token_account_owned_by(referrer_info, &owner)?;
+
+    let (deposit_receipt_pda, bump_seed) =
+        derive_stake_deposit_receipt(program_id, stake_pool_info.key, base_info.key);
+
+    // Validate: DepositReceipt should be canonical PDA
+    if deposit_receipt_pda != *deposit_receipt_info.key {
+        return Err(StakeDepositInterceptorError::InvalidSeeds.into());
+    }
+
+    let pda_seeds = [
+        DEPOSIT_RECEIPT,
+        &stake_pool_info.key.to_bytes(),
+        &base_info.key.to_bytes(),
+        &[bump_seed],
+    ];
+    // Create and initialize the DepositReceipt account
+    create_pda_account(
+        payer_info,
+        &rent,
+        8 + mem::size_of::<DepositReceipt>(),
+        program_id,
+        system_program_info,
+        deposit_receipt_info,
+        &pda_seeds,
+    )?;
+
+    let mut deposit_receipt_data = deposit_receipt_info.try_borrow_mut_data()?;
+    deposit_receipt_data[0] = DepositReceipt::DISCRIMINATOR;
+    deposit_receipt_data[ACCOUNT_VERSION_OFFSET] = DepositReceipt::VERSION;
+    let deposit_receipt = DepositReceipt::try_from_slice_versioned_mut(&mut deposit_receipt_data)?;
+
+    deposit_receipt.base = *base_info.key;
+    deposit_receipt.owner = owner;
+    deposit_receipt.stake_pool = *stake_pool_info.key;
+    deposit_receipt.stake_pool_deposit_stake_authority = *deposit_stake_authority_info.key;
+    deposit_receipt.deposit_time = clock.unix_timestamp.unsigned_abs().into();
+    deposit_receipt.deposit_slot = clock.slot.into();
+    deposit_receipt.deposit_epoch = clock.epoch.into();
+    deposit_receipt.lst_amount = pool_tokens_minted.into();
+    deposit_receipt.cool_down_seconds = deposit_stake_authority.cool_down_seconds;
+    deposit_receipt.initial_fee_bps = initial_fee_bps.into();
+    deposit_receipt.whitelist_discounted = whitelist_discounted.into();
+    deposit_receipt.fee_curve = deposit_stake_authority.fee_curve;
+    deposit_receipt.cool_down_unit = deposit_stake_authority.cool_down_unit;
+    deposit_receipt.bump_seed = bump_seed;
+    if record_referrer {
+        deposit_receipt.referrer = *referrer_info.key;
+        deposit_receipt.referrer_fee_share_bps = referrer_fee_share_bps.into();
+    }
+
+    StakeDepositInterceptorEvent::DepositReceiptCreated {
+        deposit_receipt: *deposit_receipt_info.key,
+        owner,
+        stake_pool: *stake_pool_info.key,
+        deposit_stake_authority: *deposit_stake_authority_info.key,
+        lst_amount: pool_tokens_minted,
+        initial_fee_bps,
+        deposit_time: deposit_receipt.deposit_time.into(),
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Whether `token_account_info` is a token account owned by `owner`
+fn is_token_account_owned_by(
+    token_account_info: &AccountInfo,
+    owner: &Pubkey,
+) -> Result<bool, ProgramError> {
+    let token_account_data = token_account_info.try_borrow_data()?;
+    Ok(StateWithExtensions::<Account>::unpack(&token_account_data)
+        .is_ok_and(|token_account| token_account.base.owner == *owner))
+}
+
+/// Apply `update` to the running totals of the `StakePoolDepositStakeAuthority`. Any other
+/// borrow of its data must be dropped first.
+fn update_deposit_stake_authority(
+    deposit_stake_authority_info: &AccountInfo,
+    update: impl FnOnce(&mut StakePoolDepositStakeAuthority),
+) -> ProgramResult {
+    let mut deposit_stake_authority_data = deposit_stake_authority_info.try_borrow_mut_data()?;
+    let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned_mut(
+        &mut deposit_stake_authority_data,
+    )?;
+    update(deposit_stake_authority);
+
+    Ok(())
+}
+
+/// Log the current parameters of a `StakePoolDepositStakeAuthority` after they changed.
+fn emit_deposit_stake_authority_updated(
+    deposit_stake_authority_address: &Pubkey,
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+) {
+    StakeDepositInterceptorEvent::DepositStakeAuthorityUpdated {
+        deposit_stake_authority: *deposit_stake_authority_address,
+        authority: deposit_stake_authority.authority,
+        fee_wallet: deposit_stake_authority.fee_wallet,
+        cool_down_seconds: deposit_stake_authority.cool_down_seconds.into(),
+        initial_fee_bps: deposit_stake_authority.inital_fee_bps.into(),
+    }
+    .emit();
+}
+
+/// Token balance of an SPL Token or Token-2022 account.
+fn token_account_amount(token_account_info: &AccountInfo) -> Result<u64, ProgramError> {
+    let token_account_data = token_account_info.try_borrow_data()?;
+    let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+    Ok(token_account.base.amount)
+}
+
 /// Check the validity of the supplied deposit_stake_authority given the relevant seeds.
 pub fn check_deposit_stake_authority_address(
     program_id: &Pubkey,
@@ -1264,6 +3736,183 @@ pub fn transfer_tokens_cpi<'a>(
     )
 }
 
+/// Validate: the fee token account must be owned by the `fee_wallet`. It is unused when the
+/// fees go to a FeeSplit.
+fn check_fee_token_account(
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+    fee_token_account_info: &AccountInfo,
+) -> ProgramResult {
+    if !deposit_stake_authority.has_fee_split() {
+        let fee_token_account_data = fee_token_account_info.try_borrow_data()?;
+        let fee_token_account = StateWithExtensions::<Account>::unpack(&fee_token_account_data)?;
+
+        if fee_token_account.base.owner != deposit_stake_authority.fee_wallet {
+            return Err(StakeDepositInterceptorError::InvalidFeeTokenAccount.into());
+        }
+    }
+    Ok(())
+}
+
+/// Pay the `fee_amount` owed on a DepositReceipt out of the Vault. The referrer's share goes
+/// to the referrer token account and the rest to the FeeSplit recipients, or the fee wallet
+/// token account when there is no FeeSplit. Returns the referrer and fee wallet amounts.
+#[allow(clippy::too_many_arguments)]
+fn pay_deposit_receipt_fee<'a>(
+    program_id: &Pubkey,
+    deposit_receipt: &DepositReceipt,
+    deposit_stake_authority_info: &AccountInfo<'a>,
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+    vault_token_account_info: &AccountInfo<'a>,
+    fee_token_account_info: &AccountInfo<'a>,
+    referrer_info: Option<&AccountInfo<'a>>,
+    fee_split_infos: &[AccountInfo<'a>],
+    pool_mint_info: &AccountInfo<'a>,
+    token_program_info: &AccountInfo<'a>,
+    fee_amount: u64,
+    decimals: u8,
+) -> Result<(u64, u64), ProgramError> {
+    let mut referrer_fee_amount = deposit_receipt.referrer_fee_amount(fee_amount);
+    if referrer_fee_amount > 0 {
+        // Validate: the referrer owed part of the fee must be supplied
+        let referrer_info = referrer_info
+            .filter(|info| info.key == &deposit_receipt.referrer)
+            .ok_or(StakeDepositInterceptorError::InvalidReferrerTokenAccount)?;
+
+        // A referrer token account that can no longer receive "pool" tokens forfeits
+        // its share to the fee_wallet rather than blocking the claim
+        let referrer_can_receive = referrer_info.owner == token_program_info.key
+            && StateWithExtensions::<Account>::unpack(&referrer_info.try_borrow_data()?).is_ok_and(
+                |referrer_token_account| {
+                    referrer_token_account.base.mint == *pool_mint_info.key
+                        && referrer_token_account.base.state == AccountState::Initialized
+                },
+            );
+        if referrer_can_receive {
+            // Transfer the referrer's share of the fee to the referrer token account
+            transfer_tokens_cpi(
+                token_program_info.clone(),
+                vault_token_account_info.clone(),
+                pool_mint_info.clone(),
+                referrer_info.clone(),
+                deposit_stake_authority_info.clone(),
+                referrer_fee_amount,
+                decimals,
+                deposit_stake_authority,
+            )?;
+        } else {
+            referrer_fee_amount = 0;
+        }
+    }
+    let fee_wallet_amount = fee_amount
+        .checked_sub(referrer_fee_amount)
+        .expect("overflow");
+
+    if deposit_stake_authority.has_fee_split() {
+        // Transfer fee tokens to each FeeSplit recipient
+        if fee_wallet_amount > 0 {
+            pay_fee_split(
+                program_id,
+                fee_split_infos,
+                token_program_info,
+                vault_token_account_info,
+                pool_mint_info,
+                deposit_stake_authority_info,
+                deposit_stake_authority,
+                fee_wallet_amount,
+                decimals,
+            )?;
+        }
+    } else {
+        // Transfer fee tokens to fee token account
+        transfer_tokens_cpi(
+            token_program_info.clone(),
+            vault_token_account_info.clone(),
+            pool_mint_info.clone(),
+            fee_token_account_info.clone(),
+            deposit_stake_authority_info.clone(),
+            fee_wallet_amount,
+            decimals,
+            deposit_stake_authority,
+        )?;
+    }
+
+    Ok((referrer_fee_amount, fee_wallet_amount))
+}
+
+/// Transfer `fee_amount` from the Vault to the recipients of the `StakePoolDepositStakeAuthority`'s
+/// FeeSplit. `fee_split_infos` holds the FeeSplit followed by the recipient token accounts, in
+/// FeeSplit order.
+#[allow(clippy::too_many_arguments)]
+fn pay_fee_split<'a>(
+    program_id: &Pubkey,
+    fee_split_infos: &[AccountInfo<'a>],
+    token_program_info: &AccountInfo<'a>,
+    vault_info: &AccountInfo<'a>,
+    pool_mint_info: &AccountInfo<'a>,
+    deposit_stake_authority_info: &AccountInfo<'a>,
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+    fee_amount: u64,
+    decimals: u8,
+) -> ProgramResult {
+    let (fee_split_info, recipient_infos) = fee_split_infos
+        .split_first()
+        .ok_or(StakeDepositInterceptorError::InvalidFeeSplitRecipient)?;
+
+    check_account_owner(fee_split_info, program_id)?;
+
+    let fee_split = {
+        let fee_split_data = fee_split_info.try_borrow_data()?;
+        *FeeSplit::try_from_slice_unchecked(&fee_split_data)?
+    };
+
+    // Validate: FeeSplit must belong to the `StakePoolDepositStakeAuthority`. It is a PDA of the
+    // `StakePoolDepositStakeAuthority`, so there is only one.
+    if fee_split.stake_pool_deposit_stake_authority != *deposit_stake_authority_info.key {
+        return Err(StakeDepositInterceptorError::InvalidFeeSplitRecipient.into());
+    }
+
+    // Validate: every recipient token account must be supplied
+    if recipient_infos.len() < fee_split.recipients().len() {
+        return Err(StakeDepositInterceptorError::InvalidFeeSplitRecipient.into());
+    }
+
+    for ((recipient, recipient_info), amount) in fee_split
+        .recipients()
+        .iter()
+        .zip(recipient_infos)
+        .zip(fee_split.split_fee_amount(fee_amount))
+    {
+        // Validate: no self transfer
+        if recipient_info.key == vault_info.key {
+            return Err(StakeDepositInterceptorError::InvalidFeeSplitRecipient.into());
+        }
+
+        // Validate: recipient token account must be owned by the recipient wallet
+        {
+            let recipient_data = recipient_info.try_borrow_data()?;
+            let recipient_token_account = StateWithExtensions::<Account>::unpack(&recipient_data)?;
+            if recipient_token_account.base.owner != recipient.wallet {
+                return Err(StakeDepositInterceptorError::InvalidFeeSplitRecipient.into());
+            }
+        }
+
+        if amount > 0 {
+            transfer_tokens_cpi(
+                token_program_info.clone(),
+                vault_info.clone(),
+                pool_mint_info.clone(),
+                recipient_info.clone(),
+                deposit_stake_authority_info.clone(),
+                amount,
+                decimals,
+                deposit_stake_authority,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Close an account and send any leftover lamports to the destination account.
 pub fn close_account<'a>(
     source: &AccountInfo<'a>,
@@ -1279,6 +3928,39 @@ pub fn close_account<'a>(
     source.resize(0)
 }
 
+/// Check whether a pool mint extension is safe for "pool" tokens held by the Vault. Transfer
+/// hooks, pausing and frozen-by-default token accounts can block claims, permanent delegates
+/// can move the Vault's tokens and confidential balances cannot be accounted for by the
+/// DepositReceipts.
+fn is_extension_supported_for_pool_mint(extension_type: &ExtensionType) -> bool {
+    !matches!(
+        extension_type,
+        ExtensionType::TransferHook
+            | ExtensionType::DefaultAccountState
+            | ExtensionType::ConfidentialTransferMint
+            | ExtensionType::ConfidentialTransferFeeConfig
+            | ExtensionType::ConfidentialMintBurn
+            | ExtensionType::PermanentDelegate
+            | ExtensionType::NonTransferable
+            | ExtensionType::Pausable
+    )
+}
+
+/// Check the pool mint does not carry any unsupported Token-2022 extension.
+fn check_pool_mint_extensions(pool_mint_info: &AccountInfo) -> Result<(), ProgramError> {
+    let pool_mint_data = pool_mint_info.try_borrow_data()?;
+    let pool_mint = StateWithExtensions::<Mint>::unpack(&pool_mint_data)?;
+    if let Some(extension_type) = pool_mint
+        .get_extension_types()?
+        .into_iter()
+        .find(|extension_type| !is_extension_supported_for_pool_mint(extension_type))
+    {
+        msg!("Pool mint extension {:?} is not supported", extension_type);
+        return Err(StakeDepositInterceptorError::UnsupportedPoolMintExtension.into());
+    }
+    Ok(())
+}
+
 fn check_manager_fee_info(
     manager_fee_account_info: &AccountInfo,
     stake_pool: &StakePool,
@@ -1301,3 +3983,370 @@ fn check_manager_fee_info(
     }
     Ok(())
 }
+
+/// Check that `whitelist_info` is the deposit Whitelist of the
+/// `StakePoolDepositStakeAuthority` and that `owner` is present in it.
+fn check_whitelisted_owner(
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+    whitelist_info: &AccountInfo,
+    owner: &Pubkey,
+) -> Result<(), ProgramError> {
+    // Validate: Whitelist must be the one set for deposits
+    deposit_stake_authority.check_deposit_whitelist(whitelist_info.key)?;
+    Whitelist::load(
+        &deposit_stake_authority.jito_whitelist_management_program_id,
+        whitelist_info,
+        false,
+    )?;
+    let whitelist_data = whitelist_info.try_borrow_data()?;
+    let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+
+    if !whitelist.whitelist.contains(owner) {
+        return Err(StakeDepositInterceptorError::OwnerNotWhitelisted.into());
+    }
+    Ok(())
+}
+
+/// Pass the whitelist discount of `deposit_receipt` on to `new_owner`. The discount is kept
+/// when `new_owner` is on the deposit Whitelist. Without a Whitelist the fee rate is reset to
+/// the `inital_fee_bps` of the `StakePoolDepositStakeAuthority`, never lowering it.
+fn transfer_whitelist_discount(
+    deposit_receipt: &mut DepositReceipt,
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+    new_owner: &Pubkey,
+    whitelist_info: Option<&AccountInfo>,
+) -> Result<(), ProgramError> {
+    if deposit_receipt.whitelist_discounted == 0 {
+        return Ok(());
+    }
+    match whitelist_info {
+        Some(whitelist_info) => {
+            check_whitelisted_owner(deposit_stake_authority, whitelist_info, new_owner)
+        }
+        None => {
+            deposit_receipt.initial_fee_bps = u32::from(deposit_receipt.initial_fee_bps)
+                .max(u32::from(deposit_stake_authority.inital_fee_bps))
+                .into();
+            deposit_receipt.whitelist_discounted = 0;
+            Ok(())
+        }
+    }
+}
+
+/// Check that `whitelisted_signer_info` is present in the jito-whitelist-management
+/// `Whitelist` of the `StakePoolDepositStakeAuthority`.
+fn check_whitelisted_signer(
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+    whitelist_info: &AccountInfo,
+    whitelisted_signer_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    Whitelist::load(
+        &deposit_stake_authority.jito_whitelist_management_program_id,
+        whitelist_info,
+        false,
+    )?;
+    let whitelist_data = whitelist_info.try_borrow_data()?;
+    let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+
+    if !whitelist.whitelist.contains(whitelisted_signer_info.key) {
+        return Err(StakeDepositInterceptorError::InvalidWhitelistedSigner.into());
+    }
+    Ok(())
+}
+
+/// Rebate up to `fee_lamports` of a whitelisted withdrawal's fee from the Hopper, keeping the
+/// Hopper rent exempt, and count it on the `StakePoolDepositStakeAuthority`. The rebate is
+/// reduced by the Hopper rebate policy of the `StakePoolDepositStakeAuthority` and, when it has
+/// an `epoch_hopper_rebate_budget`, by what remains of the whitelisted signer's RebateBudget.
+/// Any other borrow of its data must be dropped first.
+#[allow(clippy::too_many_arguments)]
+fn pay_hopper_rebate<'a>(
+    program_id: &Pubkey,
+    whitelisted_signer_info: &AccountInfo<'a>,
+    whitelist_info: &AccountInfo<'a>,
+    stake_deposit_authority_info: &AccountInfo<'a>,
+    fee_rebate_hopper_info: &AccountInfo<'a>,
+    fee_rebate_recipient_info: &AccountInfo<'a>,
+    rebate_budget_info: Option<&AccountInfo<'a>>,
+    system_program_info: &AccountInfo<'a>,
+    fee_lamports: u64,
+) -> ProgramResult {
+    if fee_lamports == 0 {
+        return Ok(());
+    }
+
+    let (policy_rebate_lamports, epoch_hopper_rebate_budget) = {
+        let deposit_stake_authority_data = stake_deposit_authority_info.try_borrow_data()?;
+        let deposit_stake_authority = StakePoolDepositStakeAuthority::try_from_slice_versioned(
+            &deposit_stake_authority_data,
+        )?;
+        (
+            deposit_stake_authority.hopper_rebate_lamports(fee_lamports),
+            u64::from(deposit_stake_authority.epoch_hopper_rebate_budget),
+        )
+    };
+
+    Hopper::load(
+        program_id,
+        fee_rebate_hopper_info,
+        whitelist_info.key,
+        stake_deposit_authority_info.key,
+        true,
+    )?;
+
+    let hopper_balance = fee_rebate_hopper_info.lamports();
+    let rent = Rent::get()?;
+    let min_balance = rent.minimum_balance(fee_rebate_hopper_info.data_len());
+    let available = hopper_balance.saturating_sub(min_balance);
+    let epoch = Clock::get()?.epoch;
+    let (remaining_budget, rebate_budget_info) = remaining_rebate_budget(
+        program_id,
+        whitelisted_signer_info,
+        stake_deposit_authority_info,
+        rebate_budget_info,
+        epoch_hopper_rebate_budget,
+        epoch,
+    )?;
+    let rebate_lamports = policy_rebate_lamports.min(available).min(remaining_budget);
+
+    // If there are no funds in the Hopper, the TX should still succeed and no 0.1% rebate will be sent ( This is an extreme edge case )
+    if rebate_lamports == 0 {
+        return Ok(());
+    }
+
+    let (_, hopper_bump, mut hopper_seeds) = Hopper::find_program_address(
+        program_id,
+        whitelist_info.key,
+        stake_deposit_authority_info.key,
+    );
+    hopper_seeds.push(vec![hopper_bump]);
+
+    invoke_signed(
+        &transfer(
+            fee_rebate_hopper_info.key,
+            fee_rebate_recipient_info.key,
+            rebate_lamports,
+        ),
+        &[
+            fee_rebate_hopper_info.clone(),
+            fee_rebate_recipient_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[hopper_seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice()],
+    )?;
+
+    StakeDepositInterceptorEvent::HopperRebatePaid {
+        hopper: *fee_rebate_hopper_info.key,
+        recipient: *fee_rebate_recipient_info.key,
+        lamports: rebate_lamports,
+    }
+    .emit();
+
+    if let Some(rebate_budget_info) = rebate_budget_info {
+        record_rebate_budget(
+            program_id,
+            whitelisted_signer_info,
+            stake_deposit_authority_info,
+            rebate_budget_info,
+            system_program_info,
+            rebate_lamports,
+            epoch,
+        )?;
+    }
+
+    update_deposit_stake_authority(stake_deposit_authority_info, |deposit_stake_authority| {
+        deposit_stake_authority.record_hopper_rebate(rebate_lamports)
+    })
+}
+
+/// Rebate up to the share of `pool_tokens_fee` that the Hopper rebate policy would cover of
+/// `fee_lamports` from the RebateVault, paying it to the pool token account the withdrawal
+/// burned from, and count it on the `StakePoolDepositStakeAuthority`. Its lamport value is
+/// counted against the whitelisted signer's RebateBudget. Any other borrow of the
+/// `StakePoolDepositStakeAuthority` data must be dropped first.
+#[allow(clippy::too_many_arguments)]
+fn pay_vault_rebate<'a>(
+    program_id: &Pubkey,
+    whitelisted_signer_info: &AccountInfo<'a>,
+    stake_deposit_authority_info: &AccountInfo<'a>,
+    rebate_vault_info: Option<&AccountInfo<'a>>,
+    user_pool_token_account_info: &AccountInfo<'a>,
+    pool_mint_info: &AccountInfo<'a>,
+    token_program_info: &AccountInfo<'a>,
+    rebate_budget_info: Option<&AccountInfo<'a>>,
+    system_program_info: &AccountInfo<'a>,
+    pool_tokens_fee: u64,
+    fee_lamports: u64,
+) -> ProgramResult {
+    if pool_tokens_fee == 0 || fee_lamports == 0 {
+        return Ok(());
+    }
+
+    let deposit_stake_authority = {
+        let deposit_stake_authority_data = stake_deposit_authority_info.try_borrow_data()?;
+        *StakePoolDepositStakeAuthority::try_from_slice_versioned(&deposit_stake_authority_data)?
+    };
+
+    let rebate_vault_info = rebate_vault_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let rebate_vault_initialized = RebateVault::load(
+        program_id,
+        rebate_vault_info,
+        stake_deposit_authority_info.key,
+        token_program_info.key,
+    )?;
+    // If the RebateVault was never funded, the TX should still succeed without a rebate
+    if !rebate_vault_initialized {
+        return Ok(());
+    }
+
+    let epoch = Clock::get()?.epoch;
+    let (remaining_budget, rebate_budget_info) = remaining_rebate_budget(
+        program_id,
+        whitelisted_signer_info,
+        stake_deposit_authority_info,
+        rebate_budget_info,
+        u64::from(deposit_stake_authority.epoch_hopper_rebate_budget),
+        epoch,
+    )?;
+    let policy_rebate_lamports = deposit_stake_authority
+        .hopper_rebate_lamports(fee_lamports)
+        .min(remaining_budget);
+
+    // Cannot exceed `pool_tokens_fee`, which fits in a u64
+    let rebate_lst = (u128::from(pool_tokens_fee) * u128::from(policy_rebate_lamports)
+        / u128::from(fee_lamports)) as u64;
+    let rebate_lst = rebate_lst.min(token_account_amount(rebate_vault_info)?);
+    if rebate_lst == 0 {
+        return Ok(());
+    }
+    // Cannot exceed `fee_lamports`, which fits in a u64
+    let rebate_lamports =
+        (u128::from(fee_lamports) * u128::from(rebate_lst) / u128::from(pool_tokens_fee)) as u64;
+
+    let decimals = {
+        let pool_mint_data = pool_mint_info.try_borrow_data()?;
+        StateWithExtensions::<Mint>::unpack(&pool_mint_data)?
+            .base
+            .decimals
+    };
+    transfer_tokens_cpi(
+        token_program_info.clone(),
+        rebate_vault_info.clone(),
+        pool_mint_info.clone(),
+        user_pool_token_account_info.clone(),
+        stake_deposit_authority_info.clone(),
+        rebate_lst,
+        decimals,
+        &deposit_stake_authority,
+    )?;
+
+    StakeDepositInterceptorEvent::RebateVaultRebatePaid {
+        deposit_stake_authority: *stake_deposit_authority_info.key,
+        recipient: *user_pool_token_account_info.key,
+        amount: rebate_lst,
+    }
+    .emit();
+
+    if let Some(rebate_budget_info) = rebate_budget_info {
+        record_rebate_budget(
+            program_id,
+            whitelisted_signer_info,
+            stake_deposit_authority_info,
+            rebate_budget_info,
+            system_program_info,
+            rebate_lamports,
+            epoch,
+        )?;
+    }
+
+    update_deposit_stake_authority(stake_deposit_authority_info, |deposit_stake_authority| {
+        deposit_stake_authority.record_vault_rebate(rebate_lst)
+    })
+}
+
+/// Rebates (in lamports) the whitelisted signer may still receive during `epoch` out of the
+/// `epoch_hopper_rebate_budget` of the StakePoolDepositStakeAuthority, along with the
+/// RebateBudget account to count them on. Unlimited, without a RebateBudget, when no budget
+/// is set.
+fn remaining_rebate_budget<'a, 'b>(
+    program_id: &Pubkey,
+    whitelisted_signer_info: &AccountInfo<'a>,
+    stake_deposit_authority_info: &AccountInfo<'a>,
+    rebate_budget_info: Option<&'b AccountInfo<'a>>,
+    epoch_hopper_rebate_budget: u64,
+    epoch: u64,
+) -> Result<(u64, Option<&'b AccountInfo<'a>>), ProgramError> {
+    if epoch_hopper_rebate_budget == 0 {
+        return Ok((u64::MAX, None));
+    }
+
+    // Validate: the whitelisted signer's RebateBudget must be supplied
+    let rebate_budget_info = rebate_budget_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let (rebate_budget_address, _bump_seed, _seeds) = RebateBudget::find_program_address(
+        program_id,
+        stake_deposit_authority_info.key,
+        whitelisted_signer_info.key,
+    );
+    if rebate_budget_address != *rebate_budget_info.key {
+        return Err(StakeDepositInterceptorError::InvalidRebateBudget.into());
+    }
+
+    let remaining_budget = if rebate_budget_info.owner == program_id {
+        let rebate_budget_data = rebate_budget_info.try_borrow_data()?;
+        let rebate_budget = RebateBudget::try_from_slice_unchecked(&rebate_budget_data)?;
+        rebate_budget.remaining_budget(epoch_hopper_rebate_budget, epoch)
+    } else {
+        epoch_hopper_rebate_budget
+    };
+
+    Ok((remaining_budget, Some(rebate_budget_info)))
+}
+
+/// Count a rebate of `lamports` during `epoch` on the whitelisted signer's RebateBudget,
+/// creating it with the whitelisted signer as payer on its first rebate.
+fn record_rebate_budget<'a>(
+    program_id: &Pubkey,
+    whitelisted_signer_info: &AccountInfo<'a>,
+    stake_deposit_authority_info: &AccountInfo<'a>,
+    rebate_budget_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    lamports: u64,
+    epoch: u64,
+) -> ProgramResult {
+    if rebate_budget_info.owner != program_id {
+        let (_, bump_seed, seeds) = RebateBudget::find_program_address(
+            program_id,
+            stake_deposit_authority_info.key,
+            whitelisted_signer_info.key,
+        );
+        let bump = [bump_seed];
+        let mut pda_seeds: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+        pda_seeds.push(&bump);
+        create_pda_account(
+            whitelisted_signer_info,
+            &Rent::get()?,
+            8 + mem::size_of::<RebateBudget>(),
+            program_id,
+            system_program_info,
+            rebate_budget_info,
+            &pda_seeds,
+        )?;
+
+        let mut rebate_budget_data = rebate_budget_info.try_borrow_mut_data()?;
+        rebate_budget_data[0] = RebateBudget::DISCRIMINATOR;
+        let rebate_budget = RebateBudget::try_from_slice_unchecked_mut(&mut rebate_budget_data)?;
+        rebate_budget.stake_pool_deposit_stake_authority = *stake_deposit_authority_info.key;
+        rebate_budget.whitelisted_signer = *whitelisted_signer_info.key;
+        rebate_budget.bump_seed = bump_seed;
+    }
+
+    let mut rebate_budget_data = rebate_budget_info.try_borrow_mut_data()?;
+    let rebate_budget = RebateBudget::try_from_slice_unchecked_mut(&mut rebate_budget_data)?;
+    rebate_budget.record_rebate(lamports, epoch);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//! Return data set by the program with `set_return_data`, so callers invoking it through CPI
+//! can learn the outcome of a deposit or claim without re-reading token balances.
+//!
+//! The return data is the Borsh-encoded struct for the instruction. Programs built with the
+//! `cpi` feature can read it right after the CPI with `DepositReturnData::get` or
+//! `ClaimReturnData::get`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program::set_return_data, pubkey::Pubkey};
+
+/// Return data of DepositStake, DepositStakeWithSlippage, DepositSol and
+/// DepositSolWithSlippage
+#[derive(Clone, Copy, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct DepositReturnData {
+    /// DepositReceipt created by the deposit
+    pub deposit_receipt: Pubkey,
+    /// "Pool" tokens minted to the Vault and recorded on the DepositReceipt
+    pub lst_amount: u64,
+}
+
+/// Return data of ClaimPoolTokens, ClaimPoolTokensPartial and RedeemReceiptToStake
+#[derive(Clone, Copy, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct ClaimReturnData {
+    /// "Pool" tokens charged as fees, including any referrer share
+    pub fee_amount: u64,
+    /// "Pool" tokens transferred to the destination token account. For RedeemReceiptToStake,
+    /// the "pool" tokens burned through the StakePool's WithdrawStakeWithSlippage for the
+    /// owner's stake account, matching `pool_tokens_redeemed` of the DepositReceiptRedeemed
+    /// event.
+    pub net_amount: u64,
+}
+
+macro_rules! impl_return_data {
+    ($return_data:ty) => {
+        impl $return_data {
+            /// Set as the return data of the current instruction
+            pub fn set(&self) {
+                set_return_data(&borsh::to_vec(self).unwrap());
+            }
+
+            /// Decode from the return data set by `program_id`. Returns `None` when the
+            /// return data was set by another program or does not decode.
+            pub fn decode(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+                if *program_id != crate::id() {
+                    return None;
+                }
+                Self::try_from_slice(data).ok()
+            }
+
+            /// Read the return data of the instruction just invoked through CPI
+            #[cfg(feature = "cpi")]
+            pub fn get() -> Option<Self> {
+                let (program_id, data) = solana_program::program::get_return_data()?;
+                Self::decode(&program_id, &data)
+            }
+        }
+    };
+}
+
+impl_return_data!(DepositReturnData);
+impl_return_data!(ClaimReturnData);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let deposit = DepositReturnData {
+            deposit_receipt: Pubkey::new_unique(),
+            lst_amount: 1_000,
+        };
+        let data = borsh::to_vec(&deposit).unwrap();
+        assert_eq!(
+            DepositReturnData::decode(&crate::id(), &data),
+            Some(deposit)
+        );
+        // Return data of other programs is ignored
+        assert_eq!(
+            DepositReturnData::decode(&Pubkey::new_unique(), &data),
+            None
+        );
+
+        let claim = ClaimReturnData {
+            fee_amount: 10,
+            net_amount: 990,
+        };
+        let data = borsh::to_vec(&claim).unwrap();
+        assert_eq!(ClaimReturnData::decode(&crate::id(), &data), Some(claim));
+        assert_eq!(
+            ClaimReturnData::decode(&crate::id(), &data[..data.len() - 1]),
+            None
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use solana_program::pubkey::Pubkey;
+use spl_pod::primitives::PodU32;
+
+use crate::{error::StakeDepositInterceptorError, state::StakeDepositInterceptorDiscriminators};
+
+use super::DepositReceipt;
+
+/// Maximum number of recipients in a `FeeSplit`
+pub const MAX_FEE_SPLIT_RECIPIENTS: usize = 8;
+
+/// A wallet paid a share of the claim-time fees
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct FeeSplitRecipient {
+    /// Owner of the "pool" token account the share is paid to
+    pub wallet: Pubkey,
+    /// Share (in bps) of the fees paid to `wallet`
+    pub fee_bps: PodU32,
+}
+
+/// Distribution of the claim-time fees across several wallets, used in place of the
+/// `fee_wallet` of the StakePoolDepositStakeAuthority it belongs to.
+#[derive(shank::ShankAccount)]
+#[repr(C)]
+#[derive(Clone, Copy, AccountDeserialize, Debug, PartialEq, Pod, Zeroable)]
+pub struct FeeSplit {
+    /// StakePoolDepositStakeAuthority the FeeSplit is associated with
+    pub stake_pool_deposit_stake_authority: Pubkey,
+    /// Number of entries of `recipients` in use
+    pub recipient_count: u8,
+    /// Bump seed for derivation
+    pub bump_seed: u8,
+    /// Recipients of the fees. Only the first `recipient_count` are in use.
+    pub recipients: [FeeSplitRecipient; MAX_FEE_SPLIT_RECIPIENTS],
+    // reserved bytes
+    reserved: [u8; 64],
+}
+
+impl Discriminator for FeeSplit {
+    const DISCRIMINATOR: u8 = StakeDepositInterceptorDiscriminators::FeeSplit as u8;
+}
+
+impl FeeSplit {
+    /// Returns the seeds for the PDA
+    pub fn seeds(deposit_stake_authority: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"fee_split".to_vec(),
+            deposit_stake_authority.to_bytes().to_vec(),
+        ]
+    }
+
+    /// Find the program address for the FeeSplit account
+    ///
+    /// # Arguments
+    /// - `program_id` - The program ID
+    /// - `deposit_stake_authority` - The stake pool deposit stake authority PDA
+    ///
+    /// # Returns
+    /// - `Pubkey` - The program address
+    /// - `u8` - The bump seed
+    /// - `Vec<Vec<u8>>` - The seeds used to generate the PDA
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        deposit_stake_authority: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(deposit_stake_authority);
+        let (address, bump) = Pubkey::find_program_address(
+            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
+            program_id,
+        );
+        (address, bump, seeds)
+    }
+
+    /// The recipients in use
+    pub fn recipients(&self) -> &[FeeSplitRecipient] {
+        &self.recipients[..usize::from(self.recipient_count).min(MAX_FEE_SPLIT_RECIPIENTS)]
+    }
+
+    /// Validate and store `recipients`: there must be between 1 and `MAX_FEE_SPLIT_RECIPIENTS`
+    /// of them, each with a non-default wallet and non-zero share, and the shares must sum to
+    /// `DepositReceipt::FEE_BPS_DENOMINATOR`.
+    pub fn set_recipients(
+        &mut self,
+        recipients: &[FeeSplitRecipient],
+    ) -> Result<(), StakeDepositInterceptorError> {
+        if recipients.is_empty() || recipients.len() > MAX_FEE_SPLIT_RECIPIENTS {
+            return Err(StakeDepositInterceptorError::InvalidFeeSplit);
+        }
+        let mut total_bps: u64 = 0;
+        for recipient in recipients {
+            let fee_bps = u32::from(recipient.fee_bps);
+            if recipient.wallet == Pubkey::default() || fee_bps == 0 {
+                return Err(StakeDepositInterceptorError::InvalidFeeSplit);
+            }
+            total_bps += u64::from(fee_bps);
+        }
+        if total_bps != u64::from(DepositReceipt::FEE_BPS_DENOMINATOR) {
+            return Err(StakeDepositInterceptorError::InvalidFeeSplit);
+        }
+
+        self.recipients = [FeeSplitRecipient::default(); MAX_FEE_SPLIT_RECIPIENTS];
+        self.recipients[..recipients.len()].copy_from_slice(recipients);
+        self.recipient_count = recipients.len() as u8;
+
+        Ok(())
+    }
+
+    /// Portion of `fee_amount` owed to each recipient, in order. Shares are rounded down and
+    /// the remainder goes to the first recipient, so the portions always sum to `fee_amount`.
+    pub fn split_fee_amount(&self, fee_amount: u64) -> Vec<u64> {
+        let mut amounts: Vec<u64> = self
+            .recipients()
+            .iter()
+            .map(|recipient| {
+                (u128::from(fee_amount) * u128::from(u32::from(recipient.fee_bps))
+                    / u128::from(DepositReceipt::FEE_BPS_DENOMINATOR)) as u64
+            })
+            .collect();
+        let remainder = fee_amount
+            .checked_sub(amounts.iter().sum())
+            .expect("overflow");
+        if let Some(first) = amounts.first_mut() {
+            *first = first.checked_add(remainder).expect("overflow");
+        }
+        amounts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recipient(fee_bps: u32) -> FeeSplitRecipient {
+        FeeSplitRecipient {
+            wallet: Pubkey::new_unique(),
+            fee_bps: PodU32::from(fee_bps),
+        }
+    }
+
+    #[test]
+    fn test_set_recipients() {
+        let mut fee_split = FeeSplit::zeroed();
+
+        assert!(fee_split
+            .set_recipients(&[recipient(5_000), recipient(3_000), recipient(2_000)])
+            .is_ok());
+        assert_eq!(fee_split.recipients().len(), 3);
+
+        // Shares must sum to 100%
+        assert!(matches!(
+            fee_split.set_recipients(&[recipient(5_000), recipient(4_999)]),
+            Err(StakeDepositInterceptorError::InvalidFeeSplit)
+        ));
+        assert!(matches!(
+            fee_split.set_recipients(&[recipient(5_000), recipient(5_001)]),
+            Err(StakeDepositInterceptorError::InvalidFeeSplit)
+        ));
+        // No empty shares or wallets
+        assert!(matches!(
+            fee_split.set_recipients(&[recipient(10_000), recipient(0)]),
+            Err(StakeDepositInterceptorError::InvalidFeeSplit)
+        ));
+        let mut default_wallet = recipient(10_000);
+        default_wallet.wallet = Pubkey::default();
+        assert!(matches!(
+            fee_split.set_recipients(&[default_wallet]),
+            Err(StakeDepositInterceptorError::InvalidFeeSplit)
+        ));
+        // Between 1 and MAX_FEE_SPLIT_RECIPIENTS recipients
+        assert!(matches!(
+            fee_split.set_recipients(&[]),
+            Err(StakeDepositInterceptorError::InvalidFeeSplit)
+        ));
+        let mut too_many = vec![recipient(1_000); MAX_FEE_SPLIT_RECIPIENTS];
+        too_many.push(recipient(2_000));
+        assert!(matches!(
+            fee_split.set_recipients(&too_many),
+            Err(StakeDepositInterceptorError::InvalidFeeSplit)
+        ));
+
+        // Failed updates leave the recipients untouched
+        assert_eq!(fee_split.recipients().len(), 3);
+    }
+
+    #[test]
+    fn test_split_fee_amount() {
+        let mut fee_split = FeeSplit::zeroed();
+        fee_split
+            .set_recipients(&[recipient(5_000), recipient(3_000), recipient(2_000)])
+            .unwrap();
+
+        assert_eq!(fee_split.split_fee_amount(1_000), vec![500, 300, 200]);
+        // Rounding dust goes to the first recipient
+        assert_eq!(fee_split.split_fee_amount(7), vec![4, 2, 1]);
+        assert_eq!(fee_split.split_fee_amount(1), vec![1, 0, 0]);
+        assert_eq!(fee_split.split_fee_amount(0), vec![0, 0, 0]);
+        assert_eq!(
+            fee_split.split_fee_amount(u64::MAX).iter().sum::<u64>(),
+            u64::MAX
+        );
+    }
+}
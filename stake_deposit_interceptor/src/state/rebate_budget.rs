@@ -0,0 +1,115 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use solana_program::pubkey::Pubkey;
+use spl_pod::primitives::PodU64;
+
+use crate::state::StakeDepositInterceptorDiscriminators;
+
+/// Hopper rebates paid to a whitelisted signer during an epoch, counted against the
+/// `epoch_hopper_rebate_budget` of the StakePoolDepositStakeAuthority it belongs to.
+#[derive(shank::ShankAccount)]
+#[repr(C)]
+#[derive(Clone, Copy, AccountDeserialize, Debug, PartialEq, Pod, Zeroable)]
+pub struct RebateBudget {
+    /// StakePoolDepositStakeAuthority the RebateBudget is associated with
+    pub stake_pool_deposit_stake_authority: Pubkey,
+    /// Whitelisted signer the rebates were paid for
+    pub whitelisted_signer: Pubkey,
+    /// Epoch that `epoch_rebate_lamports` was accumulated in
+    pub rebate_epoch: PodU64,
+    /// Hopper rebates (in lamports) paid during `rebate_epoch`
+    pub epoch_rebate_lamports: PodU64,
+    /// Bump seed for derivation
+    pub bump_seed: u8,
+    // reserved bytes
+    reserved: [u8; 64],
+}
+
+impl Discriminator for RebateBudget {
+    const DISCRIMINATOR: u8 = StakeDepositInterceptorDiscriminators::RebateBudget as u8;
+}
+
+impl RebateBudget {
+    /// Returns the seeds for the PDA
+    pub fn seeds(deposit_stake_authority: &Pubkey, whitelisted_signer: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"rebate_budget".to_vec(),
+            deposit_stake_authority.to_bytes().to_vec(),
+            whitelisted_signer.to_bytes().to_vec(),
+        ]
+    }
+
+    /// Find the program address for the RebateBudget account
+    ///
+    /// # Arguments
+    /// - `program_id` - The program ID
+    /// - `deposit_stake_authority` - The stake pool deposit stake authority PDA
+    /// - `whitelisted_signer` - The whitelisted signer the rebates are paid for
+    ///
+    /// # Returns
+    /// - `Pubkey` - The program address
+    /// - `u8` - The bump seed
+    /// - `Vec<Vec<u8>>` - The seeds used to generate the PDA
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        deposit_stake_authority: &Pubkey,
+        whitelisted_signer: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(deposit_stake_authority, whitelisted_signer);
+        let (address, bump) = Pubkey::find_program_address(
+            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
+            program_id,
+        );
+        (address, bump, seeds)
+    }
+
+    /// Hopper rebates (in lamports) paid so far during `epoch`
+    pub fn rebated_lamports_in_epoch(&self, epoch: u64) -> u64 {
+        if u64::from(self.rebate_epoch) == epoch {
+            u64::from(self.epoch_rebate_lamports)
+        } else {
+            0
+        }
+    }
+
+    /// Hopper rebates (in lamports) that may still be paid during `epoch` out of
+    /// `epoch_budget`
+    pub fn remaining_budget(&self, epoch_budget: u64, epoch: u64) -> u64 {
+        epoch_budget.saturating_sub(self.rebated_lamports_in_epoch(epoch))
+    }
+
+    /// Add a rebate of `lamports` to the running total for `epoch`, restarting the total
+    /// when the epoch has changed.
+    pub fn record_rebate(&mut self, lamports: u64, epoch: u64) {
+        self.epoch_rebate_lamports = self
+            .rebated_lamports_in_epoch(epoch)
+            .saturating_add(lamports)
+            .into();
+        self.rebate_epoch = epoch.into();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining_budget() {
+        let mut rebate_budget = RebateBudget::zeroed();
+
+        assert_eq!(rebate_budget.remaining_budget(1_000, 0), 1_000);
+        rebate_budget.record_rebate(600, 0);
+        assert_eq!(rebate_budget.rebated_lamports_in_epoch(0), 600);
+        assert_eq!(rebate_budget.remaining_budget(1_000, 0), 400);
+        rebate_budget.record_rebate(400, 0);
+        assert_eq!(rebate_budget.remaining_budget(1_000, 0), 0);
+        // A lowered budget leaves nothing rather than underflowing
+        assert_eq!(rebate_budget.remaining_budget(500, 0), 0);
+
+        // The running total restarts in a new epoch
+        assert_eq!(rebate_budget.remaining_budget(1_000, 1), 1_000);
+        rebate_budget.record_rebate(100, 1);
+        assert_eq!(u64::from(rebate_budget.rebate_epoch), 1);
+        assert_eq!(rebate_budget.remaining_budget(1_000, 1), 900);
+    }
+}
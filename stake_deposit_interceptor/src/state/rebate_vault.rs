@@ -0,0 +1,78 @@
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// "Pool" token account, owned by the StakePoolDepositStakeAuthority, used to rebate the
+/// withdrawal fee of whitelisted withdrawals in LST when the StakePoolDepositStakeAuthority
+/// is in `RebateMode::RebateVault`.
+#[derive(Debug)]
+pub struct RebateVault;
+
+impl RebateVault {
+    /// Returns the seeds for the PDA
+    pub fn seeds(deposit_stake_authority: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"rebate_vault".to_vec(),
+            deposit_stake_authority.to_bytes().to_vec(),
+        ]
+    }
+
+    /// Find the program address for the rebate vault token account
+    ///
+    /// # Arguments
+    /// - `program_id` - The program ID
+    /// - `deposit_stake_authority` - The stake pool deposit stake authority PDA
+    ///
+    /// # Returns
+    /// - `Pubkey` - The program address
+    /// - `u8` - The bump seed
+    /// - `Vec<Vec<u8>>` - The seeds used to generate the PDA
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        deposit_stake_authority: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(deposit_stake_authority);
+        let (address, bump) = Pubkey::find_program_address(
+            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
+            program_id,
+        );
+        (address, bump, seeds)
+    }
+
+    /// Attempts to load the account, returning an error if it's not at the expected PDA or is
+    /// not writable. Returns whether the token account has been created.
+    ///
+    /// # Arguments
+    /// - `program_id` - The program ID
+    /// - `account` - The rebate vault account
+    /// - `deposit_stake_authority` - The deposit stake authority PDA
+    /// - `token_program` - The token program the rebate vault is (or will be) owned by
+    ///
+    /// # Returns
+    /// - `Result<bool, ProgramError>` - Whether the rebate vault is initialized
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        deposit_stake_authority: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Result<bool, ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, deposit_stake_authority).0;
+
+        if account.key.ne(&expected_pda) {
+            msg!("Rebate vault is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account.is_writable {
+            msg!("Rebate vault is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if account.owner.eq(token_program) {
+            Ok(true)
+        } else if account.owner.eq(&solana_system_interface::program::id()) {
+            Ok(false)
+        } else {
+            msg!("Rebate vault has an invalid owner");
+            Err(ProgramError::InvalidAccountOwner)
+        }
+    }
+}
@@ -0,0 +1,217 @@
+mod helpers;
+
+use helpers::{
+    assert_transaction_err, create_stake_deposit_authority, get_account_data_deserialized,
+    program_test_context_with_stake_pool_state,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{InstructionError, Transaction};
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_accept_authority_instruction, create_cancel_authority_proposal_instruction,
+        create_propose_authority_instruction, derive_stake_pool_deposit_stake_authority,
+    },
+    state::StakePoolDepositStakeAuthority,
+};
+
+async fn setup() -> (ProgramTestContext, Pubkey, Keypair, Keypair) {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+
+    // Propose a new authority
+    let pending_authority = Keypair::new();
+    let ix = create_propose_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_stake_authority_pubkey,
+        &authority.pubkey(),
+        &pending_authority.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    (
+        ctx,
+        deposit_stake_authority_pubkey,
+        authority,
+        pending_authority,
+    )
+}
+
+#[tokio::test]
+async fn test_propose_and_accept_authority() {
+    let (mut ctx, deposit_stake_authority_pubkey, authority, pending_authority) = setup().await;
+
+    // Authority is unchanged until the pending authority accepts
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(deposit_stake_authority.authority, authority.pubkey());
+    assert_eq!(
+        deposit_stake_authority.pending_authority,
+        pending_authority.pubkey()
+    );
+
+    let ix = create_accept_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_stake_authority_pubkey,
+        &pending_authority.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &pending_authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        deposit_stake_authority.authority,
+        pending_authority.pubkey()
+    );
+    assert_eq!(deposit_stake_authority.pending_authority, Pubkey::default());
+}
+
+#[tokio::test]
+async fn test_fail_accept_invalid_pending_authority() {
+    let (mut ctx, deposit_stake_authority_pubkey, _authority, _pending_authority) = setup().await;
+    let bad_pending_authority = Keypair::new();
+    let ix = create_accept_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_stake_authority_pubkey,
+        &bad_pending_authority.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &bad_pending_authority],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidPendingAuthority as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_accept_after_cancel() {
+    let (mut ctx, deposit_stake_authority_pubkey, authority, pending_authority) = setup().await;
+    let ix = create_cancel_authority_proposal_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_stake_authority_pubkey,
+        &authority.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(deposit_stake_authority.pending_authority, Pubkey::default());
+
+    let ix = create_accept_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_stake_authority_pubkey,
+        &pending_authority.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &pending_authority],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidPendingAuthority as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_propose_invalid_authority() {
+    let (mut ctx, deposit_stake_authority_pubkey, _authority, _pending_authority) = setup().await;
+    let bad_authority = Keypair::new();
+    let ix = create_propose_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_stake_authority_pubkey,
+        &bad_authority.pubkey(),
+        &bad_authority.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &bad_authority],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidAuthority as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_cancel_invalid_authority() {
+    let (mut ctx, deposit_stake_authority_pubkey, _authority, pending_authority) = setup().await;
+    let ix = create_cancel_authority_proposal_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_stake_authority_pubkey,
+        &pending_authority.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &pending_authority],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidAuthority as u32),
+    )
+    .await;
+}
@@ -0,0 +1,409 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_deposit_authority, create_token_account,
+    get_account, get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    update_sol_deposit_authority, StakePoolAccounts,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program::{native_token::LAMPORTS_PER_SOL, program_pack::Pack};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use spl_token_interface::state::Account;
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_approve_claim_delegate_instruction, create_change_deposit_receipt_owner,
+        create_delegate_claim_pool_tokens_instruction, create_deposit_sol_instruction,
+        create_revoke_claim_delegate_instruction, derive_stake_deposit_receipt,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::{DepositReceipt, StakePoolDepositStakeAuthority},
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    depositor: Keypair,
+    delegate: Keypair,
+    deposit_receipt_pda: Pubkey,
+    destination_token_account: Pubkey,
+    fee_token_account: Pubkey,
+}
+
+/// Creates a StakePoolDepositStakeAuthority and a single DepositReceipt from DepositSol,
+/// still within its cool down.
+async fn setup() -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    let fee_wallet = Pubkey::new_unique();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        Some(&fee_wallet),
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    let fee_token_account =
+        create_token_account(&mut ctx, &fee_wallet, &stake_pool_accounts.pool_mint).await;
+
+    let depositor = Keypair::new();
+    let delegate = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    airdrop_lamports(&mut ctx, &delegate.pubkey(), LAMPORTS_PER_SOL).await;
+    let destination_token_account = create_token_account(
+        &mut ctx,
+        &depositor.pubkey(),
+        &stake_pool_accounts.pool_mint,
+    )
+    .await;
+    let deposit_receipt_base = Keypair::new();
+    let ix = create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.withdraw_authority,
+        &stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &deposit_stake_authority.vault,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &deposit_authority_base.pubkey(),
+        2 * LAMPORTS_PER_SOL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (deposit_receipt_pda, _bump) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        deposit_stake_authority,
+        depositor,
+        delegate,
+        deposit_receipt_pda,
+        destination_token_account,
+        fee_token_account,
+    }
+}
+
+async fn approve_delegate(test: &mut TestContext) {
+    let tx = Transaction::new_signed_with_payer(
+        &[create_approve_claim_delegate_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.deposit_receipt_pda,
+            &test.depositor.pubkey(),
+            &test.delegate.pubkey(),
+        )],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn delegate_claim_ix(test: &TestContext, destination_token_account: &Pubkey) -> Instruction {
+    delegate_claim_ix_with_receipt_owner(test, destination_token_account, &test.depositor.pubkey())
+}
+
+fn delegate_claim_ix_with_receipt_owner(
+    test: &TestContext,
+    destination_token_account: &Pubkey,
+    receipt_owner: &Pubkey,
+) -> Instruction {
+    create_delegate_claim_pool_tokens_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_receipt_pda,
+        &test.delegate.pubkey(),
+        receipt_owner,
+        &test.deposit_stake_authority.vault,
+        destination_token_account,
+        &test.fee_token_account,
+        &test.deposit_stake_authority_pubkey,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        None,
+        None,
+    )
+}
+
+async fn token_amount(ctx: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account(&mut ctx.banks_client, token_account).await;
+    Account::unpack(&account.data).unwrap().amount
+}
+
+#[tokio::test]
+async fn test_delegate_claims_to_owner() {
+    let mut test = setup().await;
+    approve_delegate(&mut test).await;
+
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &test.deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(deposit_receipt.owner, test.depositor.pubkey());
+    assert_eq!(deposit_receipt.delegate, test.delegate.pubkey());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delegate_claim_ix(&test, &test.destination_token_account)],
+        Some(&test.delegate.pubkey()),
+        &[&test.delegate],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // The pool tokens, net of the cool down fee, went to the owner's token account
+    let lst_amount = u64::from(deposit_receipt.lst_amount);
+    let destination_amount = token_amount(&mut test.ctx, &test.destination_token_account).await;
+    let fee_amount = token_amount(&mut test.ctx, &test.fee_token_account).await;
+    assert!(fee_amount > 0);
+    assert_eq!(destination_amount + fee_amount, lst_amount);
+    assert!(test
+        .ctx
+        .banks_client
+        .get_account(test.deposit_receipt_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_delegate_full_claim_refunds_receipt_rent_to_owner() {
+    let mut test = setup().await;
+    approve_delegate(&mut test).await;
+
+    let receipt_lamports = get_account(&mut test.ctx.banks_client, &test.deposit_receipt_pda)
+        .await
+        .lamports;
+    let owner_lamports_before = get_account(&mut test.ctx.banks_client, &test.depositor.pubkey())
+        .await
+        .lamports;
+    let delegate_lamports_before = get_account(&mut test.ctx.banks_client, &test.delegate.pubkey())
+        .await
+        .lamports;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delegate_claim_ix(&test, &test.destination_token_account)],
+        Some(&test.delegate.pubkey()),
+        &[&test.delegate],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // The DepositReceipt rent went to its owner, while the delegate only paid the fee
+    let owner_lamports_after = get_account(&mut test.ctx.banks_client, &test.depositor.pubkey())
+        .await
+        .lamports;
+    let delegate_lamports_after = get_account(&mut test.ctx.banks_client, &test.delegate.pubkey())
+        .await
+        .lamports;
+    assert_eq!(
+        owner_lamports_after,
+        owner_lamports_before + receipt_lamports
+    );
+    assert!(delegate_lamports_after < delegate_lamports_before);
+}
+
+#[tokio::test]
+async fn test_fail_delegate_claims_rent_to_itself() {
+    let mut test = setup().await;
+    approve_delegate(&mut test).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delegate_claim_ix_with_receipt_owner(
+            &test,
+            &test.destination_token_account,
+            &test.delegate.pubkey(),
+        )],
+        Some(&test.delegate.pubkey()),
+        &[&test.delegate],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidDepositReceiptOwner as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_delegate_claims_to_own_token_account() {
+    let mut test = setup().await;
+    approve_delegate(&mut test).await;
+
+    let delegate_token_account = create_token_account(
+        &mut test.ctx,
+        &test.delegate.pubkey(),
+        &test.stake_pool_accounts.pool_mint,
+    )
+    .await;
+    let tx = Transaction::new_signed_with_payer(
+        &[delegate_claim_ix(&test, &delegate_token_account)],
+        Some(&test.delegate.pubkey()),
+        &[&test.delegate],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(
+            StakeDepositInterceptorError::InvalidDestinationTokenAccount as u32,
+        ),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_claim_without_delegate() {
+    let mut test = setup().await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delegate_claim_ix(&test, &test.destination_token_account)],
+        Some(&test.delegate.pubkey()),
+        &[&test.delegate],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidDepositReceiptOwner as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_claim_after_revoke() {
+    let mut test = setup().await;
+    approve_delegate(&mut test).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_revoke_claim_delegate_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.deposit_receipt_pda,
+            &test.depositor.pubkey(),
+        )],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &test.deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(deposit_receipt.delegate, Pubkey::default());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delegate_claim_ix(&test, &test.destination_token_account)],
+        Some(&test.delegate.pubkey()),
+        &[&test.delegate],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidDepositReceiptOwner as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_change_owner_revokes_delegate() {
+    let mut test = setup().await;
+    approve_delegate(&mut test).await;
+
+    let new_owner = Pubkey::new_unique();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_change_deposit_receipt_owner(
+            &stake_deposit_interceptor_program::id(),
+            &test.deposit_receipt_pda,
+            &test.depositor.pubkey(),
+            &new_owner,
+        )],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &test.deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(deposit_receipt.owner, new_owner);
+    assert_eq!(deposit_receipt.delegate, Pubkey::default());
+}
+
+#[tokio::test]
+async fn test_fail_approve_not_owner() {
+    let mut test = setup().await;
+
+    // The delegate cannot approve itself
+    let tx = Transaction::new_signed_with_payer(
+        &[create_approve_claim_delegate_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.deposit_receipt_pda,
+            &test.delegate.pubkey(),
+            &test.delegate.pubkey(),
+        )],
+        Some(&test.delegate.pubkey()),
+        &[&test.delegate],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidDepositReceiptOwner as u32),
+    )
+    .await;
+}
@@ -26,6 +26,7 @@ use spl_token_2022_interface::state::Account;
 use stake_deposit_interceptor_program::{
     error::StakeDepositInterceptorError,
     instruction::{derive_stake_deposit_receipt, derive_stake_pool_deposit_stake_authority},
+    return_data::ClaimReturnData,
     state::{DepositReceipt, StakePoolDepositStakeAuthority},
 };
 
@@ -252,6 +253,7 @@ async fn test_success_claim_pool_tokens() {
         &stake_pool.pool_mint,
         &spl_token_interface::id(),
         false,
+        None,
     );
 
     let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
@@ -266,11 +268,29 @@ async fn test_success_claim_pool_tokens() {
         ctx.last_blockhash,
     );
 
+    let return_data = ctx
+        .banks_client
+        .simulate_transaction(tx.clone())
+        .await
+        .unwrap()
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
     ctx.banks_client.process_transaction(tx).await.unwrap();
 
     let fee_amount = deposit_receipt.calculate_fee_amount(clock_time);
     let user_amount = u64::from(deposit_receipt.lst_amount) - fee_amount;
 
+    // The fee and net amounts are set as return data
+    assert_eq!(
+        ClaimReturnData::decode(&return_data.program_id, &return_data.data),
+        Some(ClaimReturnData {
+            fee_amount,
+            net_amount: user_amount,
+        })
+    );
+
     // Destination token account should have received pool tokens
     let destination_token_account_info =
         get_account(&mut ctx.banks_client, &depositor_pool_token_account).await;
@@ -292,6 +312,259 @@ async fn test_success_claim_pool_tokens() {
     assert!(deposit_receipt_account.is_none());
 }
 
+#[tokio::test]
+async fn test_success_claim_pool_tokens_partial() {
+    let (
+        mut ctx,
+        stake_pool_accounts,
+        stake_pool,
+        _validator_stake_accounts,
+        deposit_stake_authority,
+        depositor,
+        _depositor_stake_account,
+        deposit_receipt_base,
+        deposit_authority_base,
+        _total_staked_amount,
+        depositor_pool_token_account,
+        fee_wallet,
+    ) = setup().await;
+
+    let (deposit_stake_authority_pubkey, _bump_seed) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    let (deposit_receipt_pda, _bump_seed) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    let lst_amount = u64::from(deposit_receipt.lst_amount);
+    let claim_amount = lst_amount / 4;
+
+    let fee_token_account =
+        get_associated_token_address(&fee_wallet.pubkey(), &stake_pool_accounts.pool_mint);
+
+    let create_fee_token_account_ix = create_associated_token_account(
+        &depositor.pubkey(),
+        &fee_wallet.pubkey(),
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+    );
+
+    let claim_partial_ix = |amount: u64| {
+        stake_deposit_interceptor_program::instruction::create_claim_pool_tokens_partial_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &deposit_receipt_pda,
+            &depositor.pubkey(),
+            &deposit_stake_authority.vault,
+            &depositor_pool_token_account,
+            &fee_token_account,
+            &deposit_stake_authority_pubkey,
+            &stake_pool.pool_mint,
+            &spl_token_interface::id(),
+            false,
+            None,
+            amount,
+        )
+    };
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let half_cool_down = u64::from(deposit_receipt.cool_down_seconds).saturating_div(2);
+    let clock_time = clock.unix_timestamp + half_cool_down as i64;
+    set_clock_time(&mut ctx, clock_time).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_fee_token_account_ix, claim_partial_ix(claim_amount)],
+        Some(&depositor.pubkey()),
+        &[&depositor],
+        ctx.last_blockhash,
+    );
+
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let fee_amount = deposit_receipt.calculate_partial_fee_amount(
+        claim_amount,
+        &Clock {
+            unix_timestamp: clock_time,
+            ..clock
+        },
+    );
+    assert!(fee_amount > 0);
+
+    // Destination token account should have received the claimed pool tokens less the fee
+    let destination_token_account_info =
+        get_account(&mut ctx.banks_client, &depositor_pool_token_account).await;
+    let destination_token_account =
+        Account::unpack(destination_token_account_info.data.as_slice()).unwrap();
+    assert_eq!(destination_token_account.amount, claim_amount - fee_amount);
+
+    // Fees should have been paid on the claimed portion only
+    let fee_token_account_info = get_account(&mut ctx.banks_client, &fee_token_account).await;
+    let fee_token_account_data = Account::unpack(fee_token_account_info.data.as_slice()).unwrap();
+    assert_eq!(fee_token_account_data.amount, fee_amount);
+
+    // DepositReceipt should remain open with the decremented balance
+    let updated_deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    let remaining_amount = lst_amount - claim_amount;
+    assert_eq!(
+        u64::from(updated_deposit_receipt.lst_amount),
+        remaining_amount
+    );
+    assert_eq!(
+        updated_deposit_receipt.deposit_time,
+        deposit_receipt.deposit_time
+    );
+
+    // StakePoolDepositStakeAuthority tracks the claimed portion and its fee
+    let updated_deposit_stake_authority = get_account_data_deserialized::<
+        StakePoolDepositStakeAuthority,
+    >(
+        &mut ctx.banks_client, &deposit_stake_authority_pubkey
+    )
+    .await;
+    assert_eq!(
+        u64::from(updated_deposit_stake_authority.outstanding_receipt_lst),
+        remaining_amount
+    );
+    assert_eq!(u64::from(updated_deposit_stake_authority.open_receipts), 1);
+    assert_eq!(
+        u64::from(updated_deposit_stake_authority.total_deposited_lst),
+        lst_amount
+    );
+    assert_eq!(
+        u64::from(updated_deposit_stake_authority.total_fees_paid),
+        fee_amount
+    );
+
+    // Claim the remainder after the cool down without any fee
+    let clock_time = clock.unix_timestamp + u64::from(deposit_receipt.cool_down_seconds) as i64;
+    set_clock_time(&mut ctx, clock_time).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_partial_ix(remaining_amount)],
+        Some(&depositor.pubkey()),
+        &[&depositor],
+        ctx.last_blockhash,
+    );
+
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let destination_token_account_info =
+        get_account(&mut ctx.banks_client, &depositor_pool_token_account).await;
+    let destination_token_account =
+        Account::unpack(destination_token_account_info.data.as_slice()).unwrap();
+    assert_eq!(destination_token_account.amount, lst_amount - fee_amount);
+
+    // DepositReceipt account should have been closed
+    let deposit_receipt_account = ctx
+        .banks_client
+        .get_account(deposit_receipt_pda)
+        .await
+        .unwrap();
+    assert!(deposit_receipt_account.is_none());
+
+    let updated_deposit_stake_authority = get_account_data_deserialized::<
+        StakePoolDepositStakeAuthority,
+    >(
+        &mut ctx.banks_client, &deposit_stake_authority_pubkey
+    )
+    .await;
+    assert_eq!(
+        u64::from(updated_deposit_stake_authority.outstanding_receipt_lst),
+        0
+    );
+    assert_eq!(u64::from(updated_deposit_stake_authority.open_receipts), 0);
+    assert_eq!(
+        u64::from(updated_deposit_stake_authority.total_fees_paid),
+        fee_amount
+    );
+}
+
+#[tokio::test]
+async fn test_fail_claim_pool_tokens_partial_exceeds_balance() {
+    let (
+        mut ctx,
+        stake_pool_accounts,
+        stake_pool,
+        _validator_stake_accounts,
+        deposit_stake_authority,
+        depositor,
+        _depositor_stake_account,
+        deposit_receipt_base,
+        deposit_authority_base,
+        _total_staked_amount,
+        depositor_pool_token_account,
+        fee_wallet,
+    ) = setup().await;
+
+    let (deposit_stake_authority_pubkey, _bump_seed) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    let (deposit_receipt_pda, _bump_seed) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+
+    let fee_token_account =
+        get_associated_token_address(&fee_wallet.pubkey(), &stake_pool_accounts.pool_mint);
+
+    let create_fee_token_account_ix = create_associated_token_account(
+        &depositor.pubkey(),
+        &fee_wallet.pubkey(),
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+    );
+
+    let ix =
+        stake_deposit_interceptor_program::instruction::create_claim_pool_tokens_partial_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &deposit_receipt_pda,
+            &depositor.pubkey(),
+            &deposit_stake_authority.vault,
+            &depositor_pool_token_account,
+            &fee_token_account,
+            &deposit_stake_authority_pubkey,
+            &stake_pool.pool_mint,
+            &spl_token_interface::id(),
+            false,
+            None,
+            u64::from(deposit_receipt.lst_amount) + 1,
+        );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_fee_token_account_ix, ix],
+        Some(&depositor.pubkey()),
+        &[&depositor],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidClaimAmount as u32),
+    )
+    .await;
+}
+
 async fn setup_with_ix() -> (
     ProgramTestContext,
     StakePoolAccounts,
@@ -347,6 +620,7 @@ async fn setup_with_ix() -> (
         &stake_pool.pool_mint,
         &spl_token_interface::id(),
         true,
+        None,
     );
     (
         ctx,
@@ -399,6 +673,59 @@ async fn test_success_permissionless_claim() {
     assert_eq!(destination_token_account.amount, user_amount);
 }
 
+#[tokio::test]
+async fn test_success_claim_with_baseline_accounts() {
+    let (
+        mut ctx,
+        _stake_pool_accounts,
+        depositor,
+        deposit_receipt_pda,
+        _deposit_stake_authority_pubkey,
+        mut instructions,
+    ) = setup_with_ix().await;
+    instructions[0].accounts[0] = AccountMeta::new(ctx.payer.pubkey(), true);
+    // Claims by the owner keep the original 9 accounts, without the receipt owner or referrer
+    instructions[1].accounts.truncate(9);
+    let deposit_receipt_rent = get_account(&mut ctx.banks_client, &deposit_receipt_pda)
+        .await
+        .lamports;
+    let depositor_lamports = get_account(&mut ctx.banks_client, &depositor.pubkey())
+        .await
+        .lamports;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let clock_time =
+        clock.unix_timestamp + u64::from(deposit_receipt.cool_down_seconds).add(10) as i64;
+    set_clock_time(&mut ctx, clock_time).await;
+
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // The DepositReceipt is closed and its rent refunded to the owner
+    assert!(ctx
+        .banks_client
+        .get_account(deposit_receipt_pda)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        get_account(&mut ctx.banks_client, &depositor.pubkey())
+            .await
+            .lamports,
+        depositor_lamports + deposit_receipt_rent
+    );
+}
+
 #[tokio::test]
 async fn test_fail_permissionless_claim_during_cool_down() {
     let (
@@ -0,0 +1,353 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_deposit_authority, create_token_account,
+    get_account, get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    update_sol_deposit_authority, StakePoolAccounts,
+};
+use solana_account::{Account as SolanaAccount, AccountSharedData};
+use solana_keypair::{Keypair, Signer};
+use solana_program::{clock::Clock, native_token::LAMPORTS_PER_SOL, program_pack::Pack};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use spl_token_interface::state::Account;
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_claim_pool_tokens_instruction, create_deposit_sol_instruction,
+        create_update_deposit_stake_authority_instruction, derive_stake_deposit_receipt,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::{DepositReceipt, StakePoolDepositStakeAuthority},
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    depositor: Keypair,
+    deposit_receipt_pda: Pubkey,
+    deposit_receipt: DepositReceipt,
+    destination_token_account: Pubkey,
+    fee_token_account: Pubkey,
+    referrer_token_account: Pubkey,
+}
+
+/// Creates a StakePoolDepositStakeAuthority with a `referrer_fee_share_bps` and a single
+/// DepositReceipt from DepositSol that names a referrer.
+async fn setup(referrer_fee_share_bps: u32) -> TestContext {
+    setup_with_referrer(referrer_fee_share_bps, false).await
+}
+
+/// Same as `setup`, but when `self_referred` the depositor names its own destination token
+/// account as referrer.
+async fn setup_with_referrer(referrer_fee_share_bps: u32, self_referred: bool) -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    let fee_wallet = Pubkey::new_unique();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        Some(&fee_wallet),
+    )
+    .await;
+
+    let update_ix = create_update_deposit_stake_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &authority.pubkey(),
+        &deposit_authority_base.pubkey(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(referrer_fee_share_bps),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    let fee_token_account =
+        create_token_account(&mut ctx, &fee_wallet, &stake_pool_accounts.pool_mint).await;
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    let destination_token_account = create_token_account(
+        &mut ctx,
+        &depositor.pubkey(),
+        &stake_pool_accounts.pool_mint,
+    )
+    .await;
+    let referrer_token_account = if self_referred {
+        destination_token_account
+    } else {
+        create_token_account(
+            &mut ctx,
+            &Pubkey::new_unique(),
+            &stake_pool_accounts.pool_mint,
+        )
+        .await
+    };
+    let deposit_receipt_base = Keypair::new();
+    let ix = create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.withdraw_authority,
+        &stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &deposit_stake_authority.vault,
+        &stake_pool_accounts.pool_fee_account,
+        &referrer_token_account,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &deposit_authority_base.pubkey(),
+        2 * LAMPORTS_PER_SOL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (deposit_receipt_pda, _bump) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        deposit_stake_authority,
+        depositor,
+        deposit_receipt_pda,
+        deposit_receipt,
+        destination_token_account,
+        fee_token_account,
+        referrer_token_account,
+    }
+}
+
+fn claim_ix(test: &TestContext, referrer: Option<&Pubkey>) -> Instruction {
+    create_claim_pool_tokens_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_receipt_pda,
+        &test.depositor.pubkey(),
+        &test.deposit_stake_authority.vault,
+        &test.destination_token_account,
+        &test.fee_token_account,
+        &test.deposit_stake_authority_pubkey,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        false,
+        referrer,
+    )
+}
+
+async fn token_amount(ctx: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account(&mut ctx.banks_client, token_account).await;
+    Account::unpack(&account.data).unwrap().amount
+}
+
+#[tokio::test]
+async fn test_claim_shares_fee_with_referrer() {
+    let mut test = setup(5_000).await;
+    assert_eq!(test.deposit_receipt.referrer, test.referrer_token_account);
+    assert_eq!(
+        u32::from(test.deposit_receipt.referrer_fee_share_bps),
+        5_000
+    );
+
+    // The StakePool may have paid the referrer part of its own deposit fee
+    let referrer_amount_before = token_amount(&mut test.ctx, &test.referrer_token_account).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&test, Some(&test.referrer_token_account))],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let clock: Clock = test.ctx.banks_client.get_sysvar().await.unwrap();
+    let lst_amount = u64::from(test.deposit_receipt.lst_amount);
+    let fee_amount = test
+        .deposit_receipt
+        .calculate_partial_fee_amount(lst_amount, &clock);
+    let referrer_fee_amount = test.deposit_receipt.referrer_fee_amount(fee_amount);
+    assert!(referrer_fee_amount > 0);
+
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.referrer_token_account).await - referrer_amount_before,
+        referrer_fee_amount
+    );
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.fee_token_account).await,
+        fee_amount - referrer_fee_amount
+    );
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.destination_token_account).await,
+        lst_amount - fee_amount
+    );
+
+    // Only the fee_wallet's portion counts towards `total_fees_paid`
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        u64::from(deposit_stake_authority.total_fees_paid),
+        fee_amount - referrer_fee_amount
+    );
+}
+
+#[tokio::test]
+async fn test_no_referrer_without_fee_share() {
+    let test = setup(0).await;
+    assert_eq!(test.deposit_receipt.referrer, Pubkey::default());
+    assert_eq!(u32::from(test.deposit_receipt.referrer_fee_share_bps), 0);
+}
+
+#[tokio::test]
+async fn test_self_referrer_not_recorded() {
+    let mut test = setup_with_referrer(5_000, true).await;
+    assert_eq!(test.deposit_receipt.referrer, Pubkey::default());
+    assert_eq!(u32::from(test.deposit_receipt.referrer_fee_share_bps), 0);
+
+    // The full fee goes to the fee_wallet
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&test, None)],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let clock: Clock = test.ctx.banks_client.get_sysvar().await.unwrap();
+    let fee_amount = test
+        .deposit_receipt
+        .calculate_partial_fee_amount(u64::from(test.deposit_receipt.lst_amount), &clock);
+    assert!(fee_amount > 0);
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.fee_token_account).await,
+        fee_amount
+    );
+}
+
+#[tokio::test]
+async fn test_closed_referrer_forfeits_fee_share() {
+    let mut test = setup(5_000).await;
+    // Simulate the referrer closing its token account
+    test.ctx.set_account(
+        &test.referrer_token_account,
+        &AccountSharedData::from(SolanaAccount::default()),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&test, Some(&test.referrer_token_account))],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let clock: Clock = test.ctx.banks_client.get_sysvar().await.unwrap();
+    let lst_amount = u64::from(test.deposit_receipt.lst_amount);
+    let fee_amount = test
+        .deposit_receipt
+        .calculate_partial_fee_amount(lst_amount, &clock);
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.fee_token_account).await,
+        fee_amount
+    );
+}
+
+#[tokio::test]
+async fn test_fail_referrer_not_supplied() {
+    let mut test = setup(5_000).await;
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&test, None)],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidReferrerTokenAccount as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_referrer_mismatch() {
+    let mut test = setup(5_000).await;
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&test, Some(&test.fee_token_account))],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidReferrerTokenAccount as u32),
+    )
+    .await;
+}
@@ -0,0 +1,459 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_deposit_authority, create_token_account,
+    deposit_sol, get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    update_sol_deposit_authority, StakePoolAccounts,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_close_deposit_stake_authority_instruction, create_deposit_sol_instruction,
+        create_fund_rebate_vault_instruction, create_set_fee_split_instruction,
+        create_withdraw_from_rebate_vault_instruction, derive_stake_pool_deposit_stake_authority,
+        FeeSplitRecipientArgs,
+    },
+    state::{fee_split::FeeSplit, rebate_vault::RebateVault, StakePoolDepositStakeAuthority},
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    deposit_authority_base: Keypair,
+    authority: Keypair,
+}
+
+async fn setup() -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        deposit_stake_authority,
+        deposit_authority_base,
+        authority,
+    }
+}
+
+fn close_ix(test: &TestContext, authority: &Pubkey, destination: &Pubkey) -> Instruction {
+    create_close_deposit_stake_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &test.deposit_authority_base.pubkey(),
+        authority,
+        &test.deposit_stake_authority.vault,
+        destination,
+        &spl_token_interface::id(),
+    )
+}
+
+/// Fund the RebateVault with `amount` pool tokens minted to a new token account of the payer,
+/// returning that token account.
+async fn fund_rebate_vault(test: &mut TestContext, amount: u64) -> Pubkey {
+    let payer = test.ctx.payer.insecure_clone();
+    let source = create_token_account(
+        &mut test.ctx,
+        &payer.pubkey(),
+        &test.stake_pool_accounts.pool_mint,
+    )
+    .await;
+    deposit_sol(
+        &mut test.ctx.banks_client,
+        &payer,
+        &test.stake_pool_accounts.stake_pool,
+        &test.stake_pool_accounts.pool_mint,
+        &test.stake_pool_accounts.withdraw_authority,
+        &test.stake_pool_accounts.reserve_stake_account,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.ctx.last_blockhash,
+        &source,
+        LAMPORTS_PER_SOL,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_fund_rebate_vault_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &payer.pubkey(),
+            &test.deposit_stake_authority_pubkey,
+            &source,
+            &test.stake_pool_accounts.pool_mint,
+            &spl_token_interface::id(),
+            amount,
+        )],
+        Some(&payer.pubkey()),
+        &[&payer],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+    source
+}
+
+#[tokio::test]
+async fn test_close_deposit_stake_authority() {
+    let mut test = setup().await;
+    let destination = Pubkey::new_unique();
+    let deposit_stake_authority_lamports = test
+        .ctx
+        .banks_client
+        .get_balance(test.deposit_stake_authority_pubkey)
+        .await
+        .unwrap();
+    let vault_lamports = test
+        .ctx
+        .banks_client
+        .get_balance(test.deposit_stake_authority.vault)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix(&test, &test.authority.pubkey(), &destination)],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // Both the PDA and its Vault are closed and their rent sent to the destination
+    let deposit_stake_authority_account = test
+        .ctx
+        .banks_client
+        .get_account(test.deposit_stake_authority_pubkey)
+        .await
+        .unwrap();
+    assert!(deposit_stake_authority_account.is_none());
+    let vault_account = test
+        .ctx
+        .banks_client
+        .get_account(test.deposit_stake_authority.vault)
+        .await
+        .unwrap();
+    assert!(vault_account.is_none());
+    let destination_lamports = test
+        .ctx
+        .banks_client
+        .get_balance(destination)
+        .await
+        .unwrap();
+    assert_eq!(
+        destination_lamports,
+        deposit_stake_authority_lamports + vault_lamports
+    );
+}
+
+#[tokio::test]
+async fn test_fail_invalid_authority() {
+    let mut test = setup().await;
+    let bad_authority = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix(
+            &test,
+            &bad_authority.pubkey(),
+            &bad_authority.pubkey(),
+        )],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &bad_authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidAuthority as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_deposit_stake_authority_in_use() {
+    let mut test = setup().await;
+    update_sol_deposit_authority(
+        &mut test.ctx.banks_client,
+        &test.stake_pool_accounts,
+        &test.deposit_stake_authority_pubkey,
+        &test.ctx.payer,
+        test.ctx.last_blockhash,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix(
+            &test,
+            &test.authority.pubkey(),
+            &test.authority.pubkey(),
+        )],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::DepositStakeAuthorityInUse as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_deposit_stake_authority_in_use_as_sol_withdraw_authority() {
+    let mut test = setup().await;
+    update_sol_withdraw_authority(
+        &mut test.ctx.banks_client,
+        &test.stake_pool_accounts,
+        &test.deposit_stake_authority_pubkey,
+        &test.ctx.payer,
+        test.ctx.last_blockhash,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix(
+            &test,
+            &test.authority.pubkey(),
+            &test.authority.pubkey(),
+        )],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::DepositStakeAuthorityInUse as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_vault_not_empty() {
+    let mut test = setup().await;
+    update_sol_deposit_authority(
+        &mut test.ctx.banks_client,
+        &test.stake_pool_accounts,
+        &test.deposit_stake_authority_pubkey,
+        &test.ctx.payer,
+        test.ctx.last_blockhash,
+    )
+    .await;
+
+    // Fund the Vault with a deposit
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut test.ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    let deposit_receipt_base = Keypair::new();
+    let ix = create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &test.stake_pool_accounts.withdraw_authority,
+        &test.stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &test.deposit_stake_authority.vault,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &test.deposit_authority_base.pubkey(),
+        LAMPORTS_PER_SOL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // Decommission the PDA on the StakePool while the Vault still holds pool tokens
+    update_sol_deposit_authority(
+        &mut test.ctx.banks_client,
+        &test.stake_pool_accounts,
+        &Pubkey::new_unique(),
+        &test.ctx.payer,
+        test.ctx.last_blockhash,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix(
+            &test,
+            &test.authority.pubkey(),
+            &test.authority.pubkey(),
+        )],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::VaultNotEmpty as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_rebate_vault_not_empty() {
+    let mut test = setup().await;
+    fund_rebate_vault(&mut test, LAMPORTS_PER_SOL / 10).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix(
+            &test,
+            &test.authority.pubkey(),
+            &test.authority.pubkey(),
+        )],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::RebateVaultNotEmpty as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_close_deposit_stake_authority_with_emptied_rebate_vault() {
+    let mut test = setup().await;
+    let amount = LAMPORTS_PER_SOL / 10;
+    let source = fund_rebate_vault(&mut test, amount).await;
+    let (rebate_vault, _bump, _seeds) = RebateVault::find_program_address(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_stake_authority_pubkey,
+    );
+
+    // Withdraw the RebateVault's pool tokens before closing
+    let tx = Transaction::new_signed_with_payer(
+        &[create_withdraw_from_rebate_vault_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.authority.pubkey(),
+            &test.deposit_stake_authority_pubkey,
+            &source,
+            &test.stake_pool_accounts.pool_mint,
+            &spl_token_interface::id(),
+            amount,
+        )],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let destination = Pubkey::new_unique();
+    let rebate_vault_lamports = test
+        .ctx
+        .banks_client
+        .get_balance(rebate_vault)
+        .await
+        .unwrap();
+    assert!(rebate_vault_lamports > 0);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix(&test, &test.authority.pubkey(), &destination)],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // The RebateVault is closed along with the PDA and its rent sent to the destination
+    let rebate_vault_account = test
+        .ctx
+        .banks_client
+        .get_account(rebate_vault)
+        .await
+        .unwrap();
+    assert!(rebate_vault_account.is_none());
+    let destination_lamports = test
+        .ctx
+        .banks_client
+        .get_balance(destination)
+        .await
+        .unwrap();
+    assert!(destination_lamports > rebate_vault_lamports);
+}
+
+#[tokio::test]
+async fn test_close_deposit_stake_authority_closes_fee_split() {
+    let mut test = setup().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[create_set_fee_split_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.ctx.payer.pubkey(),
+            &test.deposit_stake_authority_pubkey,
+            &test.authority.pubkey(),
+            vec![FeeSplitRecipientArgs {
+                wallet: Pubkey::new_unique(),
+                fee_bps: 10_000,
+            }],
+        )],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+    let (fee_split, _bump, _seeds) = FeeSplit::find_program_address(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_stake_authority_pubkey,
+    );
+    let fee_split_lamports = test.ctx.banks_client.get_balance(fee_split).await.unwrap();
+    assert!(fee_split_lamports > 0);
+
+    let destination = Pubkey::new_unique();
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix(&test, &test.authority.pubkey(), &destination)],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // The FeeSplit is closed along with the PDA and its rent sent to the destination
+    let fee_split_account = test.ctx.banks_client.get_account(fee_split).await.unwrap();
+    assert!(fee_split_account.is_none());
+    let destination_lamports = test
+        .ctx
+        .banks_client
+        .get_balance(destination)
+        .await
+        .unwrap();
+    assert!(destination_lamports > fee_split_lamports);
+}
@@ -0,0 +1,306 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_deposit_authority, create_token_account,
+    get_account, get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    set_clock_time, update_sol_deposit_authority, StakePoolAccounts,
+};
+use solana_clock::Clock;
+use solana_keypair::{Keypair, Signer};
+use solana_program::{native_token::LAMPORTS_PER_SOL, program_pack::Pack};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use spl_associated_token_account_interface::{
+    address::get_associated_token_address, instruction::create_associated_token_account,
+};
+use spl_token_interface::state::Account;
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_claim_pool_tokens_instruction, create_deposit_sol_instruction,
+        create_update_deposit_stake_authority_instruction, derive_stake_deposit_receipt,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::{CoolDownUnit, DepositReceipt, StakePoolDepositStakeAuthority},
+};
+
+const DEPOSIT_LAMPORTS: u64 = 2 * LAMPORTS_PER_SOL;
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    depositor: Keypair,
+    depositor_pool_token_account: Pubkey,
+    deposit_receipt_pda: Pubkey,
+}
+
+/// Initialize a StakePoolDepositStakeAuthority measuring its cool down in `cool_down_unit`
+/// and intercept a DepositSol into it.
+async fn setup(cool_down: u64, cool_down_unit: CoolDownUnit) -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+
+    let update_ix = create_update_deposit_stake_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &authority.pubkey(),
+        &deposit_authority_base.pubkey(),
+        None,
+        None,
+        Some(cool_down),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(cool_down_unit),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(deposit_stake_authority.cool_down_unit(), cool_down_unit);
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    let depositor_pool_token_account = create_token_account(
+        &mut ctx,
+        &depositor.pubkey(),
+        &stake_pool_accounts.pool_mint,
+    )
+    .await;
+
+    let deposit_receipt_base = Keypair::new();
+    let deposit_ix = create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.withdraw_authority,
+        &stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &deposit_stake_authority.vault,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &deposit_authority_base.pubkey(),
+        DEPOSIT_LAMPORTS,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (deposit_receipt_pda, _bump) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        deposit_stake_authority,
+        depositor,
+        depositor_pool_token_account,
+        deposit_receipt_pda,
+    }
+}
+
+/// Instructions creating the fee wallet's token account and claiming the DepositReceipt.
+/// The owner does not sign when `after_cool_down` is set.
+fn claim_ixs(test: &TestContext, after_cool_down: bool) -> Vec<Instruction> {
+    let fee_token_account = get_associated_token_address(
+        &test.deposit_stake_authority.fee_wallet,
+        &test.stake_pool_accounts.pool_mint,
+    );
+    vec![
+        create_associated_token_account(
+            &test.ctx.payer.pubkey(),
+            &test.deposit_stake_authority.fee_wallet,
+            &test.stake_pool_accounts.pool_mint,
+            &spl_token_interface::id(),
+        ),
+        create_claim_pool_tokens_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.deposit_receipt_pda,
+            &test.depositor.pubkey(),
+            &test.deposit_stake_authority.vault,
+            &test.depositor_pool_token_account,
+            &fee_token_account,
+            &test.deposit_stake_authority_pubkey,
+            &test.stake_pool_accounts.pool_mint,
+            &spl_token_interface::id(),
+            after_cool_down,
+            None,
+        ),
+    ]
+}
+
+async fn get_token_amount(ctx: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account(&mut ctx.banks_client, token_account).await;
+    Account::unpack(&account.data).unwrap().amount
+}
+
+#[tokio::test]
+async fn test_deposit_receipt_snapshots_cool_down_unit() {
+    let mut test = setup(2, CoolDownUnit::Epochs).await;
+
+    let clock: Clock = test.ctx.banks_client.get_sysvar().await.unwrap();
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &test.deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(deposit_receipt.cool_down_unit(), CoolDownUnit::Epochs);
+    assert_eq!(u64::from(deposit_receipt.cool_down_seconds), 2);
+    assert_eq!(u64::from(deposit_receipt.deposit_epoch), clock.epoch);
+    assert_eq!(u64::from(deposit_receipt.deposit_slot), clock.slot);
+    assert_eq!(deposit_receipt.cool_down_start(), clock.epoch);
+    assert!(deposit_receipt.is_cooling_down(&clock));
+}
+
+#[tokio::test]
+async fn test_epoch_cool_down_ignores_wall_clock() {
+    let mut test = setup(2, CoolDownUnit::Epochs).await;
+
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &test.deposit_receipt_pda,
+    )
+    .await;
+
+    // Validate: a year of wall-clock time does not end a cool down measured in epochs
+    let clock: Clock = test.ctx.banks_client.get_sysvar().await.unwrap();
+    set_clock_time(&mut test.ctx, clock.unix_timestamp + 365 * 24 * 60 * 60).await;
+    let tx = Transaction::new_signed_with_payer(
+        &claim_ixs(&test, true),
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer],
+        test.ctx.last_blockhash,
+    );
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::ActiveCooldown as u32),
+    )
+    .await;
+
+    // Permissionless claims are allowed once the cool down epochs have passed
+    let epoch_schedule = test.ctx.genesis_config().epoch_schedule.clone();
+    let cool_down_end =
+        epoch_schedule.get_first_slot_in_epoch(u64::from(deposit_receipt.deposit_epoch) + 2);
+    test.ctx.warp_to_slot(cool_down_end + 1).unwrap();
+    let last_blockhash = test.ctx.get_new_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &claim_ixs(&test, true),
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer],
+        last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let depositor_pool_token_account = test.depositor_pool_token_account;
+    assert_eq!(
+        get_token_amount(&mut test.ctx, &depositor_pool_token_account).await,
+        u64::from(deposit_receipt.lst_amount)
+    );
+}
+
+#[tokio::test]
+async fn test_slot_cool_down_fee_decay() {
+    let cool_down_slots = 1_000;
+    let mut test = setup(cool_down_slots, CoolDownUnit::Slots).await;
+
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &test.deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(deposit_receipt.cool_down_unit(), CoolDownUnit::Slots);
+
+    // Claim halfway through the cool down slots
+    let deposit_slot = u64::from(deposit_receipt.deposit_slot);
+    test.ctx
+        .warp_to_slot(deposit_slot + cool_down_slots / 2)
+        .unwrap();
+    let last_blockhash = test.ctx.get_new_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &claim_ixs(&test, false),
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.depositor],
+        last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let clock: Clock = test.ctx.banks_client.get_sysvar().await.unwrap();
+    let expected_fee = deposit_receipt.calculate_fee_amount_at(&clock);
+    let lst_amount = u64::from(deposit_receipt.lst_amount);
+    // Only part of the initial fee remains halfway through a linear cool down
+    let initial_fee = lst_amount * u64::from(u32::from(deposit_receipt.initial_fee_bps)) / 10_000;
+    assert!(expected_fee > 0 && expected_fee < initial_fee);
+
+    let fee_token_account = get_associated_token_address(
+        &test.deposit_stake_authority.fee_wallet,
+        &test.stake_pool_accounts.pool_mint,
+    );
+    let depositor_pool_token_account = test.depositor_pool_token_account;
+    assert_eq!(
+        get_token_amount(&mut test.ctx, &fee_token_account).await,
+        expected_fee
+    );
+    assert_eq!(
+        get_token_amount(&mut test.ctx, &depositor_pool_token_account).await,
+        lst_amount - expected_fee
+    );
+}
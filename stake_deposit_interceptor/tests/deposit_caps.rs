@@ -0,0 +1,369 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_account, create_stake_deposit_authority,
+    create_token_account, create_validator_and_add_to_pool, delegate_stake_account,
+    get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    stake_pool_update_all, update_sol_deposit_authority, update_stake_deposit_authority,
+    StakePoolAccounts, ValidatorStakeAccount,
+};
+use solana_clock::Clock;
+use solana_keypair::{Keypair, Signer};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_deposit_sol_instruction, create_deposit_stake_instruction,
+        create_update_deposit_stake_authority_instruction,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::StakePoolDepositStakeAuthority,
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    validator_stake_accounts: ValidatorStakeAccount,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    deposit_authority_base: Keypair,
+    depositor: Keypair,
+    depositor_stake_account: Pubkey,
+    deposit_lamports: u64,
+}
+
+async fn setup(max_deposit_lamports: u64, epoch_deposit_cap: u64) -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's stake_deposit_authority to the interceptor program's PDA
+    update_stake_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+    // Add a validator to the stake_pool
+    let validator_stake_accounts =
+        create_validator_and_add_to_pool(&mut ctx, &stake_pool_accounts).await;
+
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+
+    // Configure the deposit caps on the StakePoolDepositStakeAuthority
+    let update_ix = create_update_deposit_stake_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &authority.pubkey(),
+        &deposit_authority_base.pubkey(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(max_deposit_lamports),
+        Some(epoch_deposit_cap),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+
+    // Create "Depositor" owned stake account
+    let authorized = solana_stake_interface::state::Authorized {
+        staker: depositor.pubkey(),
+        withdrawer: depositor.pubkey(),
+    };
+    let lockup = solana_stake_interface::state::Lockup::default();
+    let stake_amount = 2 * LAMPORTS_PER_SOL;
+    let deposit_lamports = rent.minimum_balance(std::mem::size_of::<
+        solana_stake_interface::state::StakeStateV2,
+    >()) + stake_amount;
+    let depositor_stake_account = create_stake_account(
+        &mut ctx.banks_client,
+        &depositor,
+        &authorized,
+        &lockup,
+        stake_amount,
+        ctx.last_blockhash,
+    )
+    .await;
+    let _depositor_lst_account = create_token_account(
+        &mut ctx,
+        &depositor.pubkey(),
+        &stake_pool_accounts.pool_mint,
+    )
+    .await;
+    delegate_stake_account(
+        &mut ctx.banks_client,
+        &depositor,
+        &ctx.last_blockhash,
+        &depositor_stake_account,
+        &depositor,
+        &validator_stake_accounts.vote.pubkey(),
+    )
+    .await;
+
+    // Fast forward to next epoch so stake is active
+    let first_normal_slot = ctx.genesis_config().epoch_schedule.first_normal_slot;
+    ctx.warp_to_slot(first_normal_slot + 1).unwrap();
+    stake_pool_update_all(
+        &mut ctx.banks_client,
+        &ctx.payer,
+        &stake_pool_accounts,
+        &ctx.last_blockhash,
+        false,
+    )
+    .await;
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        validator_stake_accounts,
+        deposit_stake_authority_pubkey,
+        deposit_stake_authority,
+        deposit_authority_base,
+        depositor,
+        depositor_stake_account,
+        deposit_lamports,
+    }
+}
+
+fn deposit_stake_ixs(test: &TestContext, deposit_receipt_base: &Keypair) -> Vec<Instruction> {
+    create_deposit_stake_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &test.stake_pool_accounts.validator_list,
+        &test.stake_pool_accounts.withdraw_authority,
+        &test.depositor_stake_account,
+        &test.depositor.pubkey(),
+        &test.validator_stake_accounts.stake_account,
+        &test.stake_pool_accounts.reserve_stake_account,
+        &test.deposit_stake_authority.vault,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &test.deposit_authority_base.pubkey(),
+    )
+}
+
+fn deposit_sol_ix(
+    test: &TestContext,
+    deposit_receipt_base: &Keypair,
+    lamports_in: u64,
+) -> Instruction {
+    create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &test.stake_pool_accounts.withdraw_authority,
+        &test.stake_pool_accounts.reserve_stake_account,
+        &test.depositor.pubkey(),
+        &test.deposit_stake_authority.vault,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &test.deposit_authority_base.pubkey(),
+        lamports_in,
+    )
+}
+
+#[tokio::test]
+async fn test_deposit_within_caps() {
+    let mut test = setup(5 * LAMPORTS_PER_SOL, 10 * LAMPORTS_PER_SOL).await;
+
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &deposit_stake_ixs(&test, &deposit_receipt_base),
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // The deposit is counted toward the current epoch
+    let clock: Clock = test.ctx.banks_client.get_sysvar().await.unwrap();
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        u64::from(deposit_stake_authority.deposit_epoch),
+        clock.epoch
+    );
+    assert_eq!(
+        deposit_stake_authority.deposited_lamports_in_epoch(clock.epoch),
+        test.deposit_lamports
+    );
+}
+
+#[tokio::test]
+async fn test_fail_max_deposit_exceeded() {
+    let mut test = setup(LAMPORTS_PER_SOL, 0).await;
+
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &deposit_stake_ixs(&test, &deposit_receipt_base),
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::MaxDepositExceeded as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_epoch_deposit_cap_exceeded() {
+    let mut test = setup(0, LAMPORTS_PER_SOL).await;
+
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &deposit_stake_ixs(&test, &deposit_receipt_base),
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::EpochDepositCapExceeded as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_deposit_sol_epoch_deposit_cap_exceeded() {
+    let mut test = setup(0, 3 * LAMPORTS_PER_SOL).await;
+
+    // The first DepositSol fits within the epoch cap and is counted toward it
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_sol_ix(
+            &test,
+            &deposit_receipt_base,
+            2 * LAMPORTS_PER_SOL,
+        )],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let clock: Clock = test.ctx.banks_client.get_sysvar().await.unwrap();
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        deposit_stake_authority.deposited_lamports_in_epoch(clock.epoch),
+        2 * LAMPORTS_PER_SOL
+    );
+
+    // The second one would push the epoch's total past the cap
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_sol_ix(
+            &test,
+            &deposit_receipt_base,
+            2 * LAMPORTS_PER_SOL,
+        )],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::EpochDepositCapExceeded as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_deposit_sol_max_deposit_exceeded() {
+    let mut test = setup(LAMPORTS_PER_SOL, 0).await;
+
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_sol_ix(
+            &test,
+            &deposit_receipt_base,
+            2 * LAMPORTS_PER_SOL,
+        )],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::MaxDepositExceeded as u32),
+    )
+    .await;
+}
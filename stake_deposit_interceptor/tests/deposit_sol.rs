@@ -0,0 +1,364 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, clone_account_to_new_address,
+    create_stake_deposit_authority, get_account, get_account_data_deserialized,
+    program_test_context_with_stake_pool_state, update_sol_deposit_authority, StakePoolAccounts,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program::{native_token::LAMPORTS_PER_SOL, program_pack::Pack};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{AccountMeta, Instruction, InstructionError, Transaction};
+use spl_pod::{primitives::PodU64, solana_program::borsh1::try_from_slice_unchecked};
+use spl_stake_pool::error::StakePoolError;
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_deposit_sol_instruction, create_deposit_sol_with_slippage_instruction,
+        derive_stake_deposit_receipt, derive_stake_pool_deposit_stake_authority,
+    },
+    state::{DepositReceipt, StakePoolDepositStakeAuthority},
+};
+
+const DEPOSIT_LAMPORTS: u64 = 2 * LAMPORTS_PER_SOL;
+
+async fn setup() -> (
+    ProgramTestContext,
+    StakePoolAccounts,
+    spl_stake_pool::state::StakePool,
+    StakePoolDepositStakeAuthority,
+    Pubkey,
+    Keypair,
+    Keypair,
+    Keypair,
+) {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let stake_pool_account = ctx
+        .banks_client
+        .get_account(stake_pool_accounts.stake_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let stake_pool =
+        try_from_slice_unchecked::<spl_stake_pool::state::StakePool>(&stake_pool_account.data)
+            .unwrap();
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+
+    // Generate a random Pubkey as seed for DepositReceipt PDA.
+    let deposit_receipt_base = Keypair::new();
+    (
+        ctx,
+        stake_pool_accounts,
+        stake_pool,
+        deposit_stake_authority,
+        deposit_stake_authority_pubkey,
+        depositor,
+        deposit_receipt_base,
+        deposit_authority_base,
+    )
+}
+
+fn deposit_sol_ix(
+    stake_pool_accounts: &StakePoolAccounts,
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+    depositor: &Keypair,
+    deposit_receipt_base: &Keypair,
+    deposit_authority_base: &Keypair,
+) -> Instruction {
+    create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.withdraw_authority,
+        &stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &deposit_stake_authority.vault,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &deposit_authority_base.pubkey(),
+        DEPOSIT_LAMPORTS,
+    )
+}
+
+#[tokio::test]
+async fn test_deposit_sol() {
+    let (
+        mut ctx,
+        stake_pool_accounts,
+        stake_pool,
+        deposit_stake_authority,
+        deposit_stake_authority_pubkey,
+        depositor,
+        deposit_receipt_base,
+        deposit_authority_base,
+    ) = setup().await;
+
+    let ix = deposit_sol_ix(
+        &stake_pool_accounts,
+        &deposit_stake_authority,
+        &depositor,
+        &deposit_receipt_base,
+        &deposit_authority_base,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let vault_account = get_account(&mut ctx.banks_client, &deposit_stake_authority.vault).await;
+    let vault = spl_token_interface::state::Account::unpack(&vault_account.data).unwrap();
+
+    let pool_tokens_amount = spl_stake_pool::state::StakePool::calc_pool_tokens_for_deposit(
+        &stake_pool,
+        DEPOSIT_LAMPORTS,
+    )
+    .unwrap();
+
+    // assert LST was transfer to the vault
+    assert_eq!(vault.amount, pool_tokens_amount);
+
+    // Assert DepositReceipt has correct data.
+    let (deposit_receipt_pda, bump_seed) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(deposit_receipt.owner, depositor.pubkey());
+    assert_eq!(deposit_receipt.base, deposit_receipt_base.pubkey());
+    assert_eq!(deposit_receipt.stake_pool, stake_pool_accounts.stake_pool);
+    assert_eq!(
+        deposit_receipt.stake_pool_deposit_stake_authority,
+        deposit_stake_authority_pubkey
+    );
+    assert_eq!(deposit_receipt.bump_seed, bump_seed);
+    assert_eq!(deposit_receipt.lst_amount, PodU64::from(pool_tokens_amount));
+    assert_eq!(
+        deposit_receipt.cool_down_seconds,
+        deposit_stake_authority.cool_down_seconds
+    );
+    assert_eq!(
+        deposit_receipt.initial_fee_bps,
+        deposit_stake_authority.inital_fee_bps
+    );
+    let deposit_time: u64 = deposit_receipt.deposit_time.into();
+    assert!(deposit_time > 0);
+}
+
+#[tokio::test]
+async fn test_deposit_sol_with_slippage() {
+    let (
+        mut ctx,
+        stake_pool_accounts,
+        stake_pool,
+        deposit_stake_authority,
+        _deposit_stake_authority_pubkey,
+        depositor,
+        deposit_receipt_base,
+        deposit_authority_base,
+    ) = setup().await;
+
+    let pool_tokens_amount = spl_stake_pool::state::StakePool::calc_pool_tokens_for_deposit(
+        &stake_pool,
+        DEPOSIT_LAMPORTS,
+    )
+    .unwrap();
+
+    let create_ix = |minimum_pool_tokens_out: u64| {
+        create_deposit_sol_with_slippage_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &depositor.pubkey(),
+            &spl_stake_pool::id(),
+            &stake_pool_accounts.stake_pool,
+            &stake_pool_accounts.withdraw_authority,
+            &stake_pool_accounts.reserve_stake_account,
+            &depositor.pubkey(),
+            &deposit_stake_authority.vault,
+            &stake_pool_accounts.pool_fee_account,
+            &stake_pool_accounts.pool_fee_account,
+            &stake_pool_accounts.pool_mint,
+            &spl_token_interface::id(),
+            &deposit_receipt_base.pubkey(),
+            &deposit_authority_base.pubkey(),
+            DEPOSIT_LAMPORTS,
+            minimum_pool_tokens_out,
+        )
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix(pool_tokens_amount + 1)],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakePoolError::ExceededSlippage as u32),
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix(pool_tokens_amount)],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_fail_invalid_stake_pool_program() {
+    let (
+        mut ctx,
+        stake_pool_accounts,
+        _stake_pool,
+        deposit_stake_authority,
+        _deposit_stake_authority_pubkey,
+        depositor,
+        deposit_receipt_base,
+        deposit_authority_base,
+    ) = setup().await;
+
+    let mut ix = deposit_sol_ix(
+        &stake_pool_accounts,
+        &deposit_stake_authority,
+        &depositor,
+        &deposit_receipt_base,
+        &deposit_authority_base,
+    );
+    ix.accounts[1] = AccountMeta::new_readonly(Pubkey::new_unique(), false);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidStakePoolProgram as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_invalid_vault() {
+    let (
+        mut ctx,
+        stake_pool_accounts,
+        _stake_pool,
+        deposit_stake_authority,
+        _deposit_stake_authority_pubkey,
+        depositor,
+        deposit_receipt_base,
+        deposit_authority_base,
+    ) = setup().await;
+
+    let mut ix = deposit_sol_ix(
+        &stake_pool_accounts,
+        &deposit_stake_authority,
+        &depositor,
+        &deposit_receipt_base,
+        &deposit_authority_base,
+    );
+    let bad_account = clone_account_to_new_address(&mut ctx, &deposit_stake_authority.vault).await;
+    ix.accounts[9] = AccountMeta::new(bad_account, false);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidVault as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_invalid_deposit_receipt() {
+    let (
+        mut ctx,
+        stake_pool_accounts,
+        _stake_pool,
+        deposit_stake_authority,
+        _deposit_stake_authority_pubkey,
+        depositor,
+        deposit_receipt_base,
+        deposit_authority_base,
+    ) = setup().await;
+
+    let mut ix = deposit_sol_ix(
+        &stake_pool_accounts,
+        &deposit_stake_authority,
+        &depositor,
+        &deposit_receipt_base,
+        &deposit_authority_base,
+    );
+    ix.accounts[2] = AccountMeta::new(Pubkey::new_unique(), false);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidSeeds as u32),
+    )
+    .await;
+}
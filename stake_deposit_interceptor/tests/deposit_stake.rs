@@ -21,6 +21,7 @@ use spl_stake_pool::error::StakePoolError;
 use stake_deposit_interceptor_program::{
     error::StakeDepositInterceptorError,
     instruction::{derive_stake_deposit_receipt, derive_stake_pool_deposit_stake_authority},
+    return_data::DepositReturnData,
     state::{DepositReceipt, StakePoolDepositStakeAuthority},
 };
 
@@ -207,6 +208,15 @@ async fn test_deposit_stake() {
         ctx.last_blockhash,
     );
 
+    let return_data = ctx
+        .banks_client
+        .simulate_transaction(tx.clone())
+        .await
+        .unwrap()
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
     ctx.banks_client.process_transaction(tx).await.unwrap();
 
     let vault_account = get_account(&mut ctx.banks_client, &deposit_stake_authority.vault).await;
@@ -227,6 +237,14 @@ async fn test_deposit_stake() {
         &stake_pool_accounts.stake_pool,
         &deposit_receipt_base.pubkey(),
     );
+    // The DepositReceipt and minted LST are set as return data
+    assert_eq!(
+        DepositReturnData::decode(&return_data.program_id, &return_data.data),
+        Some(DepositReturnData {
+            deposit_receipt: deposit_receipt_pda,
+            lst_amount: pool_tokens_amount,
+        })
+    );
     let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
         &mut ctx.banks_client,
         &deposit_receipt_pda,
@@ -251,6 +269,23 @@ async fn test_deposit_stake() {
     );
     let deposit_time: u64 = deposit_receipt.deposit_time.into();
     assert!(deposit_time > 0);
+
+    // StakePoolDepositStakeAuthority counts the new DepositReceipt
+    let updated_deposit_stake_authority = get_account_data_deserialized::<
+        StakePoolDepositStakeAuthority,
+    >(
+        &mut ctx.banks_client, &deposit_stake_authority_pubkey
+    )
+    .await;
+    assert_eq!(
+        u64::from(updated_deposit_stake_authority.outstanding_receipt_lst),
+        pool_tokens_amount
+    );
+    assert_eq!(u64::from(updated_deposit_stake_authority.open_receipts), 1);
+    assert_eq!(
+        u64::from(updated_deposit_stake_authority.total_deposited_lst),
+        pool_tokens_amount
+    );
 }
 
 #[tokio::test]
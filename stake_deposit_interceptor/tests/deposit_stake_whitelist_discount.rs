@@ -0,0 +1,537 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_account, create_stake_deposit_authority,
+    create_token_account, create_validator_and_add_to_pool, delegate_stake_account,
+    get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    stake_pool_update_all, update_stake_deposit_authority,
+    whitelist_management_client::WhitelistManagementProgramClient, StakePoolAccounts,
+    ValidatorStakeAccount,
+};
+use solana_clock::Clock;
+use solana_keypair::{Keypair, Signer};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_change_deposit_receipt_owner, create_change_discounted_deposit_receipt_owner,
+        create_deposit_stake_whitelisted_owner_instruction,
+        create_split_deposit_receipt_instruction,
+        create_split_deposit_receipt_whitelisted_owner_instruction,
+        create_update_deposit_stake_authority_instruction, derive_stake_deposit_receipt,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::{DepositReceipt, StakePoolDepositStakeAuthority},
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    validator_stake_accounts: ValidatorStakeAccount,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    deposit_authority_base: Keypair,
+    depositor: Keypair,
+    depositor_stake_account: Pubkey,
+    whitelist_admin: Keypair,
+    whitelist: Pubkey,
+}
+
+async fn setup(whitelist_fee_discount_bps: u32) -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's stake_deposit_authority to the interceptor program's PDA
+    update_stake_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+    // Add a validator to the stake_pool
+    let validator_stake_accounts =
+        create_validator_and_add_to_pool(&mut ctx, &stake_pool_accounts).await;
+
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+
+    // Configure the whitelist program and discount on the StakePoolDepositStakeAuthority
+    let update_ix = create_update_deposit_stake_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &authority.pubkey(),
+        &deposit_authority_base.pubkey(),
+        None,
+        None,
+        None,
+        None,
+        Some(jito_whitelist_management_client::programs::JITO_WHITELIST_MANAGEMENT_ID),
+        None,
+        Some(whitelist_fee_discount_bps),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let mut whitelist_management_program_client =
+        WhitelistManagementProgramClient::new(ctx.banks_client.clone(), ctx.payer.insecure_clone());
+    let whitelist_admin = Keypair::new();
+    airdrop_lamports(&mut ctx, &whitelist_admin.pubkey(), LAMPORTS_PER_SOL).await;
+    whitelist_management_program_client
+        .do_initialize_whitelist(whitelist_admin.pubkey())
+        .await;
+    let whitelist = whitelist_management_program_client.get_whitelist_pda();
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+
+    // Create "Depositor" owned stake account
+    let authorized = solana_stake_interface::state::Authorized {
+        staker: depositor.pubkey(),
+        withdrawer: depositor.pubkey(),
+    };
+    let lockup = solana_stake_interface::state::Lockup::default();
+    let depositor_stake_account = create_stake_account(
+        &mut ctx.banks_client,
+        &depositor,
+        &authorized,
+        &lockup,
+        2 * LAMPORTS_PER_SOL,
+        ctx.last_blockhash,
+    )
+    .await;
+    let _depositor_lst_account = create_token_account(
+        &mut ctx,
+        &depositor.pubkey(),
+        &stake_pool_accounts.pool_mint,
+    )
+    .await;
+    delegate_stake_account(
+        &mut ctx.banks_client,
+        &depositor,
+        &ctx.last_blockhash,
+        &depositor_stake_account,
+        &depositor,
+        &validator_stake_accounts.vote.pubkey(),
+    )
+    .await;
+
+    // Fast forward to next epoch so stake is active
+    let first_normal_slot = ctx.genesis_config().epoch_schedule.first_normal_slot;
+    ctx.warp_to_slot(first_normal_slot + 1).unwrap();
+    stake_pool_update_all(
+        &mut ctx.banks_client,
+        &ctx.payer,
+        &stake_pool_accounts,
+        &ctx.last_blockhash,
+        false,
+    )
+    .await;
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        validator_stake_accounts,
+        deposit_stake_authority,
+        deposit_authority_base,
+        depositor,
+        depositor_stake_account,
+        whitelist_admin,
+        whitelist,
+    }
+}
+
+async fn add_to_whitelist(test: &mut TestContext, signer: Pubkey) {
+    let mut whitelist_management_program_client = WhitelistManagementProgramClient::new(
+        test.ctx.banks_client.clone(),
+        test.ctx.payer.insecure_clone(),
+    );
+    whitelist_management_program_client
+        .do_add_to_whitelist(&test.whitelist_admin, signer)
+        .await;
+}
+
+fn deposit_stake_ixs(test: &TestContext, deposit_receipt_base: &Keypair) -> Vec<Instruction> {
+    create_deposit_stake_whitelisted_owner_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &test.stake_pool_accounts.validator_list,
+        &test.stake_pool_accounts.withdraw_authority,
+        &test.depositor_stake_account,
+        &test.depositor.pubkey(),
+        &test.validator_stake_accounts.stake_account,
+        &test.stake_pool_accounts.reserve_stake_account,
+        &test.deposit_stake_authority.vault,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &test.deposit_authority_base.pubkey(),
+        &test.whitelist,
+    )
+}
+
+async fn deposit_stake_receipt(test: &mut TestContext) -> (Pubkey, DepositReceipt) {
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &deposit_stake_ixs(test, &deposit_receipt_base),
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (deposit_receipt_pda, _bump_seed) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    (deposit_receipt_pda, deposit_receipt)
+}
+
+async fn process_depositor_ixs(test: &mut TestContext, ixs: &[Instruction], signers: &[&Keypair]) {
+    let mut all_signers = vec![&test.depositor];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&test.depositor.pubkey()),
+        &all_signers,
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn split_ix(
+    test: &TestContext,
+    deposit_receipt_pda: &Pubkey,
+    deposit_receipt: &DepositReceipt,
+    new_base: &Keypair,
+    new_owner: &Pubkey,
+    whitelist: Option<&Pubkey>,
+) -> Instruction {
+    let amount = u64::from(deposit_receipt.lst_amount) / 2;
+    match whitelist {
+        Some(whitelist) => create_split_deposit_receipt_whitelisted_owner_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.depositor.pubkey(),
+            deposit_receipt_pda,
+            &test.depositor.pubkey(),
+            &test.stake_pool_accounts.stake_pool,
+            &deposit_receipt.stake_pool_deposit_stake_authority,
+            &new_base.pubkey(),
+            new_owner,
+            whitelist,
+            amount,
+        ),
+        None => create_split_deposit_receipt_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.depositor.pubkey(),
+            deposit_receipt_pda,
+            &test.depositor.pubkey(),
+            &test.stake_pool_accounts.stake_pool,
+            &deposit_receipt.stake_pool_deposit_stake_authority,
+            &new_base.pubkey(),
+            new_owner,
+            amount,
+        ),
+    }
+}
+
+async fn split_deposit_receipt(
+    test: &mut TestContext,
+    deposit_receipt_pda: &Pubkey,
+    deposit_receipt: &DepositReceipt,
+    new_owner: &Pubkey,
+    whitelist: Option<&Pubkey>,
+) -> DepositReceipt {
+    let new_base = Keypair::new();
+    let ix = split_ix(
+        test,
+        deposit_receipt_pda,
+        deposit_receipt,
+        &new_base,
+        new_owner,
+        whitelist,
+    );
+    process_depositor_ixs(test, &[ix], &[&new_base]).await;
+
+    let (new_deposit_receipt_pda, _bump_seed) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &new_base.pubkey(),
+    );
+    get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &new_deposit_receipt_pda,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_deposit_stake_whitelisted_owner_fee_free() {
+    let mut test = setup(10_000).await;
+    let depositor = test.depositor.pubkey();
+    add_to_whitelist(&mut test, depositor).await;
+
+    let (_, deposit_receipt) = deposit_stake_receipt(&mut test).await;
+    assert_eq!(deposit_receipt.owner, depositor);
+    assert_eq!(u32::from(deposit_receipt.initial_fee_bps), 0);
+    assert_eq!(deposit_receipt.whitelist_discounted, 1);
+    let clock: Clock = test.ctx.banks_client.get_sysvar().await.unwrap();
+    assert_eq!(deposit_receipt.calculate_fee_amount_at(&clock), 0);
+}
+
+#[tokio::test]
+async fn test_deposit_stake_whitelisted_owner_discount() {
+    let mut test = setup(5_000).await;
+    let depositor = test.depositor.pubkey();
+    add_to_whitelist(&mut test, depositor).await;
+
+    let (_, deposit_receipt) = deposit_stake_receipt(&mut test).await;
+    assert_eq!(
+        u32::from(deposit_receipt.initial_fee_bps),
+        u32::from(test.deposit_stake_authority.inital_fee_bps) / 2
+    );
+}
+
+#[tokio::test]
+async fn test_change_owner_resets_discount() {
+    let mut test = setup(10_000).await;
+    let depositor = test.depositor.pubkey();
+    add_to_whitelist(&mut test, depositor).await;
+    let (deposit_receipt_pda, deposit_receipt) = deposit_stake_receipt(&mut test).await;
+
+    let new_owner = Pubkey::new_unique();
+    let ix = create_change_discounted_deposit_receipt_owner(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_receipt_pda,
+        &depositor,
+        &new_owner,
+        &deposit_receipt.stake_pool_deposit_stake_authority,
+        None,
+    );
+    process_depositor_ixs(&mut test, &[ix], &[]).await;
+
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(deposit_receipt.owner, new_owner);
+    assert_eq!(
+        deposit_receipt.initial_fee_bps,
+        test.deposit_stake_authority.inital_fee_bps
+    );
+    assert_eq!(deposit_receipt.whitelist_discounted, 0);
+}
+
+#[tokio::test]
+async fn test_change_owner_keeps_discount_for_whitelisted_owner() {
+    let mut test = setup(10_000).await;
+    let depositor = test.depositor.pubkey();
+    let new_owner = Pubkey::new_unique();
+    add_to_whitelist(&mut test, depositor).await;
+    add_to_whitelist(&mut test, new_owner).await;
+    let (deposit_receipt_pda, deposit_receipt) = deposit_stake_receipt(&mut test).await;
+
+    let whitelist = test.whitelist;
+    let ix = create_change_discounted_deposit_receipt_owner(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_receipt_pda,
+        &depositor,
+        &new_owner,
+        &deposit_receipt.stake_pool_deposit_stake_authority,
+        Some(&whitelist),
+    );
+    process_depositor_ixs(&mut test, &[ix], &[]).await;
+
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(deposit_receipt.owner, new_owner);
+    assert_eq!(u32::from(deposit_receipt.initial_fee_bps), 0);
+    assert_eq!(deposit_receipt.whitelist_discounted, 1);
+}
+
+#[tokio::test]
+async fn test_fail_change_owner_new_owner_not_whitelisted() {
+    let mut test = setup(10_000).await;
+    let depositor = test.depositor.pubkey();
+    add_to_whitelist(&mut test, depositor).await;
+    let (deposit_receipt_pda, deposit_receipt) = deposit_stake_receipt(&mut test).await;
+
+    let ix = create_change_discounted_deposit_receipt_owner(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_receipt_pda,
+        &depositor,
+        &Pubkey::new_unique(),
+        &deposit_receipt.stake_pool_deposit_stake_authority,
+        Some(&test.whitelist),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::OwnerNotWhitelisted as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_change_owner_missing_deposit_stake_authority() {
+    let mut test = setup(10_000).await;
+    let depositor = test.depositor.pubkey();
+    add_to_whitelist(&mut test, depositor).await;
+    let (deposit_receipt_pda, _) = deposit_stake_receipt(&mut test).await;
+
+    let ix = create_change_deposit_receipt_owner(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_receipt_pda,
+        &depositor,
+        &Pubkey::new_unique(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(
+            StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority as u32,
+        ),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_split_resets_discount() {
+    let mut test = setup(10_000).await;
+    let depositor = test.depositor.pubkey();
+    add_to_whitelist(&mut test, depositor).await;
+    let (deposit_receipt_pda, deposit_receipt) = deposit_stake_receipt(&mut test).await;
+
+    let new_owner = Pubkey::new_unique();
+    let new_deposit_receipt = split_deposit_receipt(
+        &mut test,
+        &deposit_receipt_pda,
+        &deposit_receipt,
+        &new_owner,
+        None,
+    )
+    .await;
+    assert_eq!(new_deposit_receipt.owner, new_owner);
+    assert_eq!(
+        new_deposit_receipt.initial_fee_bps,
+        test.deposit_stake_authority.inital_fee_bps
+    );
+    assert_eq!(new_deposit_receipt.whitelist_discounted, 0);
+
+    // The original owner keeps the discount on the remaining balance
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(u32::from(deposit_receipt.initial_fee_bps), 0);
+    assert_eq!(deposit_receipt.whitelist_discounted, 1);
+}
+
+#[tokio::test]
+async fn test_split_keeps_discount_for_whitelisted_owner() {
+    let mut test = setup(10_000).await;
+    let depositor = test.depositor.pubkey();
+    let new_owner = Pubkey::new_unique();
+    add_to_whitelist(&mut test, depositor).await;
+    add_to_whitelist(&mut test, new_owner).await;
+    let (deposit_receipt_pda, deposit_receipt) = deposit_stake_receipt(&mut test).await;
+
+    let whitelist = test.whitelist;
+    let new_deposit_receipt = split_deposit_receipt(
+        &mut test,
+        &deposit_receipt_pda,
+        &deposit_receipt,
+        &new_owner,
+        Some(&whitelist),
+    )
+    .await;
+    assert_eq!(new_deposit_receipt.owner, new_owner);
+    assert_eq!(u32::from(new_deposit_receipt.initial_fee_bps), 0);
+    assert_eq!(new_deposit_receipt.whitelist_discounted, 1);
+}
+
+#[tokio::test]
+async fn test_fail_owner_not_whitelisted() {
+    let mut test = setup(10_000).await;
+    add_to_whitelist(&mut test, Pubkey::new_unique()).await;
+
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &deposit_stake_ixs(&test, &deposit_receipt_base),
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::OwnerNotWhitelisted as u32),
+    )
+    .await;
+}
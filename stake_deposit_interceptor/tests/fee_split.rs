@@ -0,0 +1,392 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_deposit_authority, create_token_account,
+    get_account, get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    update_sol_deposit_authority, StakePoolAccounts,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program::{clock::Clock, native_token::LAMPORTS_PER_SOL, program_pack::Pack};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use spl_token_interface::state::Account;
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        append_fee_split_accounts, create_claim_pool_tokens_instruction,
+        create_deposit_sol_instruction, create_set_fee_split_instruction,
+        derive_stake_deposit_receipt, derive_stake_pool_deposit_stake_authority,
+        FeeSplitRecipientArgs,
+    },
+    state::{fee_split::FeeSplit, DepositReceipt, StakePoolDepositStakeAuthority},
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    authority: Keypair,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    depositor: Keypair,
+    deposit_receipt_pda: Pubkey,
+    deposit_receipt: DepositReceipt,
+    destination_token_account: Pubkey,
+    fee_token_account: Pubkey,
+}
+
+/// Creates a StakePoolDepositStakeAuthority and a single DepositReceipt from DepositSol.
+async fn setup() -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    let fee_wallet = Pubkey::new_unique();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        Some(&fee_wallet),
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    let fee_token_account =
+        create_token_account(&mut ctx, &fee_wallet, &stake_pool_accounts.pool_mint).await;
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    let destination_token_account = create_token_account(
+        &mut ctx,
+        &depositor.pubkey(),
+        &stake_pool_accounts.pool_mint,
+    )
+    .await;
+    let deposit_receipt_base = Keypair::new();
+    let ix = create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.withdraw_authority,
+        &stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &deposit_stake_authority.vault,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &deposit_authority_base.pubkey(),
+        2 * LAMPORTS_PER_SOL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (deposit_receipt_pda, _bump) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        authority,
+        deposit_stake_authority_pubkey,
+        deposit_stake_authority,
+        depositor,
+        deposit_receipt_pda,
+        deposit_receipt,
+        destination_token_account,
+        fee_token_account,
+    }
+}
+
+fn set_fee_split_tx(test: &TestContext, recipients: Vec<FeeSplitRecipientArgs>) -> Transaction {
+    let ix = create_set_fee_split_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.ctx.payer.pubkey(),
+        &test.deposit_stake_authority_pubkey,
+        &test.authority.pubkey(),
+        recipients,
+    );
+    Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    )
+}
+
+/// Sets a FeeSplit with one recipient per entry of `fee_bps`, returning the recipients'
+/// token accounts.
+async fn set_fee_split(test: &mut TestContext, fee_bps: &[u32]) -> Vec<Pubkey> {
+    let mut recipients = Vec::new();
+    let mut token_accounts = Vec::new();
+    for fee_bps in fee_bps {
+        let wallet = Pubkey::new_unique();
+        token_accounts.push(
+            create_token_account(&mut test.ctx, &wallet, &test.stake_pool_accounts.pool_mint).await,
+        );
+        recipients.push(FeeSplitRecipientArgs {
+            wallet,
+            fee_bps: *fee_bps,
+        });
+    }
+    let tx = set_fee_split_tx(test, recipients);
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+    token_accounts
+}
+
+fn claim_ix(test: &TestContext, recipient_token_accounts: &[Pubkey]) -> Instruction {
+    let mut ix = create_claim_pool_tokens_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_receipt_pda,
+        &test.depositor.pubkey(),
+        &test.deposit_stake_authority.vault,
+        &test.destination_token_account,
+        &test.fee_token_account,
+        &test.deposit_stake_authority_pubkey,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        false,
+        None,
+    );
+    append_fee_split_accounts(
+        &stake_deposit_interceptor_program::id(),
+        &mut ix,
+        &test.deposit_stake_authority_pubkey,
+        recipient_token_accounts,
+    );
+    ix
+}
+
+async fn token_amount(ctx: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account(&mut ctx.banks_client, token_account).await;
+    Account::unpack(&account.data).unwrap().amount
+}
+
+#[tokio::test]
+async fn test_set_fee_split() {
+    let mut test = setup().await;
+    set_fee_split(&mut test, &[6_000, 4_000]).await;
+
+    let (fee_split_pubkey, bump_seed, _seeds) = FeeSplit::find_program_address(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_stake_authority_pubkey,
+    );
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert!(deposit_stake_authority.has_fee_split());
+
+    let fee_split =
+        get_account_data_deserialized::<FeeSplit>(&mut test.ctx.banks_client, &fee_split_pubkey)
+            .await;
+    assert_eq!(
+        fee_split.stake_pool_deposit_stake_authority,
+        test.deposit_stake_authority_pubkey
+    );
+    assert_eq!(fee_split.bump_seed, bump_seed);
+    let fee_bps: Vec<u32> = fee_split
+        .recipients()
+        .iter()
+        .map(|recipient| recipient.fee_bps.into())
+        .collect();
+    assert_eq!(fee_bps, vec![6_000, 4_000]);
+
+    // Replacing the recipients reuses the account
+    set_fee_split(&mut test, &[10_000]).await;
+    let fee_split =
+        get_account_data_deserialized::<FeeSplit>(&mut test.ctx.banks_client, &fee_split_pubkey)
+            .await;
+    assert_eq!(fee_split.recipients().len(), 1);
+
+    // No recipients removes the FeeSplit
+    let tx = set_fee_split_tx(&test, vec![]);
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert!(!deposit_stake_authority.has_fee_split());
+    assert!(test
+        .ctx
+        .banks_client
+        .get_account(fee_split_pubkey)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_claim_pays_fee_split() {
+    let mut test = setup().await;
+    let recipient_token_accounts = set_fee_split(&mut test, &[5_000, 3_000, 2_000]).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&test, &recipient_token_accounts)],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let clock: Clock = test.ctx.banks_client.get_sysvar().await.unwrap();
+    let lst_amount = u64::from(test.deposit_receipt.lst_amount);
+    let fee_amount = test
+        .deposit_receipt
+        .calculate_partial_fee_amount(lst_amount, &clock);
+    assert!(fee_amount > 0);
+
+    let (fee_split_pubkey, _bump_seed, _seeds) = FeeSplit::find_program_address(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_stake_authority_pubkey,
+    );
+    let fee_split =
+        get_account_data_deserialized::<FeeSplit>(&mut test.ctx.banks_client, &fee_split_pubkey)
+            .await;
+    for (token_account, amount) in recipient_token_accounts
+        .iter()
+        .zip(fee_split.split_fee_amount(fee_amount))
+    {
+        assert_eq!(token_amount(&mut test.ctx, token_account).await, amount);
+    }
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.fee_token_account).await,
+        0
+    );
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.destination_token_account).await,
+        lst_amount - fee_amount
+    );
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        u64::from(deposit_stake_authority.total_fees_paid),
+        fee_amount
+    );
+}
+
+#[tokio::test]
+async fn test_fail_fee_split_weights_not_full() {
+    let mut test = setup().await;
+    let tx = set_fee_split_tx(
+        &test,
+        vec![
+            FeeSplitRecipientArgs {
+                wallet: Pubkey::new_unique(),
+                fee_bps: 5_000,
+            },
+            FeeSplitRecipientArgs {
+                wallet: Pubkey::new_unique(),
+                fee_bps: 4_999,
+            },
+        ],
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidFeeSplit as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_set_fee_split_invalid_authority() {
+    let mut test = setup().await;
+    test.authority = Keypair::new();
+    let tx = set_fee_split_tx(
+        &test,
+        vec![FeeSplitRecipientArgs {
+            wallet: Pubkey::new_unique(),
+            fee_bps: 10_000,
+        }],
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidAuthority as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_claim_fee_split_recipient_mismatch() {
+    let mut test = setup().await;
+    let mut recipient_token_accounts = set_fee_split(&mut test, &[5_000, 5_000]).await;
+    recipient_token_accounts.reverse();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&test, &recipient_token_accounts)],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidFeeSplitRecipient as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_claim_fee_split_recipients_missing() {
+    let mut test = setup().await;
+    let recipient_token_accounts = set_fee_split(&mut test, &[5_000, 5_000]).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&test, &recipient_token_accounts[..1])],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidFeeSplitRecipient as u32),
+    )
+    .await;
+}
@@ -3,8 +3,9 @@ use solana_account::AccountSharedData;
 use solana_program::{instruction::InstructionError, pubkey::Pubkey};
 use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
 use solana_transaction::{Transaction, TransactionError};
+use stake_deposit_interceptor_program::state::ACCOUNT_VERSION_OFFSET;
 
-use super::{create_stake_pool, StakePoolAccounts};
+use super::{create_stake_pool, create_stake_pool_with_token_program, StakePoolAccounts};
 
 pub fn program_test_with_stake_pool_program() -> ProgramTest {
     let mut program_test = ProgramTest::default();
@@ -33,6 +34,18 @@ pub async fn program_test_context_with_stake_pool_state() -> (ProgramTestContext
     (ctx, stake_pool_accounts)
 }
 
+/// Same as `program_test_context_with_stake_pool_state`, with a Token-2022 pool mint.
+#[allow(dead_code)]
+pub async fn program_test_context_with_token_2022_stake_pool_state(
+) -> (ProgramTestContext, StakePoolAccounts) {
+    let mut ctx = program_test_with_stake_pool_program()
+        .start_with_context()
+        .await;
+    let stake_pool_accounts =
+        create_stake_pool_with_token_program(&mut ctx, &spl_token_2022_interface::id()).await;
+    (ctx, stake_pool_accounts)
+}
+
 /// Clones all the existing account information and data to a new account. Returns the
 /// new address of the account.
 #[allow(dead_code)]
@@ -54,6 +67,20 @@ pub async fn clone_account_to_new_address(
     new_address
 }
 
+/// Overwrite the layout version of a program account, simulating an account written by an
+/// older version of the program.
+#[allow(dead_code)]
+pub async fn set_account_version(ctx: &mut ProgramTestContext, address: &Pubkey, version: u8) {
+    let mut account = ctx
+        .banks_client
+        .get_account(*address)
+        .await
+        .unwrap()
+        .unwrap();
+    account.data[ACCOUNT_VERSION_OFFSET] = version;
+    ctx.set_account(address, &AccountSharedData::from(account));
+}
+
 /// Given a transaction and an error, assert the exact error is thrown during transaction
 /// execution in the ProgramTestContext.
 #[allow(dead_code)]
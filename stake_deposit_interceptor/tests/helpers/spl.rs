@@ -3,23 +3,51 @@ use solana_program::program_pack::Pack;
 use solana_program_test::ProgramTestContext;
 use solana_pubkey::Pubkey;
 use solana_system_interface::instruction::create_account;
-use solana_transaction::Transaction;
-use spl_token_interface::state::{Account, Mint};
+use solana_transaction::{Instruction, Transaction};
+use spl_token_2022_interface::{
+    extension::ExtensionType,
+    state::{Account, Mint},
+};
 
 /// Create a SPL Token mint account and return the Pubkey.
 /// ProgramTestContext `payer`` is the Mint's `mint_authority`.`
+#[allow(dead_code)]
 pub async fn create_mint(ctx: &mut ProgramTestContext) -> Pubkey {
+    create_mint_with_token_program(ctx, &spl_token_interface::id(), &[]).await
+}
+
+/// Create a mint account owned by `token_program_id`, sized for `extension_types`, and return
+/// the Pubkey.
+/// ProgramTestContext `payer`` is the Mint's `mint_authority`.`
+pub async fn create_mint_with_token_program(
+    ctx: &mut ProgramTestContext,
+    token_program_id: &Pubkey,
+    extension_types: &[ExtensionType],
+) -> Pubkey {
+    create_mint_with_extensions(ctx, token_program_id, extension_types, |_| vec![]).await
+}
+
+/// Create a Token-2022 mint account initializing `extension_types` with the instructions
+/// returned by `extension_ixs` for the new mint address, and return the Pubkey.
+/// ProgramTestContext `payer`` is the Mint's `mint_authority`.`
+pub async fn create_mint_with_extensions(
+    ctx: &mut ProgramTestContext,
+    token_program_id: &Pubkey,
+    extension_types: &[ExtensionType],
+    extension_ixs: impl FnOnce(&Pubkey) -> Vec<Instruction>,
+) -> Pubkey {
     let keypair = Keypair::new();
     let rent = ctx.banks_client.get_rent().await.unwrap();
+    let space = ExtensionType::try_calculate_account_len::<Mint>(extension_types).unwrap();
     let init_account_ix = create_account(
         &ctx.payer.pubkey(),
         &keypair.pubkey(),
-        rent.minimum_balance(Mint::LEN),
-        Mint::LEN as u64,
-        &spl_token_interface::id(),
+        rent.minimum_balance(space),
+        space as u64,
+        token_program_id,
     );
-    let init_mint_ix = spl_token_interface::instruction::initialize_mint(
-        &spl_token_interface::id(),
+    let init_mint_ix = spl_token_2022_interface::instruction::initialize_mint(
+        token_program_id,
         &keypair.pubkey(),
         &ctx.payer.pubkey(),
         None,
@@ -27,8 +55,11 @@ pub async fn create_mint(ctx: &mut ProgramTestContext) -> Pubkey {
     )
     .unwrap();
 
+    let mut instructions = vec![init_account_ix];
+    instructions.extend(extension_ixs(&keypair.pubkey()));
+    instructions.push(init_mint_ix);
     let tx = Transaction::new_signed_with_payer(
-        &[init_account_ix, init_mint_ix],
+        &instructions,
         Some(&ctx.payer.pubkey()),
         &[&ctx.payer, &keypair],
         ctx.last_blockhash,
@@ -40,10 +71,21 @@ pub async fn create_mint(ctx: &mut ProgramTestContext) -> Pubkey {
 }
 
 /// Create a SPL Token Account owned by the ProgramTestContext `payer`
+#[allow(dead_code)]
 pub async fn create_token_account(
     ctx: &mut ProgramTestContext,
     owner: &Pubkey,
     mint: &Pubkey,
+) -> Pubkey {
+    create_token_account_with_token_program(ctx, owner, mint, &spl_token_interface::id()).await
+}
+
+/// Create a token account owned by `token_program_id` for a mint without extensions
+pub async fn create_token_account_with_token_program(
+    ctx: &mut ProgramTestContext,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program_id: &Pubkey,
 ) -> Pubkey {
     let keypair = Keypair::new();
     let rent = ctx.banks_client.get_rent().await.unwrap();
@@ -53,10 +95,10 @@ pub async fn create_token_account(
         &keypair.pubkey(),
         rent.minimum_balance(Account::LEN),
         Account::LEN as u64,
-        &spl_token_interface::id(),
+        token_program_id,
     );
-    let init_token_account_ix = spl_token_interface::instruction::initialize_account3(
-        &spl_token_interface::id(),
+    let init_token_account_ix = spl_token_2022_interface::instruction::initialize_account3(
+        token_program_id,
         &keypair.pubkey(),
         mint,
         owner,
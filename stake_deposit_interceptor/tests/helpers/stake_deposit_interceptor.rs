@@ -2,6 +2,7 @@ use solana_keypair::{Keypair, Signer};
 use solana_program_test::ProgramTestContext;
 use solana_pubkey::Pubkey;
 use solana_transaction::Transaction;
+use stake_deposit_interceptor_program::state::{CoolDownUnit, FeeCurve};
 
 /// Create and initialize a `StakePoolDepositStakeAuthority`.
 #[allow(dead_code)]
@@ -12,6 +13,30 @@ pub async fn create_stake_deposit_authority(
     authority: &Keypair,
     base: &Keypair,
     fee_wallet_address: Option<&Pubkey>,
+) {
+    create_stake_deposit_authority_with_token_program(
+        ctx,
+        stake_pool_pubkey,
+        stake_pool_mint,
+        &spl_token_interface::id(),
+        authority,
+        base,
+        fee_wallet_address,
+    )
+    .await
+}
+
+/// Create and initialize a `StakePoolDepositStakeAuthority` for a pool mint owned by
+/// `token_program_id`.
+#[allow(dead_code)]
+pub async fn create_stake_deposit_authority_with_token_program(
+    ctx: &mut ProgramTestContext,
+    stake_pool_pubkey: &Pubkey,
+    stake_pool_mint: &Pubkey,
+    token_program_id: &Pubkey,
+    authority: &Keypair,
+    base: &Keypair,
+    fee_wallet_address: Option<&Pubkey>,
 ) {
     let mut fee_wallet = Pubkey::new_unique();
     if let Some(fee_wallet_address) = fee_wallet_address {
@@ -26,10 +51,12 @@ pub async fn create_stake_deposit_authority(
             stake_pool_pubkey,
             stake_pool_mint,
             &spl_stake_pool::id(),
-            &spl_token_interface::id(),
+            token_program_id,
             &fee_wallet,
             cool_down_seconds,
             initial_fee_bps,
+            FeeCurve::Linear,
+            CoolDownUnit::Seconds,
             &authority.pubkey(),
             &base.pubkey(),
         );
@@ -7,11 +7,13 @@ use solana_transaction::{InstructionError, Transaction, TransactionError};
 use stake_deposit_interceptor_client::{
     errors::StakeDepositInterceptorError,
     instructions::{
-        DepositStakeWhitelistedBuilder, WithdrawFromHopperBuilder, WithdrawStakeWhitelistedBuilder,
+        DepositSolWhitelistedBuilder, DepositStakeWhitelistedBuilder, FundRebateVaultBuilder,
+        WithdrawFromHopperBuilder, WithdrawFromRebateVaultBuilder, WithdrawSolWhitelistedBuilder,
+        WithdrawStakeWhitelistedBuilder,
     },
     programs::STAKE_DEPOSIT_INTERCEPTOR_ID,
 };
-use stake_deposit_interceptor_program::state::hopper::Hopper;
+use stake_deposit_interceptor_program::state::{hopper::Hopper, rebate_vault::RebateVault};
 
 use crate::helpers::TestError;
 
@@ -37,6 +39,11 @@ impl StakeDepositInterceptorProgramClient {
         Hopper::find_program_address(&STAKE_DEPOSIT_INTERCEPTOR_ID, whitelist, deposit_authority).0
     }
 
+    #[allow(dead_code)]
+    pub fn get_rebate_vault_pda(&self, deposit_authority: &Pubkey) -> Pubkey {
+        RebateVault::find_program_address(&STAKE_DEPOSIT_INTERCEPTOR_ID, deposit_authority).0
+    }
+
     #[allow(clippy::too_many_arguments, dead_code)]
     pub async fn deposit_stake_whitelisted(
         &mut self,
@@ -102,6 +109,7 @@ impl StakeDepositInterceptorProgramClient {
         pool_mint: Pubkey,
         fee_rebate_hopper: Pubkey,
         fee_rebate_receiver: Pubkey,
+        rebate_vault: Option<Pubkey>,
         spl_stake_pool_program_id: Pubkey,
         pool_tokens_in: u64,
         minimum_lamports_out: u64,
@@ -126,6 +134,7 @@ impl StakeDepositInterceptorProgramClient {
             .clock(solana_clock::Clock::id())
             .spl_stake_pool_program(spl_stake_pool_program_id)
             .stake_program(solana_stake_interface::program::id())
+            .rebate_vault(rebate_vault)
             .pool_tokens_in(pool_tokens_in)
             .minimum_lamports_out(minimum_lamports_out)
             .instruction();
@@ -138,6 +147,100 @@ impl StakeDepositInterceptorProgramClient {
         .await
     }
 
+    #[allow(clippy::too_many_arguments, dead_code)]
+    pub async fn deposit_sol_whitelisted(
+        &mut self,
+        whitelisted_signer: &Keypair,
+        whitelist: Pubkey,
+        stake_pool: Pubkey,
+        stake_deposit_authority: Pubkey,
+        withdraw_authority: Pubkey,
+        reserve_stake: Pubkey,
+        pool_tokens_to: Pubkey,
+        manager_fee_account: Pubkey,
+        referral_fee_account: Pubkey,
+        pool_mint: Pubkey,
+        spl_stake_pool_program: Pubkey,
+        lamports_in: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await.unwrap();
+        let ix = DepositSolWhitelistedBuilder::new()
+            .whitelisted_signer(whitelisted_signer.pubkey())
+            .whitelist(whitelist)
+            .stake_pool(stake_pool)
+            .stake_deposit_authority(stake_deposit_authority)
+            .withdraw_authority(withdraw_authority)
+            .reserve_stake(reserve_stake)
+            .pool_tokens_to(pool_tokens_to)
+            .manager_fee_account(manager_fee_account)
+            .referral_fee_account(referral_fee_account)
+            .pool_mint(pool_mint)
+            .spl_stake_pool_program(spl_stake_pool_program)
+            .lamports_in(lamports_in)
+            .instruction();
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, whitelisted_signer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments, dead_code)]
+    pub async fn withdraw_sol_whitelisted(
+        &mut self,
+        stake_deposit_authority: Pubkey,
+        whitelisted_signer: &Keypair,
+        whitelist: Pubkey,
+        stake_pool: Pubkey,
+        withdraw_authority: Pubkey,
+        user_pool_token_account: Pubkey,
+        reserve_stake: Pubkey,
+        lamports_to: Pubkey,
+        manager_fee_account: Pubkey,
+        pool_mint: Pubkey,
+        fee_rebate_hopper: Pubkey,
+        fee_rebate_receiver: Pubkey,
+        rebate_budget: Option<Pubkey>,
+        rebate_vault: Option<Pubkey>,
+        spl_stake_pool_program_id: Pubkey,
+        pool_tokens_in: u64,
+        minimum_lamports_out: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await.unwrap();
+        let ix = WithdrawSolWhitelistedBuilder::new()
+            .stake_deposit_authority(stake_deposit_authority)
+            .whitelisted_signer(whitelisted_signer.pubkey())
+            .whitelist(whitelist)
+            .stake_pool(stake_pool)
+            .withdraw_authority(withdraw_authority)
+            .user_transfer_authority(whitelisted_signer.pubkey())
+            .user_pool_token_account(user_pool_token_account)
+            .reserve_stake(reserve_stake)
+            .lamports_to(lamports_to)
+            .manager_fee_account(manager_fee_account)
+            .pool_mint(pool_mint)
+            .fee_rebate_hopper(fee_rebate_hopper)
+            .fee_rebate_recipient(fee_rebate_receiver)
+            .clock(solana_clock::Clock::id())
+            .stake_history(solana_stake_interface::stake_history::StakeHistory::id())
+            .stake_program(solana_stake_interface::program::id())
+            .spl_stake_pool_program(spl_stake_pool_program_id)
+            .rebate_budget(rebate_budget)
+            .rebate_vault(rebate_vault)
+            .pool_tokens_in(pool_tokens_in)
+            .minimum_lamports_out(minimum_lamports_out)
+            .instruction();
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, whitelisted_signer],
+            blockhash,
+        ))
+        .await
+    }
+
     #[allow(dead_code)]
     pub async fn withdraw_from_hopper(
         &mut self,
@@ -166,6 +269,62 @@ impl StakeDepositInterceptorProgramClient {
         .await
     }
 
+    #[allow(dead_code)]
+    pub async fn fund_rebate_vault(
+        &mut self,
+        funder: &Keypair,
+        stake_deposit_authority: Pubkey,
+        source: Pubkey,
+        pool_mint: Pubkey,
+        amount: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await.unwrap();
+        let ix = FundRebateVaultBuilder::new()
+            .funder(funder.pubkey())
+            .deposit_stake_authority(stake_deposit_authority)
+            .rebate_vault(self.get_rebate_vault_pda(&stake_deposit_authority))
+            .source(source)
+            .pool_mint(pool_mint)
+            .token_program(spl_token_interface::id())
+            .amount(amount)
+            .instruction();
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, funder],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn withdraw_from_rebate_vault(
+        &mut self,
+        authority: &Keypair,
+        stake_deposit_authority: Pubkey,
+        destination: Pubkey,
+        pool_mint: Pubkey,
+        amount: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await.unwrap();
+        let ix = WithdrawFromRebateVaultBuilder::new()
+            .authority(authority.pubkey())
+            .deposit_stake_authority(stake_deposit_authority)
+            .rebate_vault(self.get_rebate_vault_pda(&stake_deposit_authority))
+            .destination(destination)
+            .pool_mint(pool_mint)
+            .token_program(spl_token_interface::id())
+            .amount(amount)
+            .instruction();
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, authority],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn process_transaction(&mut self, tx: &Transaction) -> Result<(), TestError> {
         self.banks_client
             .process_transaction_with_preflight_and_commitment(
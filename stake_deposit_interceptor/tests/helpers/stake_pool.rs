@@ -10,7 +10,10 @@ use solana_transaction::Transaction;
 use spl_pod::solana_program::borsh1::{get_instance_packed_len, get_packed_len};
 use spl_stake_pool::MAX_VALIDATORS_TO_UPDATE;
 
-use super::{create_mint, create_token_account, create_vote, get_account};
+use super::{
+    create_mint_with_token_program, create_token_account_with_token_program, create_vote,
+    get_account,
+};
 
 // Copied from SPL stake-pool program
 #[allow(dead_code)]
@@ -189,12 +192,27 @@ pub struct StakePoolAccounts {
     pub withdraw_authority: Pubkey,
     pub pool_fee_account: Pubkey,
     pub validator_list: Pubkey,
+    pub token_program_id: Pubkey,
 }
 
 /// Create a stake pool and all of it's dependencies including the SPL Mint.
 pub async fn create_stake_pool(ctx: &mut ProgramTestContext) -> StakePoolAccounts {
-    let pool_mint = create_mint(ctx).await;
-    let pool_fee_account = create_token_account(ctx, &ctx.payer.pubkey(), &pool_mint).await;
+    create_stake_pool_with_token_program(ctx, &spl_token_interface::id()).await
+}
+
+/// Create a stake pool whose mint and fee account are owned by `token_program_id`.
+pub async fn create_stake_pool_with_token_program(
+    ctx: &mut ProgramTestContext,
+    token_program_id: &Pubkey,
+) -> StakePoolAccounts {
+    let pool_mint = create_mint_with_token_program(ctx, token_program_id, &[]).await;
+    let pool_fee_account = create_token_account_with_token_program(
+        ctx,
+        &ctx.payer.pubkey(),
+        &pool_mint,
+        token_program_id,
+    )
+    .await;
     let max_validators = 5;
 
     let stake_pool_keypair = Keypair::new();
@@ -247,11 +265,11 @@ pub async fn create_stake_pool(ctx: &mut ProgramTestContext) -> StakePoolAccount
         validator_list_size as u64,
         &spl_stake_pool::id(),
     );
-    let update_mint_authority_ix = spl_token_interface::instruction::set_authority(
-        &spl_token_interface::id(),
+    let update_mint_authority_ix = spl_token_2022_interface::instruction::set_authority(
+        token_program_id,
         &pool_mint,
         Some(&withdraw_authority),
-        spl_token_interface::instruction::AuthorityType::MintTokens,
+        spl_token_2022_interface::instruction::AuthorityType::MintTokens,
         &ctx.payer.pubkey(),
         &[],
     )
@@ -267,7 +285,7 @@ pub async fn create_stake_pool(ctx: &mut ProgramTestContext) -> StakePoolAccount
         &reserve_stake_account,
         &pool_mint,
         &pool_fee_account,
-        &spl_token_interface::id(),
+        token_program_id,
         None,
         zero_fee,
         one_fee,
@@ -297,6 +315,7 @@ pub async fn create_stake_pool(ctx: &mut ProgramTestContext) -> StakePoolAccount
         withdraw_authority,
         pool_fee_account,
         validator_list: validator_list_keypair.pubkey(),
+        token_program_id: *token_program_id,
     }
 }
 
@@ -325,6 +344,56 @@ pub async fn update_stake_deposit_authority(
     banks_client.process_transaction(transaction).await.unwrap();
 }
 
+/// Updates the sol_deposit_authority on the given StakePool.
+#[allow(dead_code)]
+pub async fn update_sol_deposit_authority(
+    banks_client: &mut BanksClient,
+    stake_pool_accounts: &StakePoolAccounts,
+    new_sol_deposit_authority: &Pubkey,
+    manager: &Keypair,
+    recent_blockhash: Hash,
+) {
+    let instruction = spl_stake_pool::instruction::set_funding_authority(
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &manager.pubkey(),
+        Some(new_sol_deposit_authority),
+        spl_stake_pool::instruction::FundingType::SolDeposit,
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&manager.pubkey()),
+        &[manager],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(transaction).await.unwrap();
+}
+
+/// Updates the sol_withdraw_authority on the given StakePool.
+#[allow(dead_code)]
+pub async fn update_sol_withdraw_authority(
+    banks_client: &mut BanksClient,
+    stake_pool_accounts: &StakePoolAccounts,
+    new_sol_withdraw_authority: &Pubkey,
+    manager: &Keypair,
+    recent_blockhash: Hash,
+) {
+    let instruction = spl_stake_pool::instruction::set_funding_authority(
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &manager.pubkey(),
+        Some(new_sol_withdraw_authority),
+        spl_stake_pool::instruction::FundingType::SolWithdraw,
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&manager.pubkey()),
+        &[manager],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(transaction).await.unwrap();
+}
+
 /// Deposit Sol into the stake pool
 #[allow(clippy::too_many_arguments)]
 #[allow(dead_code)]
@@ -376,8 +445,13 @@ pub async fn create_validator_and_add_to_pool(
     );
 
     // Create a pool_mint account to receive the LST from DepositSol below
-    let pool_token_account =
-        create_token_account(ctx, &ctx.payer.pubkey(), &stake_pool_accounts.pool_mint).await;
+    let pool_token_account = create_token_account_with_token_program(
+        ctx,
+        &ctx.payer.pubkey(),
+        &stake_pool_accounts.pool_mint,
+        &stake_pool_accounts.token_program_id,
+    )
+    .await;
 
     let rent = ctx.banks_client.get_rent().await.unwrap();
     let stake_rent = rent.minimum_balance(std::mem::size_of::<
@@ -473,7 +547,7 @@ pub async fn stake_pool_update_all(
             &stake_pool_accounts.reserve_stake_account,
             &stake_pool_accounts.pool_fee_account,
             &stake_pool_accounts.pool_mint,
-            &spl_token_interface::id(),
+            &stake_pool_accounts.token_program_id,
         ),
         spl_stake_pool::instruction::cleanup_removed_validator_entries(
             &spl_stake_pool::id(),
@@ -0,0 +1,325 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_ix_error, create_stake_deposit_authority, get_account,
+    get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    stake_deposit_interceptor_client::StakeDepositInterceptorProgramClient,
+    update_sol_deposit_authority, update_sol_withdraw_authority,
+    whitelist_management_client::WhitelistManagementProgramClient, StakePoolAccounts, TestError,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program::{native_token::LAMPORTS_PER_SOL, program_pack::Pack};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{InstructionError, Transaction};
+use spl_associated_token_account_interface::{
+    address::get_associated_token_address, instruction::create_associated_token_account,
+};
+use stake_deposit_interceptor_program::{
+    instruction::{
+        create_update_deposit_stake_authority_instruction,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::{rebate_budget::RebateBudget, StakePoolDepositStakeAuthority},
+};
+
+const DEPOSIT_LAMPORTS: u64 = 2 * LAMPORTS_PER_SOL;
+const HOPPER_LAMPORTS: u64 = LAMPORTS_PER_SOL;
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    interceptor_client: StakeDepositInterceptorProgramClient,
+    whitelist: Pubkey,
+    hopper: Pubkey,
+    whitelisted_signer: Keypair,
+    pool_tokens_to: Pubkey,
+    rebate_budget: Pubkey,
+}
+
+/// Whitelisted SOL deposit of `DEPOSIT_LAMPORTS` into a StakePool gated by a
+/// StakePoolDepositStakeAuthority with the given Hopper rebate policy, and a funded Hopper.
+async fn setup(
+    hopper_rebate_discount_bps: u32,
+    max_hopper_rebate_lamports: u64,
+    epoch_hopper_rebate_budget: u64,
+) -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+    update_sol_withdraw_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+
+    let update_ix = create_update_deposit_stake_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &authority.pubkey(),
+        &deposit_authority_base.pubkey(),
+        None,
+        None,
+        None,
+        None,
+        Some(jito_whitelist_management_client::programs::JITO_WHITELIST_MANAGEMENT_ID),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(hopper_rebate_discount_bps),
+        Some(max_hopper_rebate_lamports),
+        Some(epoch_hopper_rebate_budget),
+        None,
+        None,
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let mut whitelist_management_program_client =
+        WhitelistManagementProgramClient::new(ctx.banks_client.clone(), ctx.payer.insecure_clone());
+    let mut interceptor_client = StakeDepositInterceptorProgramClient::new(
+        ctx.banks_client.clone(),
+        ctx.payer.insecure_clone(),
+    );
+
+    let admin = Keypair::new();
+    airdrop_lamports(&mut ctx, &admin.pubkey(), LAMPORTS_PER_SOL).await;
+    whitelist_management_program_client
+        .do_initialize_whitelist(admin.pubkey())
+        .await;
+
+    let whitelisted_signer = Keypair::new();
+    airdrop_lamports(
+        &mut ctx,
+        &whitelisted_signer.pubkey(),
+        10 * LAMPORTS_PER_SOL,
+    )
+    .await;
+    whitelist_management_program_client
+        .do_add_to_whitelist(&admin, whitelisted_signer.pubkey())
+        .await;
+    let whitelist = whitelist_management_program_client.get_whitelist_pda();
+
+    let pool_tokens_to =
+        get_associated_token_address(&whitelisted_signer.pubkey(), &stake_pool_accounts.pool_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[create_associated_token_account(
+            &ctx.payer.pubkey(),
+            &whitelisted_signer.pubkey(),
+            &stake_pool_accounts.pool_mint,
+            &spl_token_interface::id(),
+        )],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    interceptor_client
+        .deposit_sol_whitelisted(
+            &whitelisted_signer,
+            whitelist,
+            stake_pool_accounts.stake_pool,
+            deposit_stake_authority_pubkey,
+            stake_pool_accounts.withdraw_authority,
+            stake_pool_accounts.reserve_stake_account,
+            pool_tokens_to,
+            stake_pool_accounts.pool_fee_account,
+            stake_pool_accounts.pool_fee_account,
+            stake_pool_accounts.pool_mint,
+            spl_stake_pool::id(),
+            DEPOSIT_LAMPORTS,
+        )
+        .await
+        .unwrap();
+
+    let hopper = interceptor_client.get_hopper_pda(&whitelist, &deposit_stake_authority_pubkey);
+    airdrop_lamports(&mut ctx, &hopper, HOPPER_LAMPORTS).await;
+
+    let (rebate_budget, _bump, _seeds) = RebateBudget::find_program_address(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_stake_authority_pubkey,
+        &whitelisted_signer.pubkey(),
+    );
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        interceptor_client,
+        whitelist,
+        hopper,
+        whitelisted_signer,
+        pool_tokens_to,
+        rebate_budget,
+    }
+}
+
+/// Withdraw `pool_tokens_in` as SOL, returning the lamports withdrawn and the lamports rebated
+/// from the Hopper.
+async fn withdraw_sol(
+    test: &mut TestContext,
+    pool_tokens_in: u64,
+    rebate_budget: Option<Pubkey>,
+) -> Result<(u64, u64), TestError> {
+    let lamports_to = Pubkey::new_unique();
+    let fee_rebate_receiver = Pubkey::new_unique();
+    let whitelisted_signer = test.whitelisted_signer.insecure_clone();
+    test.interceptor_client
+        .withdraw_sol_whitelisted(
+            test.deposit_stake_authority_pubkey,
+            &whitelisted_signer,
+            test.whitelist,
+            test.stake_pool_accounts.stake_pool,
+            test.stake_pool_accounts.withdraw_authority,
+            test.pool_tokens_to,
+            test.stake_pool_accounts.reserve_stake_account,
+            lamports_to,
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_mint,
+            test.hopper,
+            fee_rebate_receiver,
+            rebate_budget,
+            None,
+            spl_stake_pool::id(),
+            pool_tokens_in,
+            0,
+        )
+        .await?;
+
+    let withdrawn = get_account(&mut test.ctx.banks_client, &lamports_to)
+        .await
+        .lamports;
+    let rebate = test
+        .ctx
+        .banks_client
+        .get_account(fee_rebate_receiver)
+        .await
+        .unwrap()
+        .map(|account| account.lamports)
+        .unwrap_or_default();
+    Ok((withdrawn, rebate))
+}
+
+async fn get_pool_token_amount(test: &mut TestContext) -> u64 {
+    let account = get_account(&mut test.ctx.banks_client, &test.pool_tokens_to).await;
+    spl_token_interface::state::Account::unpack(&account.data)
+        .unwrap()
+        .amount
+}
+
+#[tokio::test]
+async fn test_hopper_rebate_discount_and_cap() {
+    // Rebate half of the withdrawal fee
+    let mut test = setup(5_000, 0, 0).await;
+    let pool_tokens_in = get_pool_token_amount(&mut test).await / 2;
+    let (withdrawn, rebate) = withdraw_sol(&mut test, pool_tokens_in, None).await.unwrap();
+    let fee_lamports = DEPOSIT_LAMPORTS / 2 - withdrawn;
+    assert!(fee_lamports > 0);
+    assert_eq!(rebate, fee_lamports / 2);
+
+    // Cap the rebate of each withdrawal below the discounted fee
+    let mut test = setup(5_000, LAMPORTS_PER_SOL / 1_000, 0).await;
+    let pool_tokens_in = get_pool_token_amount(&mut test).await / 2;
+    let (_withdrawn, rebate) = withdraw_sol(&mut test, pool_tokens_in, None).await.unwrap();
+    assert_eq!(rebate, LAMPORTS_PER_SOL / 1_000);
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        u64::from(deposit_stake_authority.total_hopper_rebate_lamports),
+        LAMPORTS_PER_SOL / 1_000
+    );
+
+    // A full discount disables rebates
+    let mut test = setup(10_000, 0, 0).await;
+    let pool_tokens_in = get_pool_token_amount(&mut test).await / 2;
+    let (_withdrawn, rebate) = withdraw_sol(&mut test, pool_tokens_in, None).await.unwrap();
+    assert_eq!(rebate, 0);
+    let hopper = get_account(&mut test.ctx.banks_client, &test.hopper).await;
+    assert_eq!(hopper.lamports, HOPPER_LAMPORTS);
+}
+
+#[tokio::test]
+async fn test_hopper_rebate_epoch_budget() {
+    let mut test = setup(0, 0, 0).await;
+    // Measure the full rebate of withdrawing a quarter of the deposit
+    let pool_tokens_in = get_pool_token_amount(&mut test).await / 4;
+    let (_withdrawn, full_rebate) = withdraw_sol(&mut test, pool_tokens_in, None).await.unwrap();
+    assert!(full_rebate > 1);
+
+    // Budget the whitelisted signer one and a half withdrawal fees per epoch
+    let epoch_budget = full_rebate + full_rebate / 2;
+    let mut test = setup(0, 0, epoch_budget).await;
+    let rebate_budget = test.rebate_budget;
+
+    // The RebateBudget must be supplied once a budget is set
+    let result = withdraw_sol(&mut test, pool_tokens_in, None).await;
+    assert_ix_error(result, InstructionError::NotEnoughAccountKeys);
+
+    // The first withdrawal is rebated in full and creates the RebateBudget
+    let (_withdrawn, rebate) = withdraw_sol(&mut test, pool_tokens_in, Some(rebate_budget))
+        .await
+        .unwrap();
+    assert_eq!(rebate, full_rebate);
+    let budget =
+        get_account_data_deserialized::<RebateBudget>(&mut test.ctx.banks_client, &rebate_budget)
+            .await;
+    assert_eq!(budget.whitelisted_signer, test.whitelisted_signer.pubkey());
+    assert_eq!(u64::from(budget.epoch_rebate_lamports), full_rebate);
+
+    // The second only receives what remains of the budget
+    let (_withdrawn, rebate) = withdraw_sol(&mut test, pool_tokens_in, Some(rebate_budget))
+        .await
+        .unwrap();
+    assert_eq!(rebate, epoch_budget - full_rebate);
+
+    // The budget is exhausted for the rest of the epoch
+    let (_withdrawn, rebate) = withdraw_sol(&mut test, pool_tokens_in, Some(rebate_budget))
+        .await
+        .unwrap();
+    assert_eq!(rebate, 0);
+    let budget =
+        get_account_data_deserialized::<RebateBudget>(&mut test.ctx.banks_client, &rebate_budget)
+            .await;
+    assert_eq!(u64::from(budget.epoch_rebate_lamports), epoch_budget);
+}
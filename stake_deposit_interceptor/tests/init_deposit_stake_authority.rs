@@ -13,8 +13,9 @@ use solana_system_interface::instruction::transfer;
 use solana_transaction::{AccountMeta, Instruction, InstructionError, Transaction};
 use spl_associated_token_account_interface::address::get_associated_token_address;
 use stake_deposit_interceptor_program::{
-    error::StakeDepositInterceptorError, instruction::derive_stake_pool_deposit_stake_authority,
-    state::StakePoolDepositStakeAuthority,
+    error::StakeDepositInterceptorError,
+    instruction::derive_stake_pool_deposit_stake_authority,
+    state::{CoolDownUnit, FeeCurve, StakePoolDepositStakeAuthority},
 };
 
 #[tokio::test]
@@ -26,6 +27,7 @@ async fn test_init_deposit_stake_authority() {
     let authority = Keypair::new();
     let cool_down_seconds = 100;
     let initial_fee_bps = 20;
+    let fee_curve = FeeCurve::FlatThenLinear { flat_seconds: 50 };
     let init_ix =
         stake_deposit_interceptor_program::instruction::create_init_deposit_stake_authority_instruction(
             &stake_deposit_interceptor_program::id(),
@@ -37,6 +39,8 @@ async fn test_init_deposit_stake_authority() {
             &fee_wallet.pubkey(),
             cool_down_seconds,
             initial_fee_bps,
+            fee_curve,
+            CoolDownUnit::Seconds,
             &authority.pubkey(),
             &deposit_authority_base.pubkey(),
         );
@@ -87,6 +91,7 @@ async fn test_init_deposit_stake_authority() {
     let actual_initial_fee_bps: u32 = deposit_stake_authority.inital_fee_bps.into();
     assert_eq!(actual_cool_down_seconds, cool_down_seconds);
     assert_eq!(actual_initial_fee_bps, initial_fee_bps);
+    assert_eq!(deposit_stake_authority.fee_curve(), fee_curve);
     assert_eq!(
         deposit_stake_authority.base,
         deposit_authority_base.pubkey()
@@ -132,6 +137,8 @@ async fn setup_with_ix() -> (
             &fee_wallet.pubkey(),
             cool_down_seconds,
             initial_fee_bps,
+            FeeCurve::Linear,
+            CoolDownUnit::Seconds,
             &authority.pubkey(),
             &deposit_authority_base.pubkey(),
         );
@@ -377,6 +384,8 @@ async fn test_fail_initial_fee_bps_cannot_exceed_10000() {
             &fee_wallet.pubkey(),
             cool_down_seconds,
             initial_fee_bps,
+            FeeCurve::Linear,
+            CoolDownUnit::Seconds,
             &authority.pubkey(),
             &deposit_authority_base.pubkey(),
         );
@@ -395,3 +404,44 @@ async fn test_fail_initial_fee_bps_cannot_exceed_10000() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn test_fail_invalid_fee_curve() {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+
+    let deposit_authority_base = Keypair::new();
+    let fee_wallet = Keypair::new();
+    let authority = Keypair::new();
+    let cool_down_seconds = 100;
+    let initial_fee_bps = 20;
+    let ix =
+        stake_deposit_interceptor_program::instruction::create_init_deposit_stake_authority_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &ctx.payer.pubkey(),
+            &stake_pool_accounts.stake_pool,
+            &stake_pool_accounts.pool_mint,
+            &spl_stake_pool::id(),
+            &spl_token_interface::id(),
+            &fee_wallet.pubkey(),
+            cool_down_seconds,
+            initial_fee_bps,
+            FeeCurve::FlatThenLinear { flat_seconds: cool_down_seconds + 1 },
+            CoolDownUnit::Seconds,
+            &authority.pubkey(),
+            &deposit_authority_base.pubkey(),
+        );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &deposit_authority_base],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidFeeCurve as u32),
+    )
+    .await;
+}
@@ -0,0 +1,371 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, clone_account_to_new_address,
+    create_stake_deposit_authority, get_account_data_deserialized,
+    program_test_context_with_stake_pool_state, set_clock_time, update_sol_deposit_authority,
+    StakePoolAccounts,
+};
+use solana_clock::Clock;
+use solana_keypair::{Keypair, Signer};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{AccountMeta, InstructionError, Transaction};
+use spl_pod::solana_program::borsh1::try_from_slice_unchecked;
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_deposit_sol_instruction, create_merge_deposit_receipts_instruction,
+        derive_stake_deposit_receipt, derive_stake_pool_deposit_stake_authority,
+    },
+    state::{DepositReceipt, StakePoolDepositStakeAuthority},
+};
+
+async fn deposit_sol(
+    ctx: &mut ProgramTestContext,
+    stake_pool_accounts: &StakePoolAccounts,
+    deposit_stake_authority: &StakePoolDepositStakeAuthority,
+    deposit_authority_base: &Keypair,
+    depositor: &Keypair,
+    lamports_in: u64,
+) -> Pubkey {
+    let deposit_receipt_base = Keypair::new();
+    let ix = create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.withdraw_authority,
+        &stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &deposit_stake_authority.vault,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &deposit_authority_base.pubkey(),
+        lamports_in,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (deposit_receipt_pda, _bump_seed) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+    deposit_receipt_pda
+}
+
+/// Creates two DepositReceipts for the same depositor, the second some time after the first.
+async fn setup() -> (ProgramTestContext, Keypair, Pubkey, Pubkey, Pubkey) {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let stake_pool_account = ctx
+        .banks_client
+        .get_account(stake_pool_accounts.stake_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let stake_pool =
+        try_from_slice_unchecked::<spl_stake_pool::state::StakePool>(&stake_pool_account.data)
+            .unwrap();
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+
+    let first_deposit_receipt_pda = deposit_sol(
+        &mut ctx,
+        &stake_pool_accounts,
+        &deposit_stake_authority,
+        &deposit_authority_base,
+        &depositor,
+        2 * LAMPORTS_PER_SOL,
+    )
+    .await;
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    set_clock_time(&mut ctx, clock.unix_timestamp + 1_000).await;
+
+    let second_deposit_receipt_pda = deposit_sol(
+        &mut ctx,
+        &stake_pool_accounts,
+        &deposit_stake_authority,
+        &deposit_authority_base,
+        &depositor,
+        LAMPORTS_PER_SOL,
+    )
+    .await;
+
+    (
+        ctx,
+        depositor,
+        first_deposit_receipt_pda,
+        second_deposit_receipt_pda,
+        deposit_stake_authority_pubkey,
+    )
+}
+
+#[tokio::test]
+async fn test_merge_deposit_receipts() {
+    let (
+        mut ctx,
+        depositor,
+        first_deposit_receipt_pda,
+        second_deposit_receipt_pda,
+        deposit_stake_authority_pubkey,
+    ) = setup().await;
+    let first_deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &first_deposit_receipt_pda,
+    )
+    .await;
+    let second_deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &second_deposit_receipt_pda,
+    )
+    .await;
+    let source_lamports = ctx
+        .banks_client
+        .get_account(second_deposit_receipt_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let depositor_lamports = ctx
+        .banks_client
+        .get_balance(depositor.pubkey())
+        .await
+        .unwrap();
+
+    let ix = create_merge_deposit_receipts_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &first_deposit_receipt_pda,
+        &second_deposit_receipt_pda,
+        &depositor.pubkey(),
+        &deposit_stake_authority_pubkey,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &depositor],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // Destination DepositReceipt holds the combined balance and the later deposit time
+    let merged_deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &first_deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(
+        u64::from(merged_deposit_receipt.lst_amount),
+        u64::from(first_deposit_receipt.lst_amount) + u64::from(second_deposit_receipt.lst_amount)
+    );
+    assert_eq!(
+        merged_deposit_receipt.deposit_time,
+        second_deposit_receipt.deposit_time
+    );
+    assert_eq!(merged_deposit_receipt.base, first_deposit_receipt.base);
+    assert_eq!(merged_deposit_receipt.owner, depositor.pubkey());
+
+    // Source DepositReceipt is closed and its rent refunded to the owner
+    let source_account = ctx
+        .banks_client
+        .get_account(second_deposit_receipt_pda)
+        .await
+        .unwrap();
+    assert!(source_account.is_none());
+    let updated_depositor_lamports = ctx
+        .banks_client
+        .get_balance(depositor.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(
+        updated_depositor_lamports,
+        depositor_lamports + source_lamports
+    );
+
+    // Merging closes a DepositReceipt but leaves the outstanding "pool" tokens unchanged
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(u64::from(deposit_stake_authority.open_receipts), 1);
+    assert_eq!(
+        u64::from(deposit_stake_authority.outstanding_receipt_lst),
+        u64::from(merged_deposit_receipt.lst_amount)
+    );
+    assert_eq!(
+        u64::from(deposit_stake_authority.total_deposited_lst),
+        u64::from(merged_deposit_receipt.lst_amount)
+    );
+}
+
+#[tokio::test]
+async fn test_fail_invalid_owner() {
+    let (
+        mut ctx,
+        depositor,
+        first_deposit_receipt_pda,
+        second_deposit_receipt_pda,
+        deposit_stake_authority_pubkey,
+    ) = setup().await;
+    let bad_owner = Keypair::new();
+    let ix = create_merge_deposit_receipts_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &first_deposit_receipt_pda,
+        &second_deposit_receipt_pda,
+        &bad_owner.pubkey(),
+        &deposit_stake_authority_pubkey,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &bad_owner],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidDepositReceiptOwner as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_owner_not_signer() {
+    let (
+        mut ctx,
+        depositor,
+        first_deposit_receipt_pda,
+        second_deposit_receipt_pda,
+        deposit_stake_authority_pubkey,
+    ) = setup().await;
+    let mut ix = create_merge_deposit_receipts_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &first_deposit_receipt_pda,
+        &second_deposit_receipt_pda,
+        &depositor.pubkey(),
+        &deposit_stake_authority_pubkey,
+    );
+    ix.accounts[2] = AccountMeta::new(depositor.pubkey(), false);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::SignatureMissing as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_merge_into_self() {
+    let (
+        mut ctx,
+        depositor,
+        first_deposit_receipt_pda,
+        _second_deposit_receipt_pda,
+        deposit_stake_authority_pubkey,
+    ) = setup().await;
+    let ix = create_merge_deposit_receipts_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &first_deposit_receipt_pda,
+        &first_deposit_receipt_pda,
+        &depositor.pubkey(),
+        &deposit_stake_authority_pubkey,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::DepositReceiptMismatch as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_invalid_deposit_stake_authority() {
+    let (
+        mut ctx,
+        depositor,
+        first_deposit_receipt_pda,
+        second_deposit_receipt_pda,
+        deposit_stake_authority_pubkey,
+    ) = setup().await;
+    let bad_account = clone_account_to_new_address(&mut ctx, &deposit_stake_authority_pubkey).await;
+    let ix = create_merge_deposit_receipts_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &first_deposit_receipt_pda,
+        &second_deposit_receipt_pda,
+        &depositor.pubkey(),
+        &bad_account,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(
+            StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority as u32,
+        ),
+    )
+    .await;
+}
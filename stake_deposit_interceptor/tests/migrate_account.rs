@@ -0,0 +1,318 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_deposit_authority, get_account,
+    get_account_data_deserialized, program_test_context_with_stake_pool_state, set_account_version,
+    update_sol_deposit_authority, StakePoolAccounts,
+};
+use solana_account::{Account as SolanaAccount, AccountSharedData};
+use solana_keypair::{Keypair, Signer};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{InstructionError, Transaction};
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_deposit_sol_instruction, create_migrate_account_instruction,
+        create_pause_instruction, derive_stake_deposit_receipt,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::{
+        account_version, DepositReceipt, StakePoolDepositStakeAuthority, VersionedAccount,
+        ACCOUNT_VERSION_OFFSET,
+    },
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_authority_base: Keypair,
+    authority: Keypair,
+}
+
+async fn setup() -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        deposit_authority_base,
+        authority,
+    }
+}
+
+/// Overwrite the StakePoolDepositStakeAuthority with `data` and `lamports`, simulating an
+/// account written by another version of the program.
+async fn set_deposit_stake_authority_account(test: &mut TestContext, data: Vec<u8>, lamports: u64) {
+    let account = get_account(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    test.ctx.set_account(
+        &test.deposit_stake_authority_pubkey,
+        &AccountSharedData::from(SolanaAccount {
+            data,
+            lamports,
+            ..account
+        }),
+    );
+}
+
+fn migrate_tx(test: &TestContext, account: &Pubkey) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[create_migrate_account_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.ctx.payer.pubkey(),
+            account,
+        )],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer],
+        test.ctx.last_blockhash,
+    )
+}
+
+fn pause_tx(test: &TestContext, operations: u8) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[create_pause_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.deposit_stake_authority_pubkey,
+            &test.authority.pubkey(),
+            operations,
+        )],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn test_migrate_unversioned_account() {
+    let mut test = setup().await;
+
+    let account = get_account(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        account_version(&account.data),
+        StakePoolDepositStakeAuthority::VERSION
+    );
+
+    // Accounts created before versioning have a zeroed version byte
+    let mut data = account.data.clone();
+    data[ACCOUNT_VERSION_OFFSET] = 0;
+    set_deposit_stake_authority_account(&mut test, data, account.lamports).await;
+
+    // Version 0 accounts are readable without migrating
+    test.ctx
+        .banks_client
+        .process_transaction(pause_tx(
+            &test,
+            StakePoolDepositStakeAuthority::PAUSE_DEPOSIT,
+        ))
+        .await
+        .unwrap();
+
+    let tx = migrate_tx(&test, &test.deposit_stake_authority_pubkey);
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let migrated = get_account(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        account_version(&migrated.data),
+        StakePoolDepositStakeAuthority::VERSION
+    );
+    assert_eq!(migrated.data.len(), StakePoolDepositStakeAuthority::LEN);
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(deposit_stake_authority.authority, test.authority.pubkey());
+    assert_eq!(
+        deposit_stake_authority.paused_operations,
+        StakePoolDepositStakeAuthority::PAUSE_DEPOSIT
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_reallocates_smaller_account() {
+    let mut test = setup().await;
+
+    // An older layout that predates the trailing reserved bytes
+    let account = get_account(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    let old_len = StakePoolDepositStakeAuthority::LEN - 32;
+    let mut data = account.data[..old_len].to_vec();
+    data[ACCOUNT_VERSION_OFFSET] = 0;
+    let rent = test.ctx.banks_client.get_rent().await.unwrap();
+    set_deposit_stake_authority_account(&mut test, data, rent.minimum_balance(old_len)).await;
+
+    // Validate: the account cannot be used until it is migrated
+    let tx = pause_tx(&test, StakePoolDepositStakeAuthority::PAUSE_DEPOSIT);
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::AccountMigrationRequired as u32),
+    )
+    .await;
+
+    let tx = migrate_tx(&test, &test.deposit_stake_authority_pubkey);
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let migrated = get_account(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(migrated.data.len(), StakePoolDepositStakeAuthority::LEN);
+    assert_eq!(
+        migrated.lamports,
+        rent.minimum_balance(StakePoolDepositStakeAuthority::LEN)
+    );
+    assert_eq!(
+        account_version(&migrated.data),
+        StakePoolDepositStakeAuthority::VERSION
+    );
+    assert_eq!(migrated.data[8..old_len], account.data[8..old_len]);
+    assert!(migrated.data[old_len..].iter().all(|byte| *byte == 0));
+
+    let tx = pause_tx(&test, StakePoolDepositStakeAuthority::PAUSE_CLAIM);
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_fail_migrate_newer_version() {
+    let mut test = setup().await;
+
+    let account = get_account(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    let mut data = account.data.clone();
+    data[ACCOUNT_VERSION_OFFSET] = StakePoolDepositStakeAuthority::VERSION + 1;
+    set_deposit_stake_authority_account(&mut test, data, account.lamports).await;
+
+    let tx = migrate_tx(&test, &test.deposit_stake_authority_pubkey);
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::UnsupportedAccountVersion as u32),
+    )
+    .await;
+
+    let tx = pause_tx(&test, StakePoolDepositStakeAuthority::PAUSE_DEPOSIT);
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::UnsupportedAccountVersion as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_migrate_account_not_owned_by_program() {
+    let mut test = setup().await;
+
+    let tx = migrate_tx(&test, &test.ctx.payer.pubkey());
+    assert_transaction_err(&mut test.ctx, tx, InstructionError::IncorrectProgramId).await;
+}
+
+#[tokio::test]
+async fn test_deposit_before_migration() {
+    let mut test = setup().await;
+    update_sol_deposit_authority(
+        &mut test.ctx.banks_client,
+        &test.stake_pool_accounts,
+        &test.deposit_stake_authority_pubkey,
+        &test.ctx.payer,
+        test.ctx.last_blockhash,
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+
+    // A version 4 layout predates `deposit_whitelist` and `withdraw_whitelist`, which were
+    // carved out of `reserved`, so it is readable without migrating
+    set_account_version(&mut test.ctx, &test.deposit_stake_authority_pubkey, 4).await;
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut test.ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_deposit_sol_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &depositor.pubkey(),
+            &spl_stake_pool::id(),
+            &test.stake_pool_accounts.stake_pool,
+            &test.stake_pool_accounts.withdraw_authority,
+            &test.stake_pool_accounts.reserve_stake_account,
+            &depositor.pubkey(),
+            &deposit_stake_authority.vault,
+            &test.stake_pool_accounts.pool_fee_account,
+            &test.stake_pool_accounts.pool_fee_account,
+            &test.stake_pool_accounts.pool_mint,
+            &spl_token_interface::id(),
+            &deposit_receipt_base.pubkey(),
+            &test.deposit_authority_base.pubkey(),
+            LAMPORTS_PER_SOL,
+        )],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (deposit_receipt_pda, _bump) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+    let deposit_receipt = get_account(&mut test.ctx.banks_client, &deposit_receipt_pda).await;
+    assert_eq!(deposit_receipt.data.len(), DepositReceipt::LEN);
+
+    // Only MigrateAccount bumps the layout version
+    let account = get_account(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(account_version(&account.data), 4);
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(u64::from(deposit_stake_authority.open_receipts), 1);
+}
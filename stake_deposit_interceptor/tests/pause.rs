@@ -0,0 +1,299 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_deposit_authority, create_token_account,
+    get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    update_sol_deposit_authority, StakePoolAccounts,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use spl_associated_token_account_interface::{
+    address::get_associated_token_address, instruction::create_associated_token_account,
+};
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_claim_pool_tokens_instruction, create_deposit_sol_instruction,
+        create_pause_instruction, create_unpause_instruction, derive_stake_deposit_receipt,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::StakePoolDepositStakeAuthority,
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    deposit_authority_base: Keypair,
+    authority: Keypair,
+    fee_wallet: Keypair,
+    depositor: Keypair,
+}
+
+async fn setup() -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    let fee_wallet = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        Some(&fee_wallet.pubkey()),
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        deposit_stake_authority,
+        deposit_authority_base,
+        authority,
+        fee_wallet,
+        depositor,
+    }
+}
+
+fn deposit_sol_ix(test: &TestContext, deposit_receipt_base: &Keypair) -> Instruction {
+    create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &test.stake_pool_accounts.withdraw_authority,
+        &test.stake_pool_accounts.reserve_stake_account,
+        &test.depositor.pubkey(),
+        &test.deposit_stake_authority.vault,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.stake_pool_accounts.pool_fee_account,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &test.deposit_authority_base.pubkey(),
+        LAMPORTS_PER_SOL,
+    )
+}
+
+async fn set_paused(test: &mut TestContext, operations: u8, paused: bool) {
+    let ix = if paused {
+        create_pause_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.deposit_stake_authority_pubkey,
+            &test.authority.pubkey(),
+            operations,
+        )
+    } else {
+        create_unpause_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.deposit_stake_authority_pubkey,
+            &test.authority.pubkey(),
+            operations,
+        )
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_pause_and_unpause_deposit() {
+    let mut test = setup().await;
+
+    set_paused(
+        &mut test,
+        StakePoolDepositStakeAuthority::PAUSE_DEPOSIT | StakePoolDepositStakeAuthority::PAUSE_CLAIM,
+        true,
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        deposit_stake_authority.paused_operations,
+        StakePoolDepositStakeAuthority::PAUSE_DEPOSIT | StakePoolDepositStakeAuthority::PAUSE_CLAIM
+    );
+
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_sol_ix(&test, &deposit_receipt_base)],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::Paused as u32),
+    )
+    .await;
+
+    // Unpausing deposits leaves claims paused
+    set_paused(
+        &mut test,
+        StakePoolDepositStakeAuthority::PAUSE_DEPOSIT,
+        false,
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        deposit_stake_authority.paused_operations,
+        StakePoolDepositStakeAuthority::PAUSE_CLAIM
+    );
+
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_sol_ix(&test, &deposit_receipt_base)],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_fail_claim_paused() {
+    let mut test = setup().await;
+
+    let deposit_receipt_base = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_sol_ix(&test, &deposit_receipt_base)],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor, &deposit_receipt_base],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+    let (deposit_receipt_pda, _bump_seed) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+
+    set_paused(&mut test, StakePoolDepositStakeAuthority::PAUSE_CLAIM, true).await;
+
+    let depositor_pool_token_account = create_token_account(
+        &mut test.ctx,
+        &test.depositor.pubkey(),
+        &test.stake_pool_accounts.pool_mint,
+    )
+    .await;
+    let fee_token_account = get_associated_token_address(
+        &test.fee_wallet.pubkey(),
+        &test.stake_pool_accounts.pool_mint,
+    );
+    let create_fee_token_account_ix = create_associated_token_account(
+        &test.depositor.pubkey(),
+        &test.fee_wallet.pubkey(),
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+    );
+    let ix = create_claim_pool_tokens_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &deposit_receipt_pda,
+        &test.depositor.pubkey(),
+        &test.deposit_stake_authority.vault,
+        &depositor_pool_token_account,
+        &fee_token_account,
+        &test.deposit_stake_authority_pubkey,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        false,
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[create_fee_token_account_ix, ix],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::Paused as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_invalid_authority() {
+    let mut test = setup().await;
+    let bad_authority = Keypair::new();
+    let ix = create_pause_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_stake_authority_pubkey,
+        &bad_authority.pubkey(),
+        StakePoolDepositStakeAuthority::PAUSE_ALL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &bad_authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidAuthority as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_unknown_operation() {
+    let mut test = setup().await;
+    let ix = create_pause_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_stake_authority_pubkey,
+        &test.authority.pubkey(),
+        !StakePoolDepositStakeAuthority::PAUSE_ALL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(&mut test.ctx, tx, InstructionError::InvalidArgument).await;
+}
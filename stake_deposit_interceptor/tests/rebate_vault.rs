@@ -0,0 +1,373 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_ix_error, create_stake_deposit_authority, get_account,
+    get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    stake_deposit_interceptor_client::StakeDepositInterceptorProgramClient,
+    update_sol_deposit_authority, update_sol_withdraw_authority,
+    whitelist_management_client::WhitelistManagementProgramClient, StakePoolAccounts, TestError,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program::{native_token::LAMPORTS_PER_SOL, program_pack::Pack};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{InstructionError, Transaction};
+use spl_associated_token_account_interface::{
+    address::get_associated_token_address, instruction::create_associated_token_account,
+};
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_update_deposit_stake_authority_instruction,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::{RebateMode, StakePoolDepositStakeAuthority},
+};
+
+const DEPOSIT_LAMPORTS: u64 = 2 * LAMPORTS_PER_SOL;
+const HOPPER_LAMPORTS: u64 = LAMPORTS_PER_SOL;
+const VAULT_POOL_TOKENS: u64 = LAMPORTS_PER_SOL / 10;
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    authority: Keypair,
+    interceptor_client: StakeDepositInterceptorProgramClient,
+    whitelist: Pubkey,
+    hopper: Pubkey,
+    whitelisted_signer: Keypair,
+    pool_tokens_to: Pubkey,
+    rebate_vault: Pubkey,
+}
+
+/// Whitelisted SOL deposit of `DEPOSIT_LAMPORTS` into a StakePool gated by a
+/// StakePoolDepositStakeAuthority rebating from its RebateVault, and a funded Hopper.
+async fn setup(hopper_rebate_discount_bps: u32) -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+    update_sol_withdraw_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+
+    let update_ix = create_update_deposit_stake_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &authority.pubkey(),
+        &deposit_authority_base.pubkey(),
+        None,
+        None,
+        None,
+        None,
+        Some(jito_whitelist_management_client::programs::JITO_WHITELIST_MANAGEMENT_ID),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(hopper_rebate_discount_bps),
+        None,
+        None,
+        Some(RebateMode::RebateVault),
+        None,
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let mut whitelist_management_program_client =
+        WhitelistManagementProgramClient::new(ctx.banks_client.clone(), ctx.payer.insecure_clone());
+    let mut interceptor_client = StakeDepositInterceptorProgramClient::new(
+        ctx.banks_client.clone(),
+        ctx.payer.insecure_clone(),
+    );
+
+    let admin = Keypair::new();
+    airdrop_lamports(&mut ctx, &admin.pubkey(), LAMPORTS_PER_SOL).await;
+    whitelist_management_program_client
+        .do_initialize_whitelist(admin.pubkey())
+        .await;
+
+    let whitelisted_signer = Keypair::new();
+    airdrop_lamports(
+        &mut ctx,
+        &whitelisted_signer.pubkey(),
+        10 * LAMPORTS_PER_SOL,
+    )
+    .await;
+    whitelist_management_program_client
+        .do_add_to_whitelist(&admin, whitelisted_signer.pubkey())
+        .await;
+    let whitelist = whitelist_management_program_client.get_whitelist_pda();
+
+    let pool_tokens_to =
+        get_associated_token_address(&whitelisted_signer.pubkey(), &stake_pool_accounts.pool_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[create_associated_token_account(
+            &ctx.payer.pubkey(),
+            &whitelisted_signer.pubkey(),
+            &stake_pool_accounts.pool_mint,
+            &spl_token_interface::id(),
+        )],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    interceptor_client
+        .deposit_sol_whitelisted(
+            &whitelisted_signer,
+            whitelist,
+            stake_pool_accounts.stake_pool,
+            deposit_stake_authority_pubkey,
+            stake_pool_accounts.withdraw_authority,
+            stake_pool_accounts.reserve_stake_account,
+            pool_tokens_to,
+            stake_pool_accounts.pool_fee_account,
+            stake_pool_accounts.pool_fee_account,
+            stake_pool_accounts.pool_mint,
+            spl_stake_pool::id(),
+            DEPOSIT_LAMPORTS,
+        )
+        .await
+        .unwrap();
+
+    let hopper = interceptor_client.get_hopper_pda(&whitelist, &deposit_stake_authority_pubkey);
+    airdrop_lamports(&mut ctx, &hopper, HOPPER_LAMPORTS).await;
+
+    let rebate_vault = interceptor_client.get_rebate_vault_pda(&deposit_stake_authority_pubkey);
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        authority,
+        interceptor_client,
+        whitelist,
+        hopper,
+        whitelisted_signer,
+        pool_tokens_to,
+        rebate_vault,
+    }
+}
+
+/// Withdraw `pool_tokens_in` as SOL, returning the lamports rebated from the Hopper.
+async fn withdraw_sol(
+    test: &mut TestContext,
+    pool_tokens_in: u64,
+    rebate_vault: Option<Pubkey>,
+) -> Result<u64, TestError> {
+    let lamports_to = Pubkey::new_unique();
+    let fee_rebate_receiver = Pubkey::new_unique();
+    let whitelisted_signer = test.whitelisted_signer.insecure_clone();
+    test.interceptor_client
+        .withdraw_sol_whitelisted(
+            test.deposit_stake_authority_pubkey,
+            &whitelisted_signer,
+            test.whitelist,
+            test.stake_pool_accounts.stake_pool,
+            test.stake_pool_accounts.withdraw_authority,
+            test.pool_tokens_to,
+            test.stake_pool_accounts.reserve_stake_account,
+            lamports_to,
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_mint,
+            test.hopper,
+            fee_rebate_receiver,
+            None,
+            rebate_vault,
+            spl_stake_pool::id(),
+            pool_tokens_in,
+            0,
+        )
+        .await?;
+
+    Ok(test
+        .ctx
+        .banks_client
+        .get_account(fee_rebate_receiver)
+        .await
+        .unwrap()
+        .map(|account| account.lamports)
+        .unwrap_or_default())
+}
+
+async fn get_token_amount(test: &mut TestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account(&mut test.ctx.banks_client, token_account).await;
+    spl_token_interface::state::Account::unpack(&account.data)
+        .unwrap()
+        .amount
+}
+
+async fn fund_rebate_vault(test: &mut TestContext, amount: u64) -> Result<(), TestError> {
+    let whitelisted_signer = test.whitelisted_signer.insecure_clone();
+    test.interceptor_client
+        .fund_rebate_vault(
+            &whitelisted_signer,
+            test.deposit_stake_authority_pubkey,
+            test.pool_tokens_to,
+            test.stake_pool_accounts.pool_mint,
+            amount,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn test_fund_and_withdraw_from_rebate_vault() {
+    let mut test = setup(0).await;
+    let rebate_vault = test.rebate_vault;
+    let pool_tokens_to = test.pool_tokens_to;
+    let pool_tokens = get_token_amount(&mut test, &pool_tokens_to).await;
+
+    // Funding creates the RebateVault, owned by the StakePoolDepositStakeAuthority
+    fund_rebate_vault(&mut test, VAULT_POOL_TOKENS)
+        .await
+        .unwrap();
+    let vault = get_account(&mut test.ctx.banks_client, &rebate_vault).await;
+    let vault = spl_token_interface::state::Account::unpack(&vault.data).unwrap();
+    assert_eq!(vault.owner, test.deposit_stake_authority_pubkey);
+    assert_eq!(vault.mint, test.stake_pool_accounts.pool_mint);
+    assert_eq!(vault.amount, VAULT_POOL_TOKENS);
+
+    // Funding again tops up the existing RebateVault
+    fund_rebate_vault(&mut test, VAULT_POOL_TOKENS)
+        .await
+        .unwrap();
+    assert_eq!(
+        get_token_amount(&mut test, &rebate_vault).await,
+        2 * VAULT_POOL_TOKENS
+    );
+    assert_eq!(
+        get_token_amount(&mut test, &pool_tokens_to).await,
+        pool_tokens - 2 * VAULT_POOL_TOKENS
+    );
+
+    // Only the authority may withdraw from the RebateVault
+    let not_authority = Keypair::new();
+    let result = test
+        .interceptor_client
+        .withdraw_from_rebate_vault(
+            &not_authority,
+            test.deposit_stake_authority_pubkey,
+            pool_tokens_to,
+            test.stake_pool_accounts.pool_mint,
+            VAULT_POOL_TOKENS,
+        )
+        .await;
+    assert_ix_error(
+        result,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidAuthority as u32),
+    );
+
+    let authority = test.authority.insecure_clone();
+    test.interceptor_client
+        .withdraw_from_rebate_vault(
+            &authority,
+            test.deposit_stake_authority_pubkey,
+            pool_tokens_to,
+            test.stake_pool_accounts.pool_mint,
+            VAULT_POOL_TOKENS,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        get_token_amount(&mut test, &rebate_vault).await,
+        VAULT_POOL_TOKENS
+    );
+    assert_eq!(
+        get_token_amount(&mut test, &pool_tokens_to).await,
+        pool_tokens - VAULT_POOL_TOKENS
+    );
+}
+
+#[tokio::test]
+async fn test_rebate_vault_rebate() {
+    let mut test = setup(5_000).await;
+    let rebate_vault = test.rebate_vault;
+    let pool_tokens_to = test.pool_tokens_to;
+    let pool_tokens_in = LAMPORTS_PER_SOL / 2;
+
+    // The RebateVault must be supplied in RebateMode::RebateVault
+    let result = withdraw_sol(&mut test, pool_tokens_in, None).await;
+    assert_ix_error(result, InstructionError::NotEnoughAccountKeys);
+
+    // An unfunded RebateVault pays no rebate
+    let pool_tokens = get_token_amount(&mut test, &pool_tokens_to).await;
+    let rebate_lamports = withdraw_sol(&mut test, pool_tokens_in, Some(rebate_vault))
+        .await
+        .unwrap();
+    assert_eq!(rebate_lamports, 0);
+    assert_eq!(
+        get_token_amount(&mut test, &pool_tokens_to).await,
+        pool_tokens - pool_tokens_in
+    );
+
+    // Half of the 1% withdrawal fee is rebated in pool tokens, and none in SOL
+    fund_rebate_vault(&mut test, VAULT_POOL_TOKENS)
+        .await
+        .unwrap();
+    let pool_tokens = get_token_amount(&mut test, &pool_tokens_to).await;
+    let rebate_lamports = withdraw_sol(&mut test, pool_tokens_in, Some(rebate_vault))
+        .await
+        .unwrap();
+    assert_eq!(rebate_lamports, 0);
+    let rebate_lst = VAULT_POOL_TOKENS - get_token_amount(&mut test, &rebate_vault).await;
+    // Converting the rebate through lamports may round it down by a pool token
+    assert!((pool_tokens_in / 100 / 2).abs_diff(rebate_lst) <= 1);
+    assert_eq!(
+        get_token_amount(&mut test, &pool_tokens_to).await,
+        pool_tokens - pool_tokens_in + rebate_lst
+    );
+    let hopper = get_account(&mut test.ctx.banks_client, &test.hopper).await;
+    assert_eq!(hopper.lamports, HOPPER_LAMPORTS);
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        u64::from(deposit_stake_authority.total_vault_rebate_lst),
+        rebate_lst
+    );
+    assert_eq!(
+        u64::from(deposit_stake_authority.total_hopper_rebate_lamports),
+        0
+    );
+}
@@ -0,0 +1,259 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_deposit_authority, create_token_account,
+    get_account, get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    update_sol_deposit_authority, StakePoolAccounts,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program::{
+    borsh1::try_from_slice_unchecked, native_token::LAMPORTS_PER_SOL, program_pack::Pack,
+};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use spl_token_interface::state::Account;
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_deposit_sol_instruction, create_redeem_receipt_to_stake_instruction,
+        derive_stake_deposit_receipt, derive_stake_pool_deposit_stake_authority,
+    },
+    return_data::ClaimReturnData,
+    state::{DepositReceipt, StakePoolDepositStakeAuthority},
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    depositor: Keypair,
+    deposit_receipt_pda: Pubkey,
+    fee_token_account: Pubkey,
+}
+
+/// Creates a StakePoolDepositStakeAuthority and a single DepositReceipt from DepositSol,
+/// still within its cool down. The pool has no validators, so stake is withdrawn from
+/// the reserve.
+async fn setup() -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    let fee_wallet = Pubkey::new_unique();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        Some(&fee_wallet),
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    let fee_token_account =
+        create_token_account(&mut ctx, &fee_wallet, &stake_pool_accounts.pool_mint).await;
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    let deposit_receipt_base = Keypair::new();
+    let ix = create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.withdraw_authority,
+        &stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &deposit_stake_authority.vault,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &deposit_authority_base.pubkey(),
+        2 * LAMPORTS_PER_SOL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (deposit_receipt_pda, _bump) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        deposit_stake_authority,
+        depositor,
+        deposit_receipt_pda,
+        fee_token_account,
+    }
+}
+
+/// Creates an uninitialized stake account to receive the withdrawn stake.
+async fn create_stake_split_to(ctx: &mut ProgramTestContext) -> Pubkey {
+    let stake_split_to = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let space = std::mem::size_of::<solana_stake_interface::state::StakeStateV2>();
+    let ix = solana_system_interface::instruction::create_account(
+        &ctx.payer.pubkey(),
+        &stake_split_to.pubkey(),
+        rent.minimum_balance(space),
+        space as u64,
+        &solana_stake_interface::program::id(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &stake_split_to],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+    stake_split_to.pubkey()
+}
+
+fn redeem_ix(test: &TestContext, owner: &Pubkey, stake_split_to: &Pubkey) -> Instruction {
+    create_redeem_receipt_to_stake_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_receipt_pda,
+        owner,
+        &test.deposit_stake_authority.vault,
+        &test.fee_token_account,
+        &test.deposit_stake_authority_pubkey,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &spl_stake_pool::id(),
+        &test.stake_pool_accounts.stake_pool,
+        &test.stake_pool_accounts.validator_list,
+        &test.stake_pool_accounts.withdraw_authority,
+        &test.stake_pool_accounts.reserve_stake_account,
+        stake_split_to,
+        &test.stake_pool_accounts.pool_fee_account,
+        None,
+        0,
+    )
+}
+
+async fn token_amount(ctx: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account(&mut ctx.banks_client, token_account).await;
+    Account::unpack(&account.data).unwrap().amount
+}
+
+#[tokio::test]
+async fn test_success_redeem_receipt_to_stake() {
+    let mut test = setup().await;
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut test.ctx.banks_client,
+        &test.deposit_receipt_pda,
+    )
+    .await;
+    let stake_split_to = create_stake_split_to(&mut test.ctx).await;
+    let split_to_lamports_before = get_account(&mut test.ctx.banks_client, &stake_split_to)
+        .await
+        .lamports;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[redeem_ix(&test, &test.depositor.pubkey(), &stake_split_to)],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+    let return_data = test
+        .ctx
+        .banks_client
+        .simulate_transaction(tx.clone())
+        .await
+        .unwrap()
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // The cool down fee was paid in pool tokens and the rest of the vault was burned
+    let fee_amount = token_amount(&mut test.ctx, &test.fee_token_account).await;
+    assert!(fee_amount > 0);
+    assert!(fee_amount < u64::from(deposit_receipt.lst_amount));
+    // The net amount is the pool tokens burned for the stake account
+    assert_eq!(
+        ClaimReturnData::decode(&return_data.program_id, &return_data.data),
+        Some(ClaimReturnData {
+            fee_amount,
+            net_amount: u64::from(deposit_receipt.lst_amount) - fee_amount,
+        })
+    );
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.deposit_stake_authority.vault).await,
+        0
+    );
+
+    // The owner received a stake account they control
+    let split_to_account = get_account(&mut test.ctx.banks_client, &stake_split_to).await;
+    assert!(split_to_account.lamports > split_to_lamports_before);
+    let stake_state = try_from_slice_unchecked::<solana_stake_interface::state::StakeStateV2>(
+        &split_to_account.data,
+    )
+    .unwrap();
+    let meta = stake_state.meta().unwrap();
+    assert_eq!(meta.authorized.staker, test.depositor.pubkey());
+    assert_eq!(meta.authorized.withdrawer, test.depositor.pubkey());
+
+    // DepositReceipt account should have been closed
+    assert!(test
+        .ctx
+        .banks_client
+        .get_account(test.deposit_receipt_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_fail_redeem_receipt_to_stake_not_owner() {
+    let mut test = setup().await;
+    let stake_split_to = create_stake_split_to(&mut test.ctx).await;
+    let not_owner = Keypair::new();
+    airdrop_lamports(&mut test.ctx, &not_owner.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[redeem_ix(&test, &not_owner.pubkey(), &stake_split_to)],
+        Some(&not_owner.pubkey()),
+        &[&not_owner],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidDepositReceiptOwner as u32),
+    )
+    .await;
+}
@@ -0,0 +1,622 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_deposit_authority, get_account,
+    get_account_data_deserialized, program_test_context_with_stake_pool_state, set_account_version,
+    stake_deposit_interceptor_client::{
+        assert_stake_deposit_interceptor_error, StakeDepositInterceptorProgramClient,
+    },
+    update_sol_deposit_authority, update_sol_withdraw_authority,
+    whitelist_management_client::WhitelistManagementProgramClient,
+    StakePoolAccounts, TestError,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program::{native_token::LAMPORTS_PER_SOL, program_pack::Pack};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_transaction::{InstructionError, Transaction};
+use spl_associated_token_account_interface::{
+    address::get_associated_token_address, instruction::create_associated_token_account,
+};
+use spl_pod::solana_program::borsh1::try_from_slice_unchecked;
+use stake_deposit_interceptor_client::errors::StakeDepositInterceptorError;
+use stake_deposit_interceptor_program::{
+    instruction::{
+        create_migrate_account_instruction, create_migrate_deposit_stake_authority_instruction,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::StakePoolDepositStakeAuthority,
+};
+
+const DEPOSIT_LAMPORTS: u64 = 2 * LAMPORTS_PER_SOL;
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    authority: Keypair,
+    deposit_authority_base: Keypair,
+    interceptor_client: StakeDepositInterceptorProgramClient,
+    whitelist: Pubkey,
+    whitelisted_signer: Keypair,
+    pool_tokens_to: Pubkey,
+}
+
+/// Set the interceptor PDA as the StakePool's SOL deposit and withdraw authority and add a
+/// signer, holding a pool token account, to the Whitelist.
+async fn setup() -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+    update_sol_withdraw_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+
+    // Set the jito_whitelist_management_program_id on the StakePoolDepositStakeAuthority
+    let update_ix =
+        stake_deposit_interceptor_program::instruction::create_update_deposit_stake_authority_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &stake_pool_accounts.stake_pool,
+            &authority.pubkey(),
+            &deposit_authority_base.pubkey(),
+            None,
+            None,
+            None,
+            None,
+            Some(jito_whitelist_management_client::programs::JITO_WHITELIST_MANAGEMENT_ID),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let mut whitelist_management_program_client =
+        WhitelistManagementProgramClient::new(ctx.banks_client.clone(), ctx.payer.insecure_clone());
+    let interceptor_client = StakeDepositInterceptorProgramClient::new(
+        ctx.banks_client.clone(),
+        ctx.payer.insecure_clone(),
+    );
+
+    let admin = Keypair::new();
+    airdrop_lamports(&mut ctx, &admin.pubkey(), LAMPORTS_PER_SOL).await;
+    whitelist_management_program_client
+        .do_initialize_whitelist(admin.pubkey())
+        .await;
+
+    let whitelisted_signer = Keypair::new();
+    airdrop_lamports(
+        &mut ctx,
+        &whitelisted_signer.pubkey(),
+        10 * LAMPORTS_PER_SOL,
+    )
+    .await;
+    whitelist_management_program_client
+        .do_add_to_whitelist(&admin, whitelisted_signer.pubkey())
+        .await;
+
+    // Create the pool token ATA for the whitelisted signer
+    let pool_tokens_to =
+        get_associated_token_address(&whitelisted_signer.pubkey(), &stake_pool_accounts.pool_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[create_associated_token_account(
+            &ctx.payer.pubkey(),
+            &whitelisted_signer.pubkey(),
+            &stake_pool_accounts.pool_mint,
+            &spl_token_interface::id(),
+        )],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        authority,
+        deposit_authority_base,
+        interceptor_client,
+        whitelist: whitelist_management_program_client.get_whitelist_pda(),
+        whitelisted_signer,
+        pool_tokens_to,
+    }
+}
+
+async fn deposit_sol_whitelisted(test: &mut TestContext, signer: &Keypair) {
+    test.interceptor_client
+        .deposit_sol_whitelisted(
+            signer,
+            test.whitelist,
+            test.stake_pool_accounts.stake_pool,
+            test.deposit_stake_authority_pubkey,
+            test.stake_pool_accounts.withdraw_authority,
+            test.stake_pool_accounts.reserve_stake_account,
+            test.pool_tokens_to,
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_mint,
+            spl_stake_pool::id(),
+            DEPOSIT_LAMPORTS,
+        )
+        .await
+        .unwrap();
+}
+
+/// Withdraw `pool_tokens_in` as SOL to a new account, without a Hopper rebate
+async fn withdraw_sol_whitelisted(
+    test: &mut TestContext,
+    signer: &Keypair,
+    pool_tokens_in: u64,
+) -> Result<(), TestError> {
+    let hopper_pda = test
+        .interceptor_client
+        .get_hopper_pda(&test.whitelist, &test.deposit_stake_authority_pubkey);
+    test.interceptor_client
+        .withdraw_sol_whitelisted(
+            test.deposit_stake_authority_pubkey,
+            signer,
+            test.whitelist,
+            test.stake_pool_accounts.stake_pool,
+            test.stake_pool_accounts.withdraw_authority,
+            test.pool_tokens_to,
+            test.stake_pool_accounts.reserve_stake_account,
+            Pubkey::new_unique(),
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_mint,
+            hopper_pda,
+            signer.pubkey(),
+            None,
+            None,
+            spl_stake_pool::id(),
+            pool_tokens_in,
+            0,
+        )
+        .await
+}
+
+/// Bind the StakePoolDepositStakeAuthority to the given deposit and withdraw Whitelists
+async fn set_operation_whitelists(
+    test: &mut TestContext,
+    deposit_whitelist: Pubkey,
+    withdraw_whitelist: Pubkey,
+) {
+    let update_ix =
+        stake_deposit_interceptor_program::instruction::create_update_deposit_stake_authority_instruction(
+            &stake_deposit_interceptor_program::id(),
+            &test.stake_pool_accounts.stake_pool,
+            &test.authority.pubkey(),
+            &test.deposit_authority_base.pubkey(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(deposit_whitelist),
+            Some(withdraw_whitelist),
+        );
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Migrate a StakePoolDepositStakeAuthority with the version 4 layout, which predates
+/// `deposit_whitelist` and `withdraw_whitelist`. When `authority` is given it binds both to
+/// the Whitelist.
+async fn migrate_version_4(
+    test: &mut TestContext,
+    authority: Option<&Keypair>,
+) -> Result<(), BanksClientError> {
+    let deposit_stake_authority_pubkey = test.deposit_stake_authority_pubkey;
+    set_account_version(&mut test.ctx, &deposit_stake_authority_pubkey, 4).await;
+    let tx = migrate_tx(test, authority);
+    test.ctx.banks_client.process_transaction(tx).await
+}
+
+fn migrate_tx(test: &TestContext, authority: Option<&Keypair>) -> Transaction {
+    let (ix, signers) = match authority {
+        Some(authority) => (
+            create_migrate_deposit_stake_authority_instruction(
+                &stake_deposit_interceptor_program::id(),
+                &test.ctx.payer.pubkey(),
+                &test.deposit_stake_authority_pubkey,
+                &authority.pubkey(),
+                &test.whitelist,
+            ),
+            vec![&test.ctx.payer, authority],
+        ),
+        None => (
+            create_migrate_account_instruction(
+                &stake_deposit_interceptor_program::id(),
+                &test.ctx.payer.pubkey(),
+                &test.deposit_stake_authority_pubkey,
+            ),
+            vec![&test.ctx.payer],
+        ),
+    };
+    Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&test.ctx.payer.pubkey()),
+        &signers,
+        test.ctx.last_blockhash,
+    )
+}
+
+async fn get_token_amount(ctx: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account(&mut ctx.banks_client, token_account).await;
+    spl_token_interface::state::Account::unpack(&account.data)
+        .unwrap()
+        .amount
+}
+
+#[tokio::test]
+async fn test_deposit_sol_whitelisted() {
+    let mut test = setup().await;
+
+    let stake_pool_account = get_account(
+        &mut test.ctx.banks_client,
+        &test.stake_pool_accounts.stake_pool,
+    )
+    .await;
+    let stake_pool =
+        try_from_slice_unchecked::<spl_stake_pool::state::StakePool>(&stake_pool_account.data)
+            .unwrap();
+    let reserve_before = get_account(
+        &mut test.ctx.banks_client,
+        &test.stake_pool_accounts.reserve_stake_account,
+    )
+    .await
+    .lamports;
+
+    let whitelisted_signer = test.whitelisted_signer.insecure_clone();
+    deposit_sol_whitelisted(&mut test, &whitelisted_signer).await;
+
+    // Pool tokens go straight to the depositor, without a DepositReceipt
+    let pool_tokens_to = test.pool_tokens_to;
+    assert_eq!(
+        get_token_amount(&mut test.ctx, &pool_tokens_to).await,
+        stake_pool
+            .calc_pool_tokens_for_deposit(DEPOSIT_LAMPORTS)
+            .unwrap()
+    );
+    let reserve_after = get_account(
+        &mut test.ctx.banks_client,
+        &test.stake_pool_accounts.reserve_stake_account,
+    )
+    .await
+    .lamports;
+    assert_eq!(reserve_after, reserve_before + DEPOSIT_LAMPORTS);
+}
+
+#[tokio::test]
+async fn test_fail_deposit_sol_whitelisted_invalid_signer() {
+    let mut test = setup().await;
+
+    let signer = Keypair::new();
+    airdrop_lamports(&mut test.ctx, &signer.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    let result = test
+        .interceptor_client
+        .deposit_sol_whitelisted(
+            &signer,
+            test.whitelist,
+            test.stake_pool_accounts.stake_pool,
+            test.deposit_stake_authority_pubkey,
+            test.stake_pool_accounts.withdraw_authority,
+            test.stake_pool_accounts.reserve_stake_account,
+            test.pool_tokens_to,
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_mint,
+            spl_stake_pool::id(),
+            DEPOSIT_LAMPORTS,
+        )
+        .await;
+    assert_stake_deposit_interceptor_error(
+        result,
+        StakeDepositInterceptorError::InvalidWhitelistedSigner,
+    );
+}
+
+#[tokio::test]
+async fn test_withdraw_sol_whitelisted() {
+    let mut test = setup().await;
+    let whitelisted_signer = test.whitelisted_signer.insecure_clone();
+    deposit_sol_whitelisted(&mut test, &whitelisted_signer).await;
+
+    let hopper_pda = test
+        .interceptor_client
+        .get_hopper_pda(&test.whitelist, &test.deposit_stake_authority_pubkey);
+    airdrop_lamports(&mut test.ctx, &hopper_pda, LAMPORTS_PER_SOL).await;
+
+    let pool_tokens_to = test.pool_tokens_to;
+    let pool_tokens_in = get_token_amount(&mut test.ctx, &pool_tokens_to).await;
+    let lamports_to = Pubkey::new_unique();
+    let fee_rebate_receiver = Pubkey::new_unique();
+    test.interceptor_client
+        .withdraw_sol_whitelisted(
+            test.deposit_stake_authority_pubkey,
+            &whitelisted_signer,
+            test.whitelist,
+            test.stake_pool_accounts.stake_pool,
+            test.stake_pool_accounts.withdraw_authority,
+            pool_tokens_to,
+            test.stake_pool_accounts.reserve_stake_account,
+            lamports_to,
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_mint,
+            hopper_pda,
+            fee_rebate_receiver,
+            None,
+            None,
+            spl_stake_pool::id(),
+            pool_tokens_in,
+            0,
+        )
+        .await
+        .unwrap();
+
+    // Assert all pool tokens were burned and the SOL, net of the withdrawal fee, paid out
+    assert_eq!(get_token_amount(&mut test.ctx, &pool_tokens_to).await, 0);
+    let lamports_to_account = get_account(&mut test.ctx.banks_client, &lamports_to).await;
+    assert!(lamports_to_account.lamports > 0);
+    assert!(lamports_to_account.lamports < DEPOSIT_LAMPORTS);
+
+    // Assert the withdrawal fee was rebated from the Hopper
+    let hopper_account = get_account(&mut test.ctx.banks_client, &hopper_pda).await;
+    assert!(hopper_account.lamports < LAMPORTS_PER_SOL);
+    let fee_rebate_receiver_account =
+        get_account(&mut test.ctx.banks_client, &fee_rebate_receiver).await;
+    assert_eq!(
+        LAMPORTS_PER_SOL - hopper_account.lamports,
+        fee_rebate_receiver_account.lamports
+    );
+    assert_eq!(
+        lamports_to_account.lamports + fee_rebate_receiver_account.lamports,
+        DEPOSIT_LAMPORTS
+    );
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(
+        u64::from(deposit_stake_authority.total_hopper_rebate_lamports),
+        fee_rebate_receiver_account.lamports
+    );
+}
+
+#[tokio::test]
+async fn test_fail_withdraw_sol_whitelisted_without_sol_withdraw_authority() {
+    let mut test = setup().await;
+    let whitelisted_signer = test.whitelisted_signer.insecure_clone();
+    deposit_sol_whitelisted(&mut test, &whitelisted_signer).await;
+
+    // SOL withdrawals from the StakePool are no longer gated by the interceptor PDA
+    update_sol_withdraw_authority(
+        &mut test.ctx.banks_client,
+        &test.stake_pool_accounts,
+        &Pubkey::new_unique(),
+        &test.ctx.payer,
+        test.ctx.last_blockhash,
+    )
+    .await;
+
+    let hopper_pda = test
+        .interceptor_client
+        .get_hopper_pda(&test.whitelist, &test.deposit_stake_authority_pubkey);
+    let pool_tokens_to = test.pool_tokens_to;
+    let result = test
+        .interceptor_client
+        .withdraw_sol_whitelisted(
+            test.deposit_stake_authority_pubkey,
+            &whitelisted_signer,
+            test.whitelist,
+            test.stake_pool_accounts.stake_pool,
+            test.stake_pool_accounts.withdraw_authority,
+            pool_tokens_to,
+            test.stake_pool_accounts.reserve_stake_account,
+            whitelisted_signer.pubkey(),
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_mint,
+            hopper_pda,
+            whitelisted_signer.pubkey(),
+            None,
+            None,
+            spl_stake_pool::id(),
+            1,
+            0,
+        )
+        .await;
+    assert_stake_deposit_interceptor_error(
+        result,
+        StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority,
+    );
+}
+
+#[tokio::test]
+async fn test_operation_whitelists() {
+    let mut test = setup().await;
+    let whitelisted_signer = test.whitelisted_signer.insecure_clone();
+    let whitelist = test.whitelist;
+    let other_whitelist = Pubkey::new_unique();
+    let hopper_pda = test
+        .interceptor_client
+        .get_hopper_pda(&whitelist, &test.deposit_stake_authority_pubkey);
+    airdrop_lamports(&mut test.ctx, &hopper_pda, LAMPORTS_PER_SOL).await;
+
+    // Deposits are bound to the Whitelist, withdrawals to another one
+    set_operation_whitelists(&mut test, whitelist, other_whitelist).await;
+    deposit_sol_whitelisted(&mut test, &whitelisted_signer).await;
+
+    let pool_tokens_to = test.pool_tokens_to;
+    let pool_tokens_in = get_token_amount(&mut test.ctx, &pool_tokens_to).await / 2;
+    let result = withdraw_sol_whitelisted(&mut test, &whitelisted_signer, pool_tokens_in).await;
+    assert_stake_deposit_interceptor_error(result, StakeDepositInterceptorError::InvalidWhitelist);
+
+    // Swap the bindings
+    set_operation_whitelists(&mut test, other_whitelist, whitelist).await;
+    withdraw_sol_whitelisted(&mut test, &whitelisted_signer, pool_tokens_in)
+        .await
+        .unwrap();
+
+    let result = test
+        .interceptor_client
+        .deposit_sol_whitelisted(
+            &whitelisted_signer,
+            whitelist,
+            test.stake_pool_accounts.stake_pool,
+            test.deposit_stake_authority_pubkey,
+            test.stake_pool_accounts.withdraw_authority,
+            test.stake_pool_accounts.reserve_stake_account,
+            pool_tokens_to,
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_fee_account,
+            test.stake_pool_accounts.pool_mint,
+            spl_stake_pool::id(),
+            DEPOSIT_LAMPORTS,
+        )
+        .await;
+    assert_stake_deposit_interceptor_error(result, StakeDepositInterceptorError::InvalidWhitelist);
+}
+
+#[tokio::test]
+async fn test_migrated_authority_accepts_any_whitelist_until_updated() {
+    let mut test = setup().await;
+    let whitelisted_signer = test.whitelisted_signer.insecure_clone();
+    let hopper_pda = test
+        .interceptor_client
+        .get_hopper_pda(&test.whitelist, &test.deposit_stake_authority_pubkey);
+    airdrop_lamports(&mut test.ctx, &hopper_pda, LAMPORTS_PER_SOL).await;
+
+    migrate_version_4(&mut test, None).await.unwrap();
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(deposit_stake_authority.deposit_whitelist, Pubkey::default());
+    assert_eq!(
+        deposit_stake_authority.withdraw_whitelist,
+        Pubkey::default()
+    );
+
+    // The Whitelist used before the upgrade keeps working until the whitelists are set
+    deposit_sol_whitelisted(&mut test, &whitelisted_signer).await;
+    let pool_tokens_to = test.pool_tokens_to;
+    let pool_tokens_in = get_token_amount(&mut test.ctx, &pool_tokens_to).await / 2;
+    withdraw_sol_whitelisted(&mut test, &whitelisted_signer, pool_tokens_in)
+        .await
+        .unwrap();
+
+    set_operation_whitelists(&mut test, Pubkey::new_unique(), Pubkey::new_unique()).await;
+    let result = withdraw_sol_whitelisted(&mut test, &whitelisted_signer, pool_tokens_in).await;
+    assert_stake_deposit_interceptor_error(result, StakeDepositInterceptorError::InvalidWhitelist);
+}
+
+#[tokio::test]
+async fn test_migrate_binds_operation_whitelists() {
+    let mut test = setup().await;
+    let whitelisted_signer = test.whitelisted_signer.insecure_clone();
+    let authority = test.authority.insecure_clone();
+    let hopper_pda = test
+        .interceptor_client
+        .get_hopper_pda(&test.whitelist, &test.deposit_stake_authority_pubkey);
+    airdrop_lamports(&mut test.ctx, &hopper_pda, LAMPORTS_PER_SOL).await;
+
+    migrate_version_4(&mut test, Some(&authority))
+        .await
+        .unwrap();
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    assert_eq!(deposit_stake_authority.deposit_whitelist, test.whitelist);
+    assert_eq!(deposit_stake_authority.withdraw_whitelist, test.whitelist);
+
+    deposit_sol_whitelisted(&mut test, &whitelisted_signer).await;
+    let pool_tokens_to = test.pool_tokens_to;
+    let pool_tokens_in = get_token_amount(&mut test.ctx, &pool_tokens_to).await;
+    withdraw_sol_whitelisted(&mut test, &whitelisted_signer, pool_tokens_in)
+        .await
+        .unwrap();
+
+    // Validate: accounts on the current layout set the whitelists with an update
+    test.ctx.last_blockhash = test.ctx.get_new_latest_blockhash().await.unwrap();
+    let tx = migrate_tx(&test, Some(&authority));
+    assert_transaction_err(&mut test.ctx, tx, InstructionError::InvalidArgument).await;
+}
+
+#[tokio::test]
+async fn test_fail_migrate_binds_operation_whitelists_invalid_authority() {
+    let mut test = setup().await;
+    let deposit_stake_authority_pubkey = test.deposit_stake_authority_pubkey;
+    set_account_version(&mut test.ctx, &deposit_stake_authority_pubkey, 4).await;
+
+    let not_authority = Keypair::new();
+    let tx = migrate_tx(&test, Some(&not_authority));
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidAuthority as u32),
+    )
+    .await;
+}
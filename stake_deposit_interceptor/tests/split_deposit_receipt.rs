@@ -0,0 +1,336 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, clone_account_to_new_address,
+    create_stake_deposit_authority, get_account_data_deserialized,
+    program_test_context_with_stake_pool_state, update_sol_deposit_authority, StakePoolAccounts,
+};
+use solana_keypair::{Keypair, Signer};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{AccountMeta, Instruction, InstructionError, Transaction};
+use spl_pod::solana_program::borsh1::try_from_slice_unchecked;
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_deposit_sol_instruction, create_split_deposit_receipt_instruction,
+        derive_stake_deposit_receipt, derive_stake_pool_deposit_stake_authority,
+    },
+    state::{DepositReceipt, StakePoolDepositStakeAuthority},
+};
+
+async fn setup() -> (ProgramTestContext, StakePoolAccounts, Keypair, Pubkey) {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let stake_pool_account = ctx
+        .banks_client
+        .get_account(stake_pool_accounts.stake_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let stake_pool =
+        try_from_slice_unchecked::<spl_stake_pool::state::StakePool>(&stake_pool_account.data)
+            .unwrap();
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        None,
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+
+    let deposit_receipt_base = Keypair::new();
+    let ix = create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.withdraw_authority,
+        &stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &deposit_stake_authority.vault,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &deposit_authority_base.pubkey(),
+        2 * LAMPORTS_PER_SOL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (deposit_receipt_pda, _bump_seed) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+
+    (ctx, stake_pool_accounts, depositor, deposit_receipt_pda)
+}
+
+#[tokio::test]
+async fn test_split_deposit_receipt() {
+    let (mut ctx, stake_pool_accounts, depositor, deposit_receipt_pda) = setup().await;
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    let lst_amount = u64::from(deposit_receipt.lst_amount);
+    let split_amount = lst_amount / 3;
+
+    let new_base = Keypair::new();
+    let new_owner = Pubkey::new_unique();
+    let ix = create_split_deposit_receipt_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &deposit_receipt_pda,
+        &depositor.pubkey(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt.stake_pool_deposit_stake_authority,
+        &new_base.pubkey(),
+        &new_owner,
+        split_amount,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &new_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // Original DepositReceipt keeps the remainder
+    let updated_deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(
+        u64::from(updated_deposit_receipt.lst_amount),
+        lst_amount - split_amount
+    );
+    assert_eq!(updated_deposit_receipt.owner, depositor.pubkey());
+
+    // New DepositReceipt has the split amount and inherits the fee parameters
+    let (new_deposit_receipt_pda, bump_seed) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &new_base.pubkey(),
+    );
+    let new_deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &new_deposit_receipt_pda,
+    )
+    .await;
+    assert_eq!(new_deposit_receipt.base, new_base.pubkey());
+    assert_eq!(new_deposit_receipt.owner, new_owner);
+    assert_eq!(new_deposit_receipt.bump_seed, bump_seed);
+    assert_eq!(u64::from(new_deposit_receipt.lst_amount), split_amount);
+    assert_eq!(new_deposit_receipt.stake_pool, deposit_receipt.stake_pool);
+    assert_eq!(
+        new_deposit_receipt.stake_pool_deposit_stake_authority,
+        deposit_receipt.stake_pool_deposit_stake_authority
+    );
+    assert_eq!(
+        new_deposit_receipt.deposit_time,
+        deposit_receipt.deposit_time
+    );
+    assert_eq!(
+        new_deposit_receipt.cool_down_seconds,
+        deposit_receipt.cool_down_seconds
+    );
+    assert_eq!(
+        new_deposit_receipt.initial_fee_bps,
+        deposit_receipt.initial_fee_bps
+    );
+
+    // Split moves no "pool" tokens out of the vault, but opens a DepositReceipt
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_receipt.stake_pool_deposit_stake_authority,
+    )
+    .await;
+    assert_eq!(u64::from(deposit_stake_authority.open_receipts), 2);
+    assert_eq!(
+        u64::from(deposit_stake_authority.outstanding_receipt_lst),
+        lst_amount
+    );
+}
+
+async fn setup_with_ix() -> (ProgramTestContext, Keypair, Keypair, Pubkey, Instruction) {
+    let (mut ctx, stake_pool_accounts, depositor, deposit_receipt_pda) = setup().await;
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    let new_base = Keypair::new();
+    let ix = create_split_deposit_receipt_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &deposit_receipt_pda,
+        &depositor.pubkey(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt.stake_pool_deposit_stake_authority,
+        &new_base.pubkey(),
+        &Pubkey::new_unique(),
+        1,
+    );
+    (ctx, depositor, new_base, deposit_receipt_pda, ix)
+}
+
+#[tokio::test]
+async fn test_fail_invalid_owner() {
+    let (mut ctx, depositor, new_base, _deposit_receipt_pda, mut ix) = setup_with_ix().await;
+    let bad_owner = Keypair::new();
+    ix.accounts[2] = AccountMeta::new_readonly(bad_owner.pubkey(), true);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &new_base, &bad_owner],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidDepositReceiptOwner as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_invalid_deposit_receipt_address() {
+    let (mut ctx, depositor, new_base, deposit_receipt_pda, mut ix) = setup_with_ix().await;
+    let bad_account = clone_account_to_new_address(&mut ctx, &deposit_receipt_pda).await;
+    ix.accounts[1] = AccountMeta::new(bad_account, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &new_base],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidDepositReceipt as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_invalid_deposit_stake_authority() {
+    let (mut ctx, depositor, new_base, _deposit_receipt_pda, mut ix) = setup_with_ix().await;
+    let deposit_stake_authority = ix.accounts[7].pubkey;
+    let bad_account = clone_account_to_new_address(&mut ctx, &deposit_stake_authority).await;
+    ix.accounts[7] = AccountMeta::new(bad_account, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &new_base],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(
+            StakeDepositInterceptorError::InvalidStakePoolDepositStakeAuthority as u32,
+        ),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_invalid_new_deposit_receipt() {
+    let (mut ctx, depositor, new_base, _deposit_receipt_pda, mut ix) = setup_with_ix().await;
+    ix.accounts[3] = AccountMeta::new(Pubkey::new_unique(), false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &new_base],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidSeeds as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_split_entire_balance() {
+    let (mut ctx, stake_pool_accounts, depositor, deposit_receipt_pda) = setup().await;
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+    let new_base = Keypair::new();
+    let ix = create_split_deposit_receipt_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &deposit_receipt_pda,
+        &depositor.pubkey(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt.stake_pool_deposit_stake_authority,
+        &new_base.pubkey(),
+        &Pubkey::new_unique(),
+        u64::from(deposit_receipt.lst_amount),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &new_base],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidSplitAmount as u32),
+    )
+    .await;
+}
@@ -0,0 +1,273 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_stake_deposit_authority, create_token_account,
+    get_account, get_account_data_deserialized, program_test_context_with_stake_pool_state,
+    update_sol_deposit_authority, StakePoolAccounts,
+};
+use jito_bytemuck::AccountDeserialize;
+use solana_account::AccountSharedData;
+use solana_keypair::{Keypair, Signer};
+use solana_program::{native_token::LAMPORTS_PER_SOL, program_pack::Pack};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use spl_token_interface::state::Account;
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_deposit_sol_instruction, create_sweep_vault_surplus_instruction,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::StakePoolDepositStakeAuthority,
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    authority: Keypair,
+    fee_token_account: Pubkey,
+}
+
+/// Creates a StakePoolDepositStakeAuthority with a single DepositReceipt from DepositSol.
+async fn setup() -> TestContext {
+    let (mut ctx, stake_pool_accounts) = program_test_context_with_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    let fee_wallet = Pubkey::new_unique();
+    create_stake_deposit_authority(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &authority,
+        &deposit_authority_base,
+        Some(&fee_wallet),
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    let fee_token_account =
+        create_token_account(&mut ctx, &fee_wallet, &stake_pool_accounts.pool_mint).await;
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    let deposit_receipt_base = Keypair::new();
+    let ix = create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.withdraw_authority,
+        &stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &deposit_stake_authority.vault,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &deposit_authority_base.pubkey(),
+        2 * LAMPORTS_PER_SOL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        deposit_stake_authority,
+        authority,
+        fee_token_account,
+    }
+}
+
+fn sweep_ix(test: &TestContext, authority: &Pubkey) -> Instruction {
+    create_sweep_vault_surplus_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_stake_authority_pubkey,
+        authority,
+        &test.deposit_stake_authority.vault,
+        &test.fee_token_account,
+        &test.stake_pool_accounts.pool_mint,
+        &spl_token_interface::id(),
+    )
+}
+
+async fn token_amount(ctx: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account(&mut ctx.banks_client, token_account).await;
+    Account::unpack(&account.data).unwrap().amount
+}
+
+/// Overwrite the token balance of the Vault, e.g. to simulate a direct transfer into it.
+async fn set_vault_amount(test: &mut TestContext, amount: u64) {
+    let mut account = get_account(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority.vault,
+    )
+    .await;
+    let mut vault = Account::unpack(&account.data).unwrap();
+    vault.amount = amount;
+    Account::pack(vault, &mut account.data).unwrap();
+    test.ctx.set_account(
+        &test.deposit_stake_authority.vault,
+        &AccountSharedData::from(account),
+    );
+}
+
+#[tokio::test]
+async fn test_sweep_vault_surplus() {
+    let mut test = setup().await;
+    let outstanding_receipt_lst = u64::from(test.deposit_stake_authority.outstanding_receipt_lst);
+    assert!(outstanding_receipt_lst > 0);
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.deposit_stake_authority.vault).await,
+        outstanding_receipt_lst
+    );
+
+    let surplus = 1_000;
+    set_vault_amount(&mut test, outstanding_receipt_lst + surplus).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix(&test, &test.authority.pubkey())],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // Only the surplus leaves the Vault
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.deposit_stake_authority.vault).await,
+        outstanding_receipt_lst
+    );
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.fee_token_account).await,
+        surplus
+    );
+}
+
+#[tokio::test]
+async fn test_fail_vault_undercollateralized() {
+    let mut test = setup().await;
+    let outstanding_receipt_lst = u64::from(test.deposit_stake_authority.outstanding_receipt_lst);
+    set_vault_amount(&mut test, outstanding_receipt_lst - 1).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix(&test, &test.authority.pubkey())],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::VaultUndercollateralized as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_receipt_accounting_incomplete() {
+    let mut test = setup().await;
+    // Simulate a StakePoolDepositStakeAuthority initialized before the accounting counters
+    let mut account = get_account(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority_pubkey,
+    )
+    .await;
+    StakePoolDepositStakeAuthority::try_from_slice_unchecked_mut(&mut account.data)
+        .unwrap()
+        .receipt_accounting_complete = 0;
+    test.ctx.set_account(
+        &test.deposit_stake_authority_pubkey,
+        &AccountSharedData::from(account),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix(&test, &test.authority.pubkey())],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::ReceiptAccountingIncomplete as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_invalid_authority() {
+    let mut test = setup().await;
+    let bad_authority = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix(&test, &bad_authority.pubkey())],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &bad_authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidAuthority as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_fee_token_account_not_owned_by_fee_wallet() {
+    let mut test = setup().await;
+    let payer = test.ctx.payer.pubkey();
+    let pool_mint = test.stake_pool_accounts.pool_mint;
+    test.fee_token_account = create_token_account(&mut test.ctx, &payer, &pool_mint).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix(&test, &test.authority.pubkey())],
+        Some(&test.ctx.payer.pubkey()),
+        &[&test.ctx.payer, &test.authority],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidFeeTokenAccount as u32),
+    )
+    .await;
+}
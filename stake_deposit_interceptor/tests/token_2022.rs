@@ -0,0 +1,341 @@
+mod helpers;
+
+use helpers::{
+    airdrop_lamports, assert_transaction_err, create_mint_with_extensions,
+    create_stake_deposit_authority_with_token_program, create_token_account_with_token_program,
+    get_account, get_account_data_deserialized,
+    program_test_context_with_token_2022_stake_pool_state, update_sol_deposit_authority,
+    StakePoolAccounts,
+};
+use solana_account::{Account as SolanaAccount, AccountSharedData};
+use solana_keypair::{Keypair, Signer};
+use solana_program::{borsh1::try_from_slice_unchecked, native_token::LAMPORTS_PER_SOL};
+use solana_program_test::ProgramTestContext;
+use solana_pubkey::Pubkey;
+use solana_transaction::{Instruction, InstructionError, Transaction};
+use spl_associated_token_account_interface::address::get_associated_token_address_with_program_id;
+use spl_token_2022_interface::{
+    extension::{
+        default_account_state::instruction::initialize_default_account_state, ExtensionType,
+        StateWithExtensions,
+    },
+    state::{Account, AccountState},
+};
+use stake_deposit_interceptor_program::{
+    error::StakeDepositInterceptorError,
+    instruction::{
+        create_claim_pool_tokens_instruction, create_deposit_sol_instruction,
+        create_init_deposit_stake_authority_instruction, derive_stake_deposit_receipt,
+        derive_stake_pool_deposit_stake_authority,
+    },
+    state::{CoolDownUnit, DepositReceipt, FeeCurve, StakePoolDepositStakeAuthority},
+};
+
+struct TestContext {
+    ctx: ProgramTestContext,
+    stake_pool_accounts: StakePoolAccounts,
+    deposit_stake_authority_pubkey: Pubkey,
+    deposit_stake_authority: StakePoolDepositStakeAuthority,
+    depositor: Keypair,
+    deposit_receipt_pda: Pubkey,
+    deposit_receipt: DepositReceipt,
+    destination_token_account: Pubkey,
+    fee_token_account: Pubkey,
+}
+
+/// Creates a StakePoolDepositStakeAuthority for a StakePool with a Token-2022 pool mint and
+/// a single DepositReceipt from DepositSol.
+async fn setup() -> TestContext {
+    let (mut ctx, stake_pool_accounts) =
+        program_test_context_with_token_2022_stake_pool_state().await;
+    let deposit_authority_base = Keypair::new();
+    let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_authority_base.pubkey(),
+    );
+    // Set the StakePool's sol_deposit_authority to the interceptor program's PDA
+    update_sol_deposit_authority(
+        &mut ctx.banks_client,
+        &stake_pool_accounts,
+        &deposit_stake_authority_pubkey,
+        &ctx.payer,
+        ctx.last_blockhash,
+    )
+    .await;
+
+    let authority = Keypair::new();
+    let fee_wallet = Pubkey::new_unique();
+    create_stake_deposit_authority_with_token_program(
+        &mut ctx,
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_2022_interface::id(),
+        &authority,
+        &deposit_authority_base,
+        Some(&fee_wallet),
+    )
+    .await;
+    let deposit_stake_authority = get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+        &mut ctx.banks_client,
+        &deposit_stake_authority_pubkey,
+    )
+    .await;
+    let fee_token_account = create_token_account_with_token_program(
+        &mut ctx,
+        &fee_wallet,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_2022_interface::id(),
+    )
+    .await;
+
+    let depositor = Keypair::new();
+    airdrop_lamports(&mut ctx, &depositor.pubkey(), 10 * LAMPORTS_PER_SOL).await;
+    let destination_token_account = create_token_account_with_token_program(
+        &mut ctx,
+        &depositor.pubkey(),
+        &stake_pool_accounts.pool_mint,
+        &spl_token_2022_interface::id(),
+    )
+    .await;
+    let deposit_receipt_base = Keypair::new();
+    let ix = create_deposit_sol_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &depositor.pubkey(),
+        &spl_stake_pool::id(),
+        &stake_pool_accounts.stake_pool,
+        &stake_pool_accounts.withdraw_authority,
+        &stake_pool_accounts.reserve_stake_account,
+        &depositor.pubkey(),
+        &deposit_stake_authority.vault,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_fee_account,
+        &stake_pool_accounts.pool_mint,
+        &spl_token_2022_interface::id(),
+        &deposit_receipt_base.pubkey(),
+        &deposit_authority_base.pubkey(),
+        2 * LAMPORTS_PER_SOL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[&depositor, &deposit_receipt_base],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (deposit_receipt_pda, _bump) = derive_stake_deposit_receipt(
+        &stake_deposit_interceptor_program::id(),
+        &stake_pool_accounts.stake_pool,
+        &deposit_receipt_base.pubkey(),
+    );
+    let deposit_receipt = get_account_data_deserialized::<DepositReceipt>(
+        &mut ctx.banks_client,
+        &deposit_receipt_pda,
+    )
+    .await;
+
+    TestContext {
+        ctx,
+        stake_pool_accounts,
+        deposit_stake_authority_pubkey,
+        deposit_stake_authority,
+        depositor,
+        deposit_receipt_pda,
+        deposit_receipt,
+        destination_token_account,
+        fee_token_account,
+    }
+}
+
+fn claim_ix(test: &TestContext, token_program: &Pubkey) -> Instruction {
+    create_claim_pool_tokens_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &test.deposit_receipt_pda,
+        &test.depositor.pubkey(),
+        &test.deposit_stake_authority.vault,
+        &test.destination_token_account,
+        &test.fee_token_account,
+        &test.deposit_stake_authority_pubkey,
+        &test.stake_pool_accounts.pool_mint,
+        token_program,
+        false,
+        None,
+    )
+}
+
+async fn token_amount(ctx: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account(&mut ctx.banks_client, token_account).await;
+    StateWithExtensions::<Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn test_token_2022_deposit_and_claim() {
+    let mut test = setup().await;
+
+    // The Vault is the Token-2022 ATA of the StakePoolDepositStakeAuthority
+    assert_eq!(
+        test.deposit_stake_authority.vault,
+        get_associated_token_address_with_program_id(
+            &test.deposit_stake_authority_pubkey,
+            &test.stake_pool_accounts.pool_mint,
+            &spl_token_2022_interface::id(),
+        )
+    );
+    let vault_account = get_account(
+        &mut test.ctx.banks_client,
+        &test.deposit_stake_authority.vault,
+    )
+    .await;
+    assert_eq!(vault_account.owner, spl_token_2022_interface::id());
+
+    let lst_amount = u64::from(test.deposit_receipt.lst_amount);
+    assert!(lst_amount > 0);
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.deposit_stake_authority.vault).await,
+        lst_amount
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&test, &spl_token_2022_interface::id())],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+    test.ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let destination_amount = token_amount(&mut test.ctx, &test.destination_token_account).await;
+    let fee_amount = token_amount(&mut test.ctx, &test.fee_token_account).await;
+    assert!(fee_amount > 0);
+    assert_eq!(destination_amount + fee_amount, lst_amount);
+    assert_eq!(
+        token_amount(&mut test.ctx, &test.deposit_stake_authority.vault).await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_fail_claim_token_program_does_not_own_pool_mint() {
+    let mut test = setup().await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&test, &spl_token_interface::id())],
+        Some(&test.depositor.pubkey()),
+        &[&test.depositor],
+        test.ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut test.ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidTokenProgram as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_init_unsupported_pool_mint_extension() {
+    let (mut ctx, stake_pool_accounts) =
+        program_test_context_with_token_2022_stake_pool_state().await;
+
+    // The SPL stake-pool program refuses transfer hook mints, so swap the mint into an
+    // existing StakePool's data.
+    let transfer_hook_authority = ctx.payer.pubkey();
+    let pool_mint = create_mint_with_extensions(
+        &mut ctx,
+        &spl_token_2022_interface::id(),
+        &[ExtensionType::TransferHook],
+        |mint| {
+            vec![
+                spl_token_2022_interface::extension::transfer_hook::instruction::initialize(
+                    &spl_token_2022_interface::id(),
+                    mint,
+                    Some(transfer_hook_authority),
+                    Some(Pubkey::new_unique()),
+                )
+                .unwrap(),
+            ]
+        },
+    )
+    .await;
+    assert_init_rejects_pool_mint(&mut ctx, &stake_pool_accounts, pool_mint).await;
+}
+
+#[tokio::test]
+async fn test_fail_init_default_account_state_pool_mint() {
+    let (mut ctx, stake_pool_accounts) =
+        program_test_context_with_token_2022_stake_pool_state().await;
+
+    let pool_mint = create_mint_with_extensions(
+        &mut ctx,
+        &spl_token_2022_interface::id(),
+        &[ExtensionType::DefaultAccountState],
+        |mint| {
+            vec![initialize_default_account_state(
+                &spl_token_2022_interface::id(),
+                mint,
+                &AccountState::Initialized,
+            )
+            .unwrap()]
+        },
+    )
+    .await;
+    assert_init_rejects_pool_mint(&mut ctx, &stake_pool_accounts, pool_mint).await;
+}
+
+/// Swap `pool_mint` into the StakePool's data and assert InitStakePoolDepositStakeAuthority
+/// rejects it as an unsupported pool mint.
+async fn assert_init_rejects_pool_mint(
+    ctx: &mut ProgramTestContext,
+    stake_pool_accounts: &StakePoolAccounts,
+    pool_mint: Pubkey,
+) {
+    let stake_pool_account =
+        get_account(&mut ctx.banks_client, &stake_pool_accounts.stake_pool).await;
+    let mut stake_pool =
+        try_from_slice_unchecked::<spl_stake_pool::state::StakePool>(&stake_pool_account.data)
+            .unwrap();
+    stake_pool.pool_mint = pool_mint;
+    let mut data = stake_pool_account.data.clone();
+    borsh::to_writer(&mut data[..], &stake_pool).unwrap();
+    ctx.set_account(
+        &stake_pool_accounts.stake_pool,
+        &AccountSharedData::from(SolanaAccount {
+            data,
+            ..stake_pool_account
+        }),
+    );
+
+    let deposit_authority_base = Keypair::new();
+    let init_ix = create_init_deposit_stake_authority_instruction(
+        &stake_deposit_interceptor_program::id(),
+        &ctx.payer.pubkey(),
+        &stake_pool_accounts.stake_pool,
+        &pool_mint,
+        &spl_stake_pool::id(),
+        &spl_token_2022_interface::id(),
+        &Pubkey::new_unique(),
+        100,
+        20,
+        FeeCurve::Linear,
+        CoolDownUnit::Seconds,
+        &Keypair::new().pubkey(),
+        &deposit_authority_base.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &deposit_authority_base],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::UnsupportedPoolMintExtension as u32),
+    )
+    .await;
+}
@@ -16,7 +16,7 @@ use stake_deposit_interceptor_program::{
         derive_stake_pool_deposit_stake_authority, StakeDepositInterceptorInstruction,
         UpdateStakePoolDepositStakeAuthorityArgs,
     },
-    state::StakePoolDepositStakeAuthority,
+    state::{CoolDownUnit, FeeCurve, RebateMode, StakePoolDepositStakeAuthority},
 };
 
 #[tokio::test]
@@ -49,6 +49,18 @@ async fn test_update_deposit_stake_authority() {
     let cool_down_seconds = 78;
     let initial_fee_bps = 20;
     let jito_whitelist_management_program_id = Pubkey::new_unique();
+    let fee_curve = FeeCurve::Step { steps: 3 };
+    let whitelist_fee_discount_bps = 5_000;
+    let max_deposit_lamports = 1_000_000_000;
+    let epoch_deposit_cap = 10_000_000_000;
+    let referrer_fee_share_bps = 2_500;
+    let cool_down_unit = CoolDownUnit::Epochs;
+    let hopper_rebate_discount_bps = 2_000;
+    let max_hopper_rebate_lamports = 5_000_000;
+    let epoch_hopper_rebate_budget = 50_000_000;
+    let rebate_mode = RebateMode::RebateVault;
+    let deposit_whitelist = Pubkey::new_unique();
+    let withdraw_whitelist = Pubkey::new_unique();
 
     let update_ix =
         stake_deposit_interceptor_program::instruction::create_update_deposit_stake_authority_instruction(
@@ -60,13 +72,25 @@ async fn test_update_deposit_stake_authority() {
             Some(fee_wallet.pubkey()),
             Some(cool_down_seconds),
             Some(initial_fee_bps),
-            Some(jito_whitelist_management_program_id)
+            Some(jito_whitelist_management_program_id),
+            Some(fee_curve),
+            Some(whitelist_fee_discount_bps),
+            Some(max_deposit_lamports),
+            Some(epoch_deposit_cap),
+            Some(referrer_fee_share_bps),
+            Some(cool_down_unit),
+            Some(hopper_rebate_discount_bps),
+            Some(max_hopper_rebate_lamports),
+            Some(epoch_hopper_rebate_budget),
+            Some(rebate_mode),
+            Some(deposit_whitelist),
+            Some(withdraw_whitelist),
         );
 
     let tx = Transaction::new_signed_with_payer(
         &[update_ix],
         Some(&ctx.payer.pubkey()),
-        &[&ctx.payer, &authority],
+        &[&ctx.payer, &authority, &new_authority],
         ctx.last_blockhash,
     );
 
@@ -98,13 +122,46 @@ async fn test_update_deposit_stake_authority() {
         deposit_stake_authority.jito_whitelist_management_program_id,
         jito_whitelist_management_program_id
     );
+    assert_eq!(deposit_stake_authority.fee_curve(), fee_curve);
+    let actual_whitelist_fee_discount_bps: u32 =
+        deposit_stake_authority.whitelist_fee_discount_bps.into();
+    assert_eq!(
+        actual_whitelist_fee_discount_bps,
+        whitelist_fee_discount_bps
+    );
+    let actual_referrer_fee_share_bps: u32 = deposit_stake_authority.referrer_fee_share_bps.into();
+    assert_eq!(actual_referrer_fee_share_bps, referrer_fee_share_bps);
+    assert_eq!(deposit_stake_authority.cool_down_unit(), cool_down_unit);
+    let actual_hopper_rebate_discount_bps: u32 =
+        deposit_stake_authority.hopper_rebate_discount_bps.into();
+    assert_eq!(
+        actual_hopper_rebate_discount_bps,
+        hopper_rebate_discount_bps
+    );
+    let actual_max_hopper_rebate_lamports: u64 =
+        deposit_stake_authority.max_hopper_rebate_lamports.into();
+    assert_eq!(
+        actual_max_hopper_rebate_lamports,
+        max_hopper_rebate_lamports
+    );
+    let actual_epoch_hopper_rebate_budget: u64 =
+        deposit_stake_authority.epoch_hopper_rebate_budget.into();
+    assert_eq!(
+        actual_epoch_hopper_rebate_budget,
+        epoch_hopper_rebate_budget
+    );
+    assert_eq!(deposit_stake_authority.rebate_mode(), rebate_mode);
+    assert_eq!(deposit_stake_authority.deposit_whitelist, deposit_whitelist);
+    assert_eq!(
+        deposit_stake_authority.withdraw_whitelist,
+        withdraw_whitelist
+    );
 }
 
 async fn setup_with_ix() -> (
     ProgramTestContext,
     StakePoolAccounts,
     Keypair,
-    Keypair,
     Pubkey,
     Instruction,
 ) {
@@ -132,7 +189,6 @@ async fn setup_with_ix() -> (
     .await;
 
     let fee_wallet = Keypair::new();
-    let new_authority = Keypair::new();
     let cool_down_seconds = 78;
     let initial_fee_bps = 20;
     let jito_whitelist_management_program_id = Pubkey::new_unique();
@@ -143,11 +199,23 @@ async fn setup_with_ix() -> (
             &stake_pool_accounts.stake_pool,
             &authority.pubkey(),
             &deposit_authority_base.pubkey(),
-            Some(new_authority.pubkey()),
+            None,
             Some(fee_wallet.pubkey()),
             Some(cool_down_seconds),
             Some(initial_fee_bps),
-            Some(jito_whitelist_management_program_id)
+            Some(jito_whitelist_management_program_id),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         );
 
     let (deposit_stake_authority_pubkey, _bump) = derive_stake_pool_deposit_stake_authority(
@@ -159,7 +227,6 @@ async fn setup_with_ix() -> (
         ctx,
         stake_pool_accounts,
         authority,
-        new_authority,
         deposit_stake_authority_pubkey,
         update_ix,
     )
@@ -167,14 +234,8 @@ async fn setup_with_ix() -> (
 
 #[tokio::test]
 async fn test_fail_program_does_not_own_pda_account() {
-    let (
-        mut ctx,
-        _stake_pool_accounts,
-        authority,
-        _new_authority,
-        _deposit_stake_authority_pubkey,
-        mut ix,
-    ) = setup_with_ix().await;
+    let (mut ctx, _stake_pool_accounts, authority, _deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
     ix.accounts[0] = AccountMeta::new(Pubkey::new_unique(), false);
 
     let tx = Transaction::new_signed_with_payer(
@@ -189,14 +250,8 @@ async fn test_fail_program_does_not_own_pda_account() {
 
 #[tokio::test]
 async fn test_fail_authority_not_signer() {
-    let (
-        mut ctx,
-        _stake_pool_accounts,
-        authority,
-        _new_authority,
-        _deposit_stake_authority_pubkey,
-        mut ix,
-    ) = setup_with_ix().await;
+    let (mut ctx, _stake_pool_accounts, authority, _deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
     ix.accounts[1] = AccountMeta::new_readonly(authority.pubkey(), false);
 
     let tx = Transaction::new_signed_with_payer(
@@ -214,16 +269,60 @@ async fn test_fail_authority_not_signer() {
     .await;
 }
 
+#[tokio::test]
+async fn test_fail_new_authority_not_signer() {
+    let (mut ctx, _stake_pool_accounts, authority, _deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
+    ix.accounts
+        .push(AccountMeta::new_readonly(Pubkey::new_unique(), false));
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::SignatureMissing as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_omitted_new_authority_placeholder_keeps_authority() {
+    let (mut ctx, _stake_pool_accounts, authority, deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
+    ix.accounts.push(AccountMeta::new_readonly(
+        stake_deposit_interceptor_program::id(),
+        false,
+    ));
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = ctx
+        .banks_client
+        .get_account(deposit_stake_authority_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    let deposit_stake_authority =
+        StakePoolDepositStakeAuthority::try_from_slice_unchecked(account.data.as_slice()).unwrap();
+    assert_eq!(deposit_stake_authority.authority, authority.pubkey());
+}
+
 #[tokio::test]
 async fn test_fail_authority_incorrect() {
-    let (
-        mut ctx,
-        _stake_pool_accounts,
-        _authority,
-        _new_authority,
-        _deposit_stake_authority_pubkey,
-        mut ix,
-    ) = setup_with_ix().await;
+    let (mut ctx, _stake_pool_accounts, _authority, _deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
     let bad_authority = Keypair::new();
     ix.accounts[1] = AccountMeta::new_readonly(bad_authority.pubkey(), true);
 
@@ -244,14 +343,8 @@ async fn test_fail_authority_incorrect() {
 
 #[tokio::test]
 async fn test_fail_invalid_stake_deposit_authority_address() {
-    let (
-        mut ctx,
-        _stake_pool_accounts,
-        authority,
-        _new_authority,
-        deposit_stake_authority_pubkey,
-        mut ix,
-    ) = setup_with_ix().await;
+    let (mut ctx, _stake_pool_accounts, authority, deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
     let bad_account = clone_account_to_new_address(&mut ctx, &deposit_stake_authority_pubkey).await;
     ix.accounts[0] = AccountMeta::new(bad_account, false);
 
@@ -274,20 +367,26 @@ async fn test_fail_invalid_stake_deposit_authority_address() {
 
 #[tokio::test]
 async fn test_fail_initial_fee_bps_cannot_exceed_10000() {
-    let (
-        mut ctx,
-        _stake_pool_accounts,
-        authority,
-        _new_authority,
-        _deposit_stake_authority_pubkey,
-        mut ix,
-    ) = setup_with_ix().await;
+    let (mut ctx, _stake_pool_accounts, authority, _deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
 
     let args = UpdateStakePoolDepositStakeAuthorityArgs {
         fee_wallet: None,
         initial_fee_bps: Some(10_001),
         cool_down_seconds: None,
         jito_whitelist_management_program_id: None,
+        fee_curve: None,
+        whitelist_fee_discount_bps: None,
+        max_deposit_lamports: None,
+        epoch_deposit_cap: None,
+        referrer_fee_share_bps: None,
+        cool_down_unit: None,
+        hopper_rebate_discount_bps: None,
+        max_hopper_rebate_lamports: None,
+        epoch_hopper_rebate_budget: None,
+        rebate_mode: None,
+        deposit_whitelist: None,
+        withdraw_whitelist: None,
     };
     ix.data = borsh::to_vec(
         &StakeDepositInterceptorInstruction::UpdateStakePoolDepositStakeAuthority(args),
@@ -308,3 +407,181 @@ async fn test_fail_initial_fee_bps_cannot_exceed_10000() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn test_fail_invalid_fee_curve() {
+    let (mut ctx, _stake_pool_accounts, authority, _deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
+
+    let args = UpdateStakePoolDepositStakeAuthorityArgs {
+        fee_wallet: None,
+        initial_fee_bps: None,
+        cool_down_seconds: None,
+        jito_whitelist_management_program_id: None,
+        fee_curve: Some(FeeCurve::FlatThenLinear {
+            flat_seconds: u64::MAX,
+        }),
+        whitelist_fee_discount_bps: None,
+        max_deposit_lamports: None,
+        epoch_deposit_cap: None,
+        referrer_fee_share_bps: None,
+        cool_down_unit: None,
+        hopper_rebate_discount_bps: None,
+        max_hopper_rebate_lamports: None,
+        epoch_hopper_rebate_budget: None,
+        rebate_mode: None,
+        deposit_whitelist: None,
+        withdraw_whitelist: None,
+    };
+    ix.data = borsh::to_vec(
+        &StakeDepositInterceptorInstruction::UpdateStakePoolDepositStakeAuthority(args),
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::InvalidFeeCurve as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_whitelist_fee_discount_max_exceeded() {
+    let (mut ctx, _stake_pool_accounts, authority, _deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
+
+    let args = UpdateStakePoolDepositStakeAuthorityArgs {
+        fee_wallet: None,
+        initial_fee_bps: None,
+        cool_down_seconds: None,
+        jito_whitelist_management_program_id: None,
+        fee_curve: None,
+        whitelist_fee_discount_bps: Some(10_001),
+        max_deposit_lamports: None,
+        epoch_deposit_cap: None,
+        referrer_fee_share_bps: None,
+        cool_down_unit: None,
+        hopper_rebate_discount_bps: None,
+        max_hopper_rebate_lamports: None,
+        epoch_hopper_rebate_budget: None,
+        rebate_mode: None,
+        deposit_whitelist: None,
+        withdraw_whitelist: None,
+    };
+    ix.data = borsh::to_vec(
+        &StakeDepositInterceptorInstruction::UpdateStakePoolDepositStakeAuthority(args),
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(
+            StakeDepositInterceptorError::WhitelistFeeDiscountMaxExceeded as u32,
+        ),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_referrer_fee_share_max_exceeded() {
+    let (mut ctx, _stake_pool_accounts, authority, _deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
+
+    let args = UpdateStakePoolDepositStakeAuthorityArgs {
+        fee_wallet: None,
+        initial_fee_bps: None,
+        cool_down_seconds: None,
+        jito_whitelist_management_program_id: None,
+        fee_curve: None,
+        whitelist_fee_discount_bps: None,
+        max_deposit_lamports: None,
+        epoch_deposit_cap: None,
+        referrer_fee_share_bps: Some(10_001),
+        cool_down_unit: None,
+        hopper_rebate_discount_bps: None,
+        max_hopper_rebate_lamports: None,
+        epoch_hopper_rebate_budget: None,
+        rebate_mode: None,
+        deposit_whitelist: None,
+        withdraw_whitelist: None,
+    };
+    ix.data = borsh::to_vec(
+        &StakeDepositInterceptorInstruction::UpdateStakePoolDepositStakeAuthority(args),
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(StakeDepositInterceptorError::ReferrerFeeShareMaxExceeded as u32),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_fail_hopper_rebate_discount_max_exceeded() {
+    let (mut ctx, _stake_pool_accounts, authority, _deposit_stake_authority_pubkey, mut ix) =
+        setup_with_ix().await;
+
+    let args = UpdateStakePoolDepositStakeAuthorityArgs {
+        fee_wallet: None,
+        initial_fee_bps: None,
+        cool_down_seconds: None,
+        jito_whitelist_management_program_id: None,
+        fee_curve: None,
+        whitelist_fee_discount_bps: None,
+        max_deposit_lamports: None,
+        epoch_deposit_cap: None,
+        referrer_fee_share_bps: None,
+        cool_down_unit: None,
+        hopper_rebate_discount_bps: Some(10_001),
+        max_hopper_rebate_lamports: None,
+        epoch_hopper_rebate_budget: None,
+        rebate_mode: None,
+        deposit_whitelist: None,
+        withdraw_whitelist: None,
+    };
+    ix.data = borsh::to_vec(
+        &StakeDepositInterceptorInstruction::UpdateStakePoolDepositStakeAuthority(args),
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+
+    assert_transaction_err(
+        &mut ctx,
+        tx,
+        InstructionError::Custom(
+            StakeDepositInterceptorError::HopperRebateDiscountMaxExceeded as u32,
+        ),
+    )
+    .await;
+}
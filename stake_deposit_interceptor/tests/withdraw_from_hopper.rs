@@ -84,6 +84,18 @@ mod tests {
                 None,
                 None,
                 Some(jito_whitelist_management_client::programs::JITO_WHITELIST_MANAGEMENT_ID),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
             );
         let tx = solana_transaction::Transaction::new_signed_with_payer(
             &[update_ix],
@@ -94,6 +94,18 @@ mod tests {
             None,
             None,
             Some(jito_whitelist_management_client::programs::JITO_WHITELIST_MANAGEMENT_ID),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         );
         let tx = solana_transaction::Transaction::new_signed_with_payer(
             &[update_ix],
@@ -353,6 +365,7 @@ mod tests {
                 stake_pool_accounts.pool_mint,
                 hopper_pda,
                 fee_rebate_receiver,
+                None,
                 spl_stake_pool::id(),
                 pool_tokens_to_token.amount,
                 0,
@@ -379,6 +392,18 @@ mod tests {
         let fee_rebate_receiver_account =
             get_account(&mut ctx.banks_client, &fee_rebate_receiver).await;
         assert!(fee_rebate_receiver_account.lamports > 0);
+
+        // Assert the rebate was counted on the StakePoolDepositStakeAuthority
+        let deposit_stake_authority =
+            get_account_data_deserialized::<StakePoolDepositStakeAuthority>(
+                &mut ctx.banks_client,
+                &deposit_stake_authority_pubkey,
+            )
+            .await;
+        assert_eq!(
+            u64::from(deposit_stake_authority.total_hopper_rebate_lamports),
+            fee_rebate_receiver_account.lamports
+        );
     }
 
     #[tokio::test]
@@ -574,6 +599,7 @@ mod tests {
                 stake_pool_accounts.pool_mint,
                 hopper_pda,
                 fee_rebate_receiver,
+                None,
                 spl_stake_pool::id(),
                 pool_tokens_to_token.amount,
                 0,
@@ -767,6 +793,7 @@ mod tests {
                 stake_pool_accounts.pool_mint,
                 hopper_pda,
                 fee_rebate_receiver,
+                None,
                 spl_stake_pool::id(),
                 pool_tokens_to_token.amount,
                 0,
@@ -960,6 +987,7 @@ mod tests {
                 stake_pool_accounts.pool_mint,
                 hopper_pda,
                 fee_rebate_receiver,
+                None,
                 spl_stake_pool::id(),
                 pool_tokens_to_token.amount,
                 0,
@@ -1143,6 +1171,7 @@ mod tests {
                 stake_pool_accounts.pool_mint,
                 hopper_pda,
                 fee_rebate_receiver,
+                None,
                 invalid_spl_stake_pool_program_id,
                 pool_tokens_to_token.amount,
                 0,